@@ -0,0 +1,29 @@
+use clap::Parser;
+use roboat::ClientBuilder;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    roblosecurity: String,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+    let client = ClientBuilder::new()
+        .roblosecurity(args.roblosecurity)
+        .build();
+
+    let (requests, _) = client.friend_requests(None).await?;
+
+    if requests.is_empty() {
+        println!("No friend requests found.");
+    }
+
+    for request in requests {
+        client.accept_friend_request(request.user_id).await?;
+        println!("Accepted friend request from {}", request.username);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use clap::Parser;
+use roboat::ids::AssetId;
+use roboat::ClientBuilder;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    asset_id: AssetId,
+    /// The maximum amount of assets to download.
+    #[arg(long, short, default_value_t = 100)]
+    max_assets: usize,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+
+    let client = ClientBuilder::new().build();
+
+    let graph = client
+        .asset_dependency_graph(args.asset_id, args.max_assets)
+        .await?;
+
+    for (asset_id, dependencies) in &graph.dependencies {
+        println!("{} -> {:?}", asset_id, dependencies);
+    }
+
+    for (asset_id, e) in &graph.failures {
+        println!("Could not download {}: {}", asset_id, e);
+    }
+
+    println!(
+        "Asset {} depends on {} assets.",
+        graph.root_asset_id,
+        graph.all_dependencies().len()
+    );
+
+    Ok(())
+}
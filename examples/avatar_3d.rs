@@ -0,0 +1,42 @@
+use clap::Parser;
+use roboat::ids::UserId;
+use roboat::ClientBuilder;
+use std::path::PathBuf;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    user_id: UserId,
+    /// The directory to save the model files in.
+    #[arg(long, short)]
+    output_dir: PathBuf,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+
+    let client = ClientBuilder::new().build();
+
+    let Some(avatar) = client.avatar_3d(args.user_id).await? else {
+        println!("The 3D model of this avatar is not available right now.");
+        return Ok(());
+    };
+
+    std::fs::create_dir_all(&args.output_dir)?;
+
+    std::fs::write(args.output_dir.join("avatar.obj"), &avatar.obj)?;
+    std::fs::write(args.output_dir.join("avatar.mtl"), &avatar.mtl)?;
+
+    for texture in &avatar.textures {
+        std::fs::write(args.output_dir.join(&texture.hash), &texture.bytes)?;
+    }
+
+    println!(
+        "Saved the model and {} textures to {}.",
+        avatar.textures.len(),
+        args.output_dir.display()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use clap::Parser;
+use roboat::economy::BestPrice;
+use roboat::ids::AssetId;
+use roboat::ClientBuilder;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    roblosecurity: String,
+    #[arg(long, short)]
+    item_id: AssetId,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+    let client = ClientBuilder::new()
+        .roblosecurity(args.roblosecurity)
+        .build();
+
+    match client.best_price(args.item_id).await? {
+        BestPrice::Primary {
+            product_id, price, ..
+        } => println!(
+            "Cheapest from primary sale (product {}): {} robux",
+            product_id, price
+        ),
+        BestPrice::Resale(purchase_args) => println!(
+            "Cheapest from reseller uaid {}: {} robux",
+            purchase_args.uaid, purchase_args.price
+        ),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+use clap::Parser;
+use roboat::ClientBuilder;
+use roboat::Limit;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    roblosecurity: String,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+
+    let client = ClientBuilder::new()
+        .roblosecurity(args.roblosecurity)
+        .build();
+
+    let credit = client.billing_balance().await?;
+    let (purchases, _) = client.billing_history(Limit::Ten, None).await?;
+
+    println!("Credit Balance: {}", credit.balance);
+
+    for purchase in purchases {
+        println!("Bought {} Robux at {:?}", purchase.robux, purchase.created);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use clap::Parser;
+use roboat::ids::UniverseId;
+use roboat::ClientBuilder;
+use serde_json::json;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    api_key: String,
+    #[arg(long, short)]
+    universe_id: UniverseId,
+    #[arg(long, short, default_value = "Announcements")]
+    topic: String,
+    #[arg(long, short)]
+    message: String,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+
+    let client = ClientBuilder::new().api_key(args.api_key).build();
+
+    let message = json!({ "motd": args.message });
+
+    client
+        .broadcast_to_game(args.universe_id, &args.topic, &message)
+        .await?;
+
+    println!("Broadcasted to topic {}", args.topic);
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use clap::Parser;
+use roboat::economy::ProductInfo;
+use roboat::ids::AssetId;
+use roboat::ClientBuilder;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    roblosecurity: String,
+    #[arg(long, short)]
+    item_id: AssetId,
+    /// The most robux to pay for the item.
+    #[arg(long, short)]
+    buy_max: u64,
+    /// The price to list the purchased copy at.
+    #[arg(long, short = 'p')]
+    relist_price: u64,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+    let client = ClientBuilder::new()
+        .roblosecurity(args.roblosecurity)
+        .build();
+
+    let item_ids = client.resolve_item_ids(args.item_id).await?;
+
+    let product_info = ProductInfo {
+        asset_id: args.item_id,
+        product_id: item_ids.product_id,
+    };
+
+    let outcome = client
+        .buy_and_relist(product_info, args.buy_max, args.relist_price)
+        .await?;
+
+    println!(
+        "Bought uaid {} for {} robux.",
+        outcome.receipt.uaid, outcome.receipt.price
+    );
+
+    match outcome.result {
+        Ok(uaid) => println!("Listed uaid {} for {} robux.", uaid, args.relist_price),
+        Err(e) => println!("Failed to list the purchased copy. Reason: {}", e),
+    }
+
+    Ok(())
+}
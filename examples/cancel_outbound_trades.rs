@@ -0,0 +1,43 @@
+use clap::Parser;
+use roboat::ids::UserId;
+use roboat::ClientBuilder;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    roblosecurity: String,
+    /// Cancel every outbound trade sent to this user.
+    #[arg(long, short)]
+    partner_id: UserId,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+
+    let client = ClientBuilder::new()
+        .roblosecurity(args.roblosecurity)
+        .build();
+
+    let cancellations = client
+        .cancel_all_outbound(|trade| {
+            (trade.partner.user_id == args.partner_id)
+                .then(|| format!("sent to {}", trade.partner.username))
+        })
+        .await?;
+
+    for cancellation in cancellations {
+        match cancellation.result {
+            Ok(()) => println!(
+                "Cancelled trade {} ({})",
+                cancellation.trade.trade_id, cancellation.reason
+            ),
+            Err(e) => println!(
+                "Failed to cancel trade {}: {}",
+                cancellation.trade.trade_id, e
+            ),
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use clap::Parser;
+use roboat::catalog::avatar_catalog::CatalogQuery;
+use roboat::ClientBuilder;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    keyword: String,
+    /// Only shows items made by verified creators.
+    #[arg(long, short)]
+    verified_only: bool,
+    /// Instead of listing the first page, searches until an item with this name is found.
+    #[arg(long, short)]
+    find: Option<String>,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+
+    let client = ClientBuilder::new().build();
+
+    let query = CatalogQuery::new()
+        .keyword(args.keyword)
+        .verified_creators_only(args.verified_only);
+
+    if let Some(name) = args.find {
+        let result = client
+            .search_until(&query, |item| item.name.eq_ignore_ascii_case(&name))
+            .await?;
+
+        match result.item {
+            Some(item) => println!(
+                "Found {} ({}) after {} pages.",
+                item.name, item.id, result.pages_scanned
+            ),
+            None => println!("Not found in {} pages.", result.pages_scanned),
+        }
+
+        return Ok(());
+    }
+
+    let (items, _) = client.avatar_catalog_search(&query, None).await?;
+
+    for item in items {
+        println!(
+            "{} ({}) by {} - {:?} robux",
+            item.name, item.id, item.creator_name, item.price
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+use clap::Parser;
+use roboat::ids::{AssetId, UserId};
+use roboat::ClientBuilder;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    user_id: UserId,
+    #[arg(long, short)]
+    asset_id: AssetId,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+    let client = ClientBuilder::new().build();
+
+    if !client.can_view_inventory(args.user_id).await? {
+        println!("The inventory of {} is private.", args.user_id);
+        return Ok(());
+    }
+
+    match client.user_owns_asset(args.user_id, args.asset_id).await? {
+        true => println!("{} owns {}", args.user_id, args.asset_id),
+        false => println!("{} does not own {}", args.user_id, args.asset_id),
+    }
+
+    Ok(())
+}
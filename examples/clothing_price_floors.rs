@@ -0,0 +1,28 @@
+use clap::Parser;
+use roboat::ClientBuilder;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    roblosecurity: String,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+
+    let client = ClientBuilder::new()
+        .roblosecurity(args.roblosecurity)
+        .build();
+
+    let price_floors = client.clothing_price_floors().await?;
+
+    for price_floor in price_floors {
+        println!(
+            "{:?}: {} robux",
+            price_floor.asset_type, price_floor.price_floor
+        );
+    }
+
+    Ok(())
+}
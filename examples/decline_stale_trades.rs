@@ -0,0 +1,39 @@
+use clap::Parser;
+use roboat::ClientBuilder;
+use std::time::Duration;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    roblosecurity: String,
+    /// Inbound trades older than this many days are declined.
+    #[arg(long, short, default_value_t = 3)]
+    days: u64,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+
+    let client = ClientBuilder::new()
+        .roblosecurity(args.roblosecurity)
+        .build();
+
+    let older_than = Duration::from_secs(args.days * 86400);
+    let report = client.decline_stale_inbound_trades(older_than).await?;
+
+    println!("Checked {} inbound trades.", report.checked);
+
+    for trade in &report.declined {
+        println!(
+            "Declined trade {} from {} (sent {})",
+            trade.trade_id, trade.partner.username, trade.created
+        );
+    }
+
+    for (trade, e) in &report.failed {
+        println!("Failed to decline trade {}: {}", trade.trade_id, e);
+    }
+
+    Ok(())
+}
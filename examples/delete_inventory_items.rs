@@ -0,0 +1,33 @@
+use clap::Parser;
+use roboat::ids::AssetId;
+use roboat::ClientBuilder;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    roblosecurity: String,
+    /// The asset ids of the items to delete, separated by commas.
+    #[arg(long, short, value_delimiter = ',', required = true)]
+    asset_ids: Vec<AssetId>,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+
+    let client = ClientBuilder::new()
+        .roblosecurity(args.roblosecurity)
+        .build();
+
+    let result = client.delete_inventory_items(args.asset_ids.clone()).await;
+
+    for (i, _) in result.successes {
+        println!("Deleted {}", args.asset_ids[i]);
+    }
+
+    for (i, e) in result.failures {
+        println!("Failed to delete {}: {}", args.asset_ids[i], e);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use clap::Parser;
+use roboat::ids::AssetId;
+use roboat::ClientBuilder;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    asset_id: AssetId,
+    /// The file to save the asset to. Defaults to `{asset_id}.rbxm`.
+    #[arg(long, short)]
+    output: Option<String>,
+    /// Needed for assets that are only served to accounts with access to them.
+    #[arg(long, short)]
+    roblosecurity: Option<String>,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+
+    let client = match args.roblosecurity {
+        Some(roblosecurity) => ClientBuilder::new().roblosecurity(roblosecurity).build(),
+        None => ClientBuilder::new().build(),
+    };
+
+    let bytes = client.download_asset(args.asset_id).await?;
+
+    let output = args
+        .output
+        .unwrap_or_else(|| format!("{}.rbxm", args.asset_id));
+
+    std::fs::write(&output, &bytes)?;
+
+    println!("Downloaded {} bytes into {}.", bytes.len(), output);
+
+    Ok(())
+}
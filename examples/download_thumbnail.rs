@@ -0,0 +1,38 @@
+use clap::Parser;
+use roboat::thumbnails::{ThumbnailArgs, ThumbnailFormat, ThumbnailSize, ThumbnailType};
+use roboat::ClientBuilder;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    item_id: u64,
+    #[arg(long, short, default_value = "thumbnail_cache")]
+    cache_dir: String,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+    let client = ClientBuilder::new().build();
+
+    let thumbnail_args = ThumbnailArgs {
+        target_id: args.item_id,
+        thumbnail_type: ThumbnailType::Asset,
+        size: ThumbnailSize::Size420x420,
+        format: ThumbnailFormat::Png,
+    };
+
+    let thumbnail = client.thumbnails(vec![thumbnail_args]).await?.remove(0);
+
+    match thumbnail.image_url {
+        Some(image_url) => {
+            let image = client
+                .download_thumbnail(image_url, &args.cache_dir)
+                .await?;
+            println!("Downloaded {} bytes into {}.", image.len(), args.cache_dir);
+        }
+        None => println!("Thumbnail is not available: {:?}", thumbnail.state),
+    }
+
+    Ok(())
+}
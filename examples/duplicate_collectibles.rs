@@ -0,0 +1,31 @@
+use clap::Parser;
+use roboat::ids::UserId;
+use roboat::ClientBuilder;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    user_id: UserId,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+
+    let client = ClientBuilder::new().build();
+
+    let duplicates = client.duplicate_collectibles(args.user_id).await?;
+
+    for duplicate in duplicates {
+        println!("{} x{}", duplicate.name, duplicate.copies.len());
+
+        for copy in duplicate.copies {
+            match copy.serial_number {
+                Some(serial_number) => println!("    uaid {} (#{})", copy.uaid, serial_number),
+                None => println!("    uaid {}", copy.uaid),
+            }
+        }
+    }
+
+    Ok(())
+}
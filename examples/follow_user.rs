@@ -0,0 +1,32 @@
+use clap::Parser;
+use roboat::ids::UserId;
+use roboat::ClientBuilder;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    roblosecurity: String,
+    #[arg(long, short)]
+    user_id: UserId,
+    /// Unfollow the user instead of following them.
+    #[arg(long)]
+    unfollow: bool,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+    let client = ClientBuilder::new()
+        .roblosecurity(args.roblosecurity)
+        .build();
+
+    if args.unfollow {
+        client.unfollow(args.user_id).await?;
+        println!("Unfollowed {}", args.user_id);
+    } else {
+        client.follow(args.user_id).await?;
+        println!("Followed {}", args.user_id);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use clap::Parser;
+use roboat::ClientBuilder;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    roblosecurity: String,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+
+    let client = ClientBuilder::new()
+        .roblosecurity(args.roblosecurity)
+        .build();
+
+    let online_friends = client.friends_online().await?;
+
+    for friend in online_friends {
+        println!(
+            "{} ({:?}) - {}",
+            friend.username,
+            friend.presence_type,
+            friend.last_location.unwrap_or_default()
+        );
+    }
+
+    Ok(())
+}
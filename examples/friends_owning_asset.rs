@@ -0,0 +1,39 @@
+use clap::Parser;
+use roboat::ids::AssetId;
+use roboat::ClientBuilder;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    roblosecurity: String,
+    #[arg(long, short)]
+    item_id: AssetId,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+    let client = ClientBuilder::new()
+        .roblosecurity(args.roblosecurity)
+        .build();
+
+    let result = client.friends_owning_asset(args.item_id).await?;
+
+    match result.successes.len() {
+        0 => println!("None of your friends own this item."),
+        _ => {
+            for (_, owner) in result.successes {
+                println!("{} ({}) owns this item.", owner.username, owner.user_id);
+            }
+        }
+    }
+
+    if !result.failures.is_empty() {
+        println!(
+            "Could not check {} friends (their inventories may be private).",
+            result.failures.len()
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,26 @@
+use clap::Parser;
+use roboat::ClientBuilder;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    roblosecurity: String,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+
+    let client = ClientBuilder::new()
+        .roblosecurity(args.roblosecurity)
+        .build();
+
+    let robux = client.robux().await?;
+    let credit = client.billing_balance().await?;
+
+    println!("Robux: {}", robux);
+    println!("Credit Balance: {}", credit.balance);
+    println!("Credit Redeemable For: {} Robux", credit.robux_amount);
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+use clap::Parser;
+use roboat::ClientBuilder;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short, default_value_t = 39)]
+    bundle_id: u64,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+    let client = ClientBuilder::new().build();
+
+    let details = client.bundle_details(args.bundle_id).await?;
+
+    println!(
+        "Bundle Name: {} / Type: {:?} / Creator: {}",
+        details.name, details.bundle_type, details.creator_name
+    );
+
+    let contents = client.bundle_contents(args.bundle_id).await?;
+
+    for item in contents {
+        println!("- {} ({})", item.name, item.id);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use clap::Parser;
+use roboat::{ClientBuilder, Limit};
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    roblosecurity: String,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+
+    let client = ClientBuilder::new()
+        .roblosecurity(args.roblosecurity)
+        .build();
+
+    let limit = Limit::Hundred;
+    let cursor = None;
+
+    let (commission_sales, _) = client.commission_sales(limit, cursor).await?;
+
+    for sale in &commission_sales {
+        println!(
+            "{} robux from {} (sold in {})",
+            sale.robux_received,
+            sale.asset_name,
+            sale.place_name.as_deref().unwrap_or("an unknown place")
+        );
+    }
+
+    println!(
+        "Robux earned from last {} commission sales: {}",
+        commission_sales.len(),
+        commission_sales
+            .iter()
+            .map(|sale| sale.robux_received)
+            .sum::<u64>()
+    );
+
+    Ok(())
+}
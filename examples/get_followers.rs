@@ -0,0 +1,36 @@
+use clap::Parser;
+use roboat::ids::UserId;
+use roboat::ClientBuilder;
+use roboat::Limit;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    user_id: UserId,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+    let client = ClientBuilder::new().build();
+
+    let (followers, _) = client.followers(args.user_id, Limit::Hundred, None).await?;
+
+    let (followings, _) = client
+        .followings(args.user_id, Limit::Hundred, None)
+        .await?;
+
+    println!("Followers (first page):");
+
+    for follower in followers {
+        println!("- {} ({})", follower.username, follower.user_id);
+    }
+
+    println!("Following (first page):");
+
+    for following in followings {
+        println!("- {} ({})", following.username, following.user_id);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,25 @@
+use clap::Parser;
+use roboat::ids::UserId;
+use roboat::ClientBuilder;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    user_id: UserId,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+    let client = ClientBuilder::new().build();
+
+    let friends = client.friends_list(args.user_id).await?;
+
+    println!("User {} has {} friends:", args.user_id, friends.len());
+
+    for friend in friends {
+        println!("- {} ({})", friend.username, friend.user_id);
+    }
+
+    Ok(())
+}
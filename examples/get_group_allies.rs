@@ -0,0 +1,39 @@
+use clap::Parser;
+use roboat::ClientBuilder;
+use roboat::Limit;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    group_id: u64,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+    let client = ClientBuilder::new().build();
+
+    let limit = Limit::Hundred;
+    let cursor = None;
+
+    let (allies, _) = client.group_allies(args.group_id, limit, cursor).await?;
+    let (enemies, _) = client.group_enemies(args.group_id, limit, cursor).await?;
+
+    println!("Allies:");
+    for ally in allies {
+        println!(
+            "  {} ({}) - {} members",
+            ally.name, ally.group_id, ally.member_count
+        );
+    }
+
+    println!("Enemies:");
+    for enemy in enemies {
+        println!(
+            "  {} ({}) - {} members",
+            enemy.name, enemy.group_id, enemy.member_count
+        );
+    }
+
+    Ok(())
+}
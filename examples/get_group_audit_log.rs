@@ -0,0 +1,45 @@
+use clap::Parser;
+use roboat::groups::AuditAction;
+use roboat::{ClientBuilder, Limit};
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    roblosecurity: String,
+    #[arg(long, short)]
+    group_id: u64,
+    /// Only show rank changes.
+    #[arg(long)]
+    rank_changes: bool,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+
+    let client = ClientBuilder::new()
+        .roblosecurity(args.roblosecurity)
+        .build();
+
+    let action_filter = match args.rank_changes {
+        true => Some(AuditAction::ChangeRank),
+        false => None,
+    };
+
+    let (entries, _) = client
+        .group_audit_log(args.group_id, action_filter, Limit::Hundred, None)
+        .await?;
+
+    for entry in entries {
+        println!(
+            "[{}] {} ({}): {} - {}",
+            entry.created,
+            entry.actor.username,
+            entry.actor.role.name,
+            entry.action_type,
+            entry.description
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use clap::Parser;
+use roboat::ClientBuilder;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    group_id: u64,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+    let client = ClientBuilder::new().build();
+
+    let group = client.group_details(args.group_id).await?;
+    let roles = client.group_roles(args.group_id).await?;
+
+    println!("{} ({} members)", group.name, group.member_count);
+
+    if let Some(owner) = &group.owner {
+        println!("Owner: {}", owner.username);
+    }
+
+    if let Some(shout) = &group.shout {
+        println!("Shout: {}", shout.body);
+    }
+
+    for role in roles {
+        println!("Rank {}: {}", role.rank, role.name);
+    }
+
+    Ok(())
+}
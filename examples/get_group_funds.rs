@@ -0,0 +1,27 @@
+use clap::Parser;
+use roboat::ClientBuilder;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    roblosecurity: String,
+    #[arg(long, short)]
+    group_id: u64,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+
+    let client = ClientBuilder::new()
+        .roblosecurity(args.roblosecurity)
+        .build();
+
+    let funds = client.group_funds(args.group_id).await?;
+
+    println!("Available Robux: {}", funds.available);
+    println!("Pending Robux: {}", funds.pending);
+    println!("Total Robux: {}", funds.total);
+
+    Ok(())
+}
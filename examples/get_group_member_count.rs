@@ -0,0 +1,20 @@
+use clap::Parser;
+use roboat::ClientBuilder;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    group_id: u64,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+    let client = ClientBuilder::new().build();
+
+    let member_count = client.group_member_count(args.group_id).await?;
+
+    println!("Group {} has {} members", args.group_id, member_count);
+
+    Ok(())
+}
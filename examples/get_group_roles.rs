@@ -0,0 +1,29 @@
+use clap::Parser;
+use roboat::ClientBuilder;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    group_id: u64,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+    let client = ClientBuilder::new().build();
+
+    let roles = client.group_roles(args.group_id).await?;
+
+    // Roles are sorted from lowest to highest rank, so we reverse them to show the owner first.
+    for role in roles.iter().rev() {
+        match role.member_count {
+            Some(member_count) => println!(
+                "[{}] {} ({}) - {} members",
+                role.rank, role.name, role.role_id, member_count
+            ),
+            None => println!("[{}] {} ({})", role.rank, role.name, role.role_id),
+        }
+    }
+
+    Ok(())
+}
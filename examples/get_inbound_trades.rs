@@ -1,5 +1,4 @@
 use clap::Parser;
-use roboat::trades::TradeType;
 use roboat::ClientBuilder;
 use roboat::Limit;
 
@@ -16,11 +15,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .roblosecurity(args.roblosecurity)
         .build();
 
-    let trade_type = TradeType::Inbound;
     let limit = Limit::Ten;
     let cursor = None;
 
-    let trades = client.trades(trade_type, limit, cursor).await?;
+    let (trades, next_cursor) = client.inbound_trades(limit, cursor).await?;
 
     let trade_count = trades.len();
 
@@ -37,5 +35,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
     }
 
+    if let Some(next_cursor) = next_cursor {
+        println!("Next Cursor: {}", next_cursor);
+    }
+
     Ok(())
 }
@@ -0,0 +1,42 @@
+use clap::Parser;
+use roboat::ids::UniverseId;
+use roboat::ClientBuilder;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    roblosecurity: String,
+    #[arg(long, short)]
+    universe_id: UniverseId,
+    #[arg(long, short)]
+    start: String,
+    #[arg(long, short)]
+    end: String,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+
+    let client = ClientBuilder::new()
+        .roblosecurity(args.roblosecurity)
+        .build();
+
+    let payouts = client
+        .premium_payouts(args.universe_id, args.start, args.end)
+        .await?;
+
+    for payout in &payouts {
+        println!(
+            "{}: {} robux (engagement score {})",
+            payout.date, payout.payout_in_robux, payout.engagement_score
+        );
+    }
+
+    println!(
+        "Total Premium Payouts: {} robux",
+        payouts.iter().map(|x| x.payout_in_robux).sum::<u64>()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use clap::Parser;
+use roboat::ids::AssetId;
+use roboat::ClientBuilder;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    roblosecurity: String,
+    #[arg(long, short)]
+    item_id: AssetId,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+
+    let client = ClientBuilder::new()
+        .roblosecurity(args.roblosecurity)
+        .build();
+
+    let resale_data = client.resale_data(args.item_id).await?;
+
+    println!("RAP: {}", resale_data.recent_average_price);
+    println!("Original Price: {:?}", resale_data.original_price);
+    println!("Total Sales: {}", resale_data.sales);
+
+    let daily_history = resale_data
+        .price_data_points
+        .iter()
+        .zip(resale_data.volume_data_points.iter());
+
+    for (price, volume) in daily_history.take(10) {
+        println!(
+            "{}: {} sales averaging {} robux",
+            price.date.format("%Y-%m-%d"),
+            volume.value,
+            price.value
+        );
+    }
+
+    Ok(())
+}
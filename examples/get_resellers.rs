@@ -1,4 +1,5 @@
 use clap::Parser;
+use roboat::ids::AssetId;
 use roboat::ClientBuilder;
 use roboat::Limit;
 
@@ -16,7 +17,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .roblosecurity(args.roblosecurity)
         .build();
 
-    let item_id = 1365767;
+    let item_id = AssetId(1365767);
     let limit = Limit::Ten;
     let cursor = None;
 
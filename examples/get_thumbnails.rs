@@ -0,0 +1,34 @@
+use roboat::thumbnails::{ThumbnailArgs, ThumbnailFormat, ThumbnailSize, ThumbnailType};
+use roboat::ClientBuilder;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let client = ClientBuilder::new().build();
+
+    // limited item
+    let item = ThumbnailArgs {
+        target_id: 1365767,
+        thumbnail_type: ThumbnailType::Asset,
+        size: ThumbnailSize::Size420x420,
+        format: ThumbnailFormat::Png,
+    };
+
+    // user headshot
+    let headshot = ThumbnailArgs {
+        target_id: 2207291,
+        thumbnail_type: ThumbnailType::AvatarHeadShot,
+        size: ThumbnailSize::Size150x150,
+        format: ThumbnailFormat::Png,
+    };
+
+    let thumbnails = client.thumbnails(vec![item, headshot]).await?;
+
+    for thumbnail in thumbnails {
+        match thumbnail.image_url {
+            Some(image_url) => println!("{}: {}", thumbnail.target_id, image_url),
+            None => println!("{}: {:?}", thumbnail.target_id, thumbnail.state),
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use clap::Parser;
+use roboat::ClientBuilder;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    roblosecurity: String,
+    #[arg(long, short)]
+    trade_id: u64,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+    let client = ClientBuilder::new()
+        .roblosecurity(args.roblosecurity)
+        .build();
+
+    let details = client.trade_details(args.trade_id).await?;
+
+    println!(
+        "Trade {} with {} ({:?})",
+        details.trade_id, details.partner.username, details.status
+    );
+
+    for (label, side) in [("Giving", &details.offer), ("Receiving", &details.request)] {
+        println!("{} {} robux and:", label, side.robux);
+
+        for item in &side.items {
+            println!(
+                "  {} (uaid {}, serial {:?}, rap {})",
+                item.name, item.uaid, item.serial_number, item.recent_average_price
+            );
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use clap::Parser;
+use roboat::ClientBuilder;
+use roboat::Limit;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    roblosecurity: String,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+    let client = ClientBuilder::new()
+        .roblosecurity(args.roblosecurity)
+        .build();
+
+    let limit = Limit::Ten;
+
+    let (outbound, _) = client.outbound_trades(limit, None).await?;
+
+    println!("Outbound Trades:");
+
+    for trade in outbound {
+        println!("  {} - Partner: {}", trade.trade_id, trade.partner.username);
+    }
+
+    let (completed, _) = client.completed_trades(limit, None).await?;
+
+    println!("Completed Trades:");
+
+    for trade in completed {
+        println!("  {} - Partner: {}", trade.trade_id, trade.partner.username);
+    }
+
+    Ok(())
+}
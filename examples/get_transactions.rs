@@ -0,0 +1,53 @@
+use clap::Parser;
+use roboat::economy::{Transaction, TransactionType};
+use roboat::{ClientBuilder, Limit};
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    roblosecurity: String,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+
+    let client = ClientBuilder::new()
+        .roblosecurity(args.roblosecurity)
+        .build();
+
+    let (purchases, _) = client
+        .transactions(TransactionType::Purchase, Limit::Hundred, None)
+        .await?;
+
+    println!("Last {} purchases:", purchases.len());
+
+    for transaction in purchases {
+        if let Transaction::Purchase(purchase) = transaction {
+            println!(
+                "- {} robux on {} from {}",
+                purchase.robux_spent,
+                purchase.item_name.as_deref().unwrap_or("*Unknown Item*"),
+                purchase.seller_name
+            );
+        }
+    }
+
+    let (stipends, _) = client
+        .transactions(TransactionType::PremiumStipend, Limit::Ten, None)
+        .await?;
+
+    println!(
+        "Robux from the last {} premium stipends: {}",
+        stipends.len(),
+        stipends
+            .iter()
+            .filter_map(|transaction| match transaction {
+                Transaction::PremiumStipend(record) => Some(record.robux),
+                _ => None,
+            })
+            .sum::<i64>()
+    );
+
+    Ok(())
+}
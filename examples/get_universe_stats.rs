@@ -0,0 +1,31 @@
+use clap::Parser;
+use roboat::ids::UniverseId;
+use roboat::ClientBuilder;
+
+#[derive(Parser, Debug)]
+struct Args {
+    /// The universe ids to look up, separated by commas.
+    #[arg(long, short, value_delimiter = ',', required = true)]
+    universe_ids: Vec<UniverseId>,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+    let client = ClientBuilder::new().build();
+
+    let stats = client.universe_stats(args.universe_ids).await?;
+
+    for universe in stats {
+        println!(
+            "{} ({}) - {} playing, {} visits, {} favorites",
+            universe.name,
+            universe.universe_id,
+            universe.playing,
+            universe.visits,
+            universe.favorited_count
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use clap::Parser;
+use roboat::ids::UserId;
+use roboat::pagination::all_pages;
+use roboat::ClientBuilder;
+use roboat::Limit;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    user_id: UserId,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+
+    let client = ClientBuilder::new().build();
+
+    let collectibles = all_pages(None, |cursor| {
+        client.user_collectibles(args.user_id, Limit::Hundred, cursor)
+    })
+    .await?;
+
+    for collectible in &collectibles {
+        let serial_number = match collectible.serial_number {
+            Some(serial_number) => format!("#{}", serial_number),
+            None => "no serial".to_string(),
+        };
+
+        println!(
+            "{} ({}) - RAP: {}, Original Price: {}",
+            collectible.name,
+            serial_number,
+            collectible.recent_average_price.unwrap_or(0),
+            collectible.original_price.unwrap_or(0)
+        );
+    }
+
+    let total_rap: u64 = collectibles
+        .iter()
+        .filter_map(|collectible| collectible.recent_average_price)
+        .sum();
+
+    println!("Total RAP: {}", total_rap);
+
+    Ok(())
+}
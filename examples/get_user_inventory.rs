@@ -0,0 +1,38 @@
+use clap::Parser;
+use roboat::catalog::avatar_catalog::AssetType;
+use roboat::ids::UserId;
+use roboat::{ClientBuilder, Limit};
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    user_id: UserId,
+    /// The id of the asset type to list. Defaults to hats.
+    #[arg(long, short, default_value_t = 8)]
+    asset_type_id: u64,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+    let client = ClientBuilder::new().build();
+
+    let asset_type = AssetType::from(args.asset_type_id);
+
+    let (items, _) = client
+        .user_inventory(args.user_id, asset_type, Limit::Hundred, None)
+        .await?;
+
+    println!("{:?} owned by {} (first page):", asset_type, args.user_id);
+
+    for item in items {
+        match item.serial_number {
+            Some(serial_number) => {
+                println!("- {} ({}) #{}", item.name, item.asset_id, serial_number)
+            }
+            None => println!("- {} ({})", item.name, item.asset_id),
+        }
+    }
+
+    Ok(())
+}
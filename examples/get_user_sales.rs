@@ -29,5 +29,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             .sum::<u64>()
     );
 
+    let summary = client.pending_robux().await?;
+
+    println!("Available Robux: {}", summary.available);
+    println!("Pending Robux: {}", summary.pending);
+    println!("Total Robux: {}", summary.total);
+
     Ok(())
 }
@@ -0,0 +1,48 @@
+use clap::Parser;
+use roboat::ids::UserId;
+use roboat::ClientBuilder;
+use roboat::Limit;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    roblosecurity: String,
+    #[arg(long, short)]
+    group_id: u64,
+    /// Ban this user from the group.
+    #[arg(long)]
+    ban: Option<UserId>,
+    /// Unban this user from the group.
+    #[arg(long, conflicts_with = "ban")]
+    unban: Option<UserId>,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+    let client = ClientBuilder::new()
+        .roblosecurity(args.roblosecurity)
+        .build();
+
+    if let Some(user_id) = args.ban {
+        client.ban_member(args.group_id, user_id).await?;
+        println!("Banned {} from group {}", user_id, args.group_id);
+    }
+
+    if let Some(user_id) = args.unban {
+        client.unban_member(args.group_id, user_id).await?;
+        println!("Unbanned {} from group {}", user_id, args.group_id);
+    }
+
+    let (bans, _) = client
+        .group_bans(args.group_id, Limit::Hundred, None)
+        .await?;
+
+    println!("Banned users (first page):");
+
+    for ban in bans {
+        println!("- {} ({}) since {}", ban.username, ban.user_id, ban.created);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use clap::Parser;
+use roboat::ClientBuilder;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    roblosecurity: String,
+    #[arg(long, short)]
+    group_id: u64,
+    /// Accept every pending request on the first page.
+    #[arg(long)]
+    accept_all: bool,
+    /// Decline every pending request on the first page.
+    #[arg(long, conflicts_with = "accept_all")]
+    decline_all: bool,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+    let client = ClientBuilder::new()
+        .roblosecurity(args.roblosecurity)
+        .build();
+
+    let (requests, _) = client.join_requests(args.group_id, None).await?;
+
+    for request in requests {
+        println!(
+            "{} ({}) requested to join at {}",
+            request.username, request.user_id, request.created
+        );
+
+        if args.accept_all || args.decline_all {
+            client
+                .handle_join_request(args.group_id, request.user_id, args.accept_all)
+                .await?;
+
+            match args.accept_all {
+                true => println!("Accepted {}", request.username),
+                false => println!("Declined {}", request.username),
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use clap::Parser;
+use roboat::groups::GroupPayoutError;
+use roboat::ids::UserId;
+use roboat::{ClientBuilder, RoboatError};
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    roblosecurity: String,
+    #[arg(long, short)]
+    group_id: u64,
+    #[arg(long, short)]
+    user_id: UserId,
+    #[arg(long, short = 'R')]
+    robux: u64,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+
+    let client = ClientBuilder::new()
+        .roblosecurity(args.roblosecurity)
+        .build();
+
+    let recipients = vec![(args.user_id, args.robux)];
+
+    match client.group_payout(args.group_id, recipients).await {
+        Ok(()) => println!("Paid {} robux to {}", args.robux, args.user_id),
+        Err(RoboatError::GroupPayoutError(GroupPayoutError::InsufficientFunds)) => {
+            println!("The group does not have enough robux.")
+        }
+        Err(e) => return Err(e.into()),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use clap::Parser;
+use roboat::economy::{RevenueTimeFrame, Transaction, TransactionType};
+use roboat::{ClientBuilder, Limit};
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    roblosecurity: String,
+    #[arg(long, short)]
+    group_id: u64,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+
+    let client = ClientBuilder::new()
+        .roblosecurity(args.roblosecurity)
+        .build();
+
+    let summary = client
+        .group_revenue_summary(args.group_id, RevenueTimeFrame::Month)
+        .await?;
+
+    println!("Item sale Robux (month): {}", summary.item_sale_robux);
+    println!("Premium payouts (month): {}", summary.group_premium_payouts);
+    println!("Group payouts (month): {}", summary.group_payout_robux);
+    println!("Pending Robux: {}", summary.pending_robux);
+
+    let (transactions, _) = client
+        .group_transactions(args.group_id, TransactionType::Sale, Limit::Hundred, None)
+        .await?;
+
+    println!("Last {} sales:", transactions.len());
+
+    for transaction in transactions {
+        if let Transaction::Sale(sale) = transaction {
+            println!(
+                "- {} bought {} for {} robux",
+                sale.user_display_name, sale.asset_name, sale.robux_received
+            );
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use clap::Parser;
+use roboat::hba::HbaSigner;
+use roboat::ClientBuilder;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    roblosecurity: String,
+    /// The path to the PKCS#8 PEM private key bound to the session.
+    #[arg(long, short)]
+    key_path: String,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+
+    let pem = std::fs::read_to_string(args.key_path)?;
+    let signer = HbaSigner::from_pkcs8_pem(&pem)?;
+
+    let client = ClientBuilder::new()
+        .roblosecurity(args.roblosecurity)
+        .hba_signer(signer)
+        .build();
+
+    let username = client.username().await?;
+    let robux = client.robux().await?;
+
+    println!("Robux for {}: {}", username, robux);
+
+    Ok(())
+}
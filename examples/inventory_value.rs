@@ -0,0 +1,31 @@
+use clap::Parser;
+use roboat::ids::UserId;
+use roboat::ClientBuilder;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    user_id: UserId,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+
+    let client = ClientBuilder::new().build();
+
+    // Values every item by its recent average price. Swap this out for a
+    // lookup into a value list to get a more accurate valuation.
+    let valuation = client.inventory_value(args.user_id, |_| None).await?;
+
+    println!("Total RAP: {}", valuation.total_recent_average_price);
+    println!("Total Value: {}", valuation.total_value);
+
+    println!("Top 10 Items:");
+
+    for item in valuation.items.iter().take(10) {
+        println!("{}: {}", item.collectible.name, item.value);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use clap::Parser;
+use roboat::ids::UserId;
+use roboat::ClientBuilder;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    roblosecurity: String,
+    #[arg(long, short)]
+    user_id: UserId,
+    /// Decline a friend request from the user instead of sending one.
+    #[arg(long, short)]
+    decline: bool,
+    /// Unfriend the user instead of sending a friend request.
+    #[arg(long, conflicts_with = "decline")]
+    unfriend: bool,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+    let client = ClientBuilder::new()
+        .roblosecurity(args.roblosecurity)
+        .build();
+
+    if args.decline {
+        client.decline_friend_request(args.user_id).await?;
+        println!("Declined friend request from {}", args.user_id);
+    } else if args.unfriend {
+        client.unfriend(args.user_id).await?;
+        println!("Unfriended {}", args.user_id);
+    } else {
+        client.send_friend_request(args.user_id).await?;
+        println!("Sent friend request to {}", args.user_id);
+    }
+
+    Ok(())
+}
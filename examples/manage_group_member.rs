@@ -0,0 +1,43 @@
+use clap::Parser;
+use roboat::ids::UserId;
+use roboat::ClientBuilder;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    roblosecurity: String,
+    #[arg(long, short)]
+    group_id: u64,
+    #[arg(long, short)]
+    user_id: UserId,
+    /// The role to move the member to. Use `get_group_roles` to find role ids.
+    #[arg(long, short = 'R', required_unless_present = "kick")]
+    role_id: Option<u64>,
+    /// Kick the member from the group instead of changing their role.
+    #[arg(long, conflicts_with = "role_id")]
+    kick: bool,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+    let client = ClientBuilder::new()
+        .roblosecurity(args.roblosecurity)
+        .build();
+
+    match args.role_id {
+        Some(role_id) => {
+            client
+                .set_member_role(args.group_id, args.user_id, role_id)
+                .await?;
+
+            println!("Set role of {} to {}", args.user_id, role_id);
+        }
+        None => {
+            client.kick_member(args.group_id, args.user_id).await?;
+            println!("Kicked {} from group {}", args.user_id, args.group_id);
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use clap::Parser;
+use roboat::groups::GroupRelationshipType;
+use roboat::ClientBuilder;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    roblosecurity: String,
+    #[arg(long, short)]
+    group_id: u64,
+    #[arg(long, short = 'G')]
+    related_group_id: u64,
+    /// Declare the group an enemy instead of sending an ally request.
+    #[arg(long, short)]
+    enemy: bool,
+    /// Remove the relationship instead of adding it.
+    #[arg(long)]
+    remove: bool,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+
+    let client = ClientBuilder::new()
+        .roblosecurity(args.roblosecurity)
+        .build();
+
+    let relationship_type = match args.enemy {
+        true => GroupRelationshipType::Enemies,
+        false => GroupRelationshipType::Allies,
+    };
+
+    if args.remove {
+        client
+            .remove_group_relationship(args.group_id, relationship_type, args.related_group_id)
+            .await?;
+
+        println!("Removed {:?} relationship", relationship_type);
+    } else {
+        client
+            .add_group_relationship(args.group_id, relationship_type, args.related_group_id)
+            .await?;
+
+        println!("Added {:?} relationship", relationship_type);
+    }
+
+    Ok(())
+}
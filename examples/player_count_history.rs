@@ -0,0 +1,41 @@
+use clap::Parser;
+use roboat::history::{downsample, PlayerCountSample};
+use roboat::ids::UniverseId;
+use roboat::ClientBuilder;
+use std::time::Duration;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    universe_id: UniverseId,
+    /// The amount of seconds between samples.
+    #[arg(long, short, default_value_t = 10)]
+    interval: u64,
+    /// The amount of samples to take.
+    #[arg(long, short, default_value_t = 30)]
+    samples: usize,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+
+    let client = ClientBuilder::new().build();
+
+    let mut samples: Vec<PlayerCountSample> = Vec::new();
+
+    client
+        .record_player_counts(
+            args.universe_id,
+            Duration::from_secs(args.interval),
+            args.samples,
+            &mut samples,
+        )
+        .await?;
+
+    for sample in downsample(&samples, Duration::from_secs(60)) {
+        println!("{}: {} players", sample.timestamp, sample.playing);
+    }
+
+    Ok(())
+}
@@ -1,4 +1,6 @@
 use clap::Parser;
+use roboat::economy::PurchaseArgs;
+use roboat::ids::{AssetId, Uaid};
 use roboat::ClientBuilder;
 
 #[derive(Parser, Debug)]
@@ -6,13 +8,20 @@ struct Args {
     #[arg(long, short)]
     roblosecurity: String,
     #[arg(long, short)]
-    item_id: u64,
+    item_id: AssetId,
     #[arg(long, short)]
     seller_id: u64,
     #[arg(long, short)]
-    uaid: u64,
+    uaid: Uaid,
     #[arg(long, short)]
     price: u64,
+    /// Buys the item on behalf of this group instead of the current account.
+    #[arg(long, short)]
+    group_id: Option<u64>,
+    /// Retries the purchase up to this many attempts on errors that can still go through.
+    /// Ignored when buying for a group.
+    #[arg(long, short)]
+    attempts: Option<usize>,
 }
 
 #[tokio::main]
@@ -29,7 +38,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let item_args = roboat::catalog::avatar_catalog::ItemArgs {
         item_type: roboat::catalog::avatar_catalog::ItemType::Asset,
-        id: item_id,
+        id: item_id.0,
     };
 
     let product_id = client
@@ -39,12 +48,45 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .unwrap()
         .product_id;
 
-    let result = client
-        .purchase_limited(product_id, seller_id, uaid, price)
-        .await;
+    let result = match (args.group_id, args.attempts) {
+        (Some(group_id), _) => {
+            client
+                .purchase_limited_for_group(group_id, product_id, seller_id, uaid, price)
+                .await
+        }
+        (None, Some(attempts)) => {
+            let purchase_args = PurchaseArgs {
+                product_id,
+                seller_id,
+                uaid,
+                price,
+            };
+
+            let purchase = client
+                .purchase_limited_with_retries(purchase_args, attempts)
+                .await;
+
+            for attempt in &purchase.failed_attempts {
+                println!(
+                    "[{}] Attempt failed: {}",
+                    attempt.attempted_at, attempt.error
+                );
+            }
+
+            purchase.result
+        }
+        (None, None) => {
+            client
+                .purchase_limited(product_id, seller_id, uaid, price)
+                .await
+        }
+    };
 
     match result {
-        Ok(()) => println!("Purchased item for {} robux.", price),
+        Ok(receipt) => println!(
+            "Purchased uaid {} for {} robux at {}.",
+            receipt.uaid, receipt.price, receipt.purchased_at
+        ),
         Err(e) => println!("Failed to purchase item for {} robux. Reason: {}", price, e),
     }
 
@@ -0,0 +1,40 @@
+use clap::Parser;
+use roboat::ids::ProductId;
+use roboat::ClientBuilder;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    roblosecurity: String,
+    #[arg(long, short)]
+    product_id: ProductId,
+    #[arg(long, short = 'c')]
+    price: u64,
+    /// The id of the creator of the product. This is a group id if a group owns it.
+    #[arg(long, short)]
+    seller_id: u64,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+    let client = ClientBuilder::new()
+        .roblosecurity(args.roblosecurity)
+        .build();
+
+    match client
+        .purchase_product(args.product_id, args.price, args.seller_id)
+        .await
+    {
+        Ok(receipt) => println!(
+            "Purchased product {} for {} robux at {}.",
+            receipt.product_id, receipt.price, receipt.purchased_at
+        ),
+        Err(e) => println!(
+            "Failed to purchase product for {} robux. Reason: {}",
+            args.price, e
+        ),
+    }
+
+    Ok(())
+}
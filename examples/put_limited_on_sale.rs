@@ -1,4 +1,5 @@
 use clap::Parser;
+use roboat::ids::{AssetId, Uaid};
 use roboat::ClientBuilder;
 
 #[derive(Parser, Debug)]
@@ -6,9 +7,9 @@ struct Args {
     #[arg(long, short)]
     roblosecurity: String,
     #[arg(long, short)]
-    item_id: u64,
+    item_id: AssetId,
     #[arg(long, short)]
-    uaid: u64,
+    uaid: Uaid,
     #[arg(long, short)]
     price: u64,
 }
@@ -24,6 +25,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let uaid = args.uaid;
     let price = args.price;
 
+    if let Err(e) = client.validate_listing_price(item_id, price).await {
+        println!("Cannot put item {} on sale. Reason: {}", item_id, e);
+        return Ok(());
+    }
+
     let result = client.put_limited_on_sale(item_id, uaid, price).await;
 
     match result {
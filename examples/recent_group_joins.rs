@@ -0,0 +1,37 @@
+use clap::Parser;
+use roboat::ClientBuilder;
+use std::time::Duration;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    group_id: u64,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+
+    let client = ClientBuilder::new().build();
+
+    let members = client.recent_group_joins(args.group_id, None).await?;
+    let mut newest_member_id = members.first().map(|member| member.user_id);
+
+    println!("Waiting for new members...");
+
+    loop {
+        tokio::time::sleep(Duration::from_secs(60)).await;
+
+        let new_members = client
+            .recent_group_joins(args.group_id, newest_member_id)
+            .await?;
+
+        for member in new_members.iter().rev() {
+            println!("Welcome, {} (@{})!", member.display_name, member.username);
+        }
+
+        if let Some(member) = new_members.first() {
+            newest_member_id = Some(member.user_id);
+        }
+    }
+}
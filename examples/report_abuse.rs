@@ -0,0 +1,44 @@
+use clap::Parser;
+use roboat::abuse_reporting::AbuseCategory;
+use roboat::ids::{AssetId, UserId};
+use roboat::ClientBuilder;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    roblosecurity: String,
+    /// Report this user for scamming.
+    #[arg(long, short, required_unless_present = "asset_id")]
+    user_id: Option<UserId>,
+    /// Report this asset for inappropriate content.
+    #[arg(long, short, conflicts_with = "user_id")]
+    asset_id: Option<AssetId>,
+    #[arg(long, short)]
+    comment: String,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+    let client = ClientBuilder::new()
+        .roblosecurity(args.roblosecurity)
+        .build();
+
+    if let Some(user_id) = args.user_id {
+        client
+            .report_user(user_id, AbuseCategory::Scamming, &args.comment)
+            .await?;
+
+        println!("Reported user {}", user_id);
+    }
+
+    if let Some(asset_id) = args.asset_id {
+        client
+            .report_asset(asset_id, AbuseCategory::InappropriateContent, &args.comment)
+            .await?;
+
+        println!("Reported asset {}", asset_id);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use clap::Parser;
+use roboat::ids::AssetId;
+use roboat::ClientBuilder;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    item_id: AssetId,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+    let client = ClientBuilder::new().build();
+
+    let ids = client.resolve_item_ids(args.item_id).await?;
+
+    println!("Asset Id: {}", ids.asset_id);
+    println!("Product Id: {}", ids.product_id);
+
+    match (ids.collectible_item_id, ids.collectible_product_id) {
+        (Some(collectible_item_id), Some(collectible_product_id)) => {
+            println!("Collectible Item Id: {}", collectible_item_id);
+            println!("Collectible Product Id: {}", collectible_product_id);
+        }
+        _ => println!("Item is not a collectible."),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use clap::Parser;
+use roboat::ClientBuilder;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    roblosecurity: String,
+    #[arg(long, short)]
+    trade_id: u64,
+    /// Decline the trade instead of accepting it.
+    #[arg(long, short)]
+    decline: bool,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+    let client = ClientBuilder::new()
+        .roblosecurity(args.roblosecurity)
+        .build();
+
+    if args.decline {
+        client.decline_trade(args.trade_id).await?;
+        println!("Declined trade {}", args.trade_id);
+    } else {
+        client.accept_trade(args.trade_id).await?;
+        println!("Accepted trade {}", args.trade_id);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use clap::Parser;
+use roboat::ids::AssetId;
+use roboat::ClientBuilder;
+use std::time::Duration;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    roblosecurity: String,
+    #[arg(long, short)]
+    item_id: AssetId,
+    /// The amount of days to look back.
+    #[arg(long, short, default_value_t = 30)]
+    days: u64,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+
+    let client = ClientBuilder::new()
+        .roblosecurity(args.roblosecurity)
+        .build();
+
+    let window = Duration::from_secs(args.days * 86400);
+    let velocity = client.sales_velocity(args.item_id, window).await?;
+
+    println!("Sales: {}", velocity.total_sales);
+    println!("Sales per day: {:.2}", velocity.average_daily_sales);
+
+    match velocity.average_price {
+        Some(price) => println!("Average price: {}", price),
+        None => println!("Average price: no sales"),
+    }
+
+    match velocity.price_trend {
+        Some(trend) => println!("Price trend: {:+.1}%", trend),
+        None => println!("Price trend: not enough sales"),
+    }
+
+    Ok(())
+}
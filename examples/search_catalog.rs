@@ -0,0 +1,54 @@
+use clap::Parser;
+use roboat::catalog::avatar_catalog::{CatalogSearchQuery, SalesType, SortType};
+use roboat::ClientBuilder;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    keyword: String,
+    /// Only shows items up to this price.
+    #[arg(long, short)]
+    max_price: Option<u64>,
+    /// Only shows collectibles.
+    #[arg(long, short)]
+    collectibles_only: bool,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+
+    let client = ClientBuilder::new().build();
+
+    let mut query = CatalogSearchQuery::new()
+        .keyword(args.keyword)
+        .price_range(None, args.max_price)
+        .sort_type(SortType::PriceAsc);
+
+    if args.collectibles_only {
+        query = query.sales_type(SalesType::Collectibles);
+    }
+
+    let (items, next_cursor) = client.search_catalog(query.clone()).await?;
+
+    for item in items {
+        println!(
+            "{} ({}) by {} - {:?} robux",
+            item.name, item.id, item.creator_name, item.price
+        );
+    }
+
+    // Fetches the second page using the cursor of the first one.
+    if let Some(cursor) = next_cursor {
+        let (items, _) = client.search_catalog(query.cursor(cursor)).await?;
+
+        for item in items {
+            println!(
+                "{} ({}) by {} - {:?} robux",
+                item.name, item.id, item.creator_name, item.price
+            );
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,28 @@
+use clap::Parser;
+use roboat::ClientBuilder;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    query: String,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+    let client = ClientBuilder::new().build();
+
+    let ranked_users = client.search_users_ranked(args.query, 10).await?;
+
+    for (i, ranked_user) in ranked_users.iter().enumerate() {
+        println!(
+            "#{} {} ({}) - Score: {:.2}",
+            i + 1,
+            ranked_user.user.username,
+            ranked_user.user.user_id,
+            ranked_user.score
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use clap::Parser;
+use roboat::ids::{Uaid, UserId};
+use roboat::trades::TradeOffer;
+use roboat::ClientBuilder;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    roblosecurity: String,
+    #[arg(long, short)]
+    partner_id: UserId,
+    /// The uaids the current account gives.
+    #[arg(long, value_delimiter = ',')]
+    offer_uaids: Vec<Uaid>,
+    /// The robux the current account gives.
+    #[arg(long, default_value_t = 0)]
+    offer_robux: u64,
+    /// The uaids asked for from the partner.
+    #[arg(long, value_delimiter = ',')]
+    request_uaids: Vec<Uaid>,
+    /// The robux asked for from the partner.
+    #[arg(long, default_value_t = 0)]
+    request_robux: u64,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+    let client = ClientBuilder::new()
+        .roblosecurity(args.roblosecurity)
+        .build();
+
+    let offer = TradeOffer {
+        uaids: args.offer_uaids,
+        robux: args.offer_robux,
+    };
+
+    let request = TradeOffer {
+        uaids: args.request_uaids,
+        robux: args.request_robux,
+    };
+
+    let trade_id = client.send_trade(args.partner_id, offer, request).await?;
+
+    println!("Sent trade {} to user {}", trade_id, args.partner_id);
+
+    Ok(())
+}
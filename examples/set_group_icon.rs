@@ -0,0 +1,28 @@
+use clap::Parser;
+use roboat::ClientBuilder;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    roblosecurity: String,
+    #[arg(long, short)]
+    group_id: u64,
+    #[arg(long, short)]
+    path: String,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+
+    let client = ClientBuilder::new()
+        .roblosecurity(args.roblosecurity)
+        .build();
+
+    let image_bytes = std::fs::read(&args.path)?;
+
+    let state = client.set_group_icon(args.group_id, image_bytes).await?;
+    println!("Uploaded icon. Moderation state: {:?}", state);
+
+    Ok(())
+}
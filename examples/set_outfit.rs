@@ -0,0 +1,33 @@
+use clap::Parser;
+use roboat::ids::AssetId;
+use roboat::ClientBuilder;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    roblosecurity: String,
+    /// The asset ids to wear, separated by commas. Everything else is taken off.
+    #[arg(long, short, value_delimiter = ',', required = true)]
+    asset_ids: Vec<AssetId>,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+
+    let client = ClientBuilder::new()
+        .roblosecurity(args.roblosecurity)
+        .build();
+
+    client.set_wearing_assets(args.asset_ids).await?;
+
+    let worn_assets = client.wearing_assets().await?;
+
+    println!("Now wearing:");
+
+    for asset in worn_assets {
+        println!("- {} ({})", asset.name, asset.asset_id);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use clap::Parser;
+use roboat::ClientBuilder;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    roblosecurity: String,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+
+    let client = ClientBuilder::new()
+        .roblosecurity(args.roblosecurity.clone())
+        .build();
+
+    let other_client = ClientBuilder::new()
+        .roblosecurity(args.roblosecurity)
+        .build();
+
+    // The first request fetches an X-CSRF-TOKEN through a failed request and retries.
+    client.register_presence().await?;
+
+    let xcsrf = client.current_xcsrf().await;
+    println!("Fetched X-CSRF-TOKEN: {}", xcsrf);
+
+    // The other client can now skip the failed request.
+    other_client.set_xcsrf_manual(xcsrf).await;
+    other_client.register_presence().await?;
+
+    println!("Registered presence from both clients!");
+
+    Ok(())
+}
@@ -1,4 +1,5 @@
 use clap::Parser;
+use roboat::ids::{AssetId, Uaid};
 use roboat::ClientBuilder;
 
 #[derive(Parser, Debug)]
@@ -6,9 +7,9 @@ struct Args {
     #[arg(long, short)]
     roblosecurity: String,
     #[arg(long, short)]
-    item_id: u64,
+    item_id: AssetId,
     #[arg(long, short)]
-    uaid: u64,
+    uaid: Uaid,
 }
 
 #[tokio::main]
@@ -0,0 +1,37 @@
+use clap::Parser;
+use roboat::catalog::avatar_catalog::{ItemArgs, ItemType};
+use roboat::ClientBuilder;
+
+#[derive(Parser, Debug)]
+struct Args {
+    /// The asset ids of the items.
+    #[arg(long, short, value_delimiter = ',')]
+    item_ids: Vec<u64>,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+    let client = ClientBuilder::new().build();
+
+    let items = args
+        .item_ids
+        .iter()
+        .map(|id| ItemArgs {
+            item_type: ItemType::Asset,
+            id: *id,
+        })
+        .collect();
+
+    let batch = client.try_item_details(items).await;
+
+    for (i, details) in &batch.successes {
+        println!("{}: {} ({})", i, details.name, details.id);
+    }
+
+    for (i, e) in &batch.failures {
+        println!("Chunk starting at item {} failed: {}", i, e);
+    }
+
+    Ok(())
+}
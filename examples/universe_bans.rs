@@ -0,0 +1,73 @@
+use clap::Parser;
+use roboat::ids::{UniverseId, UserId};
+use roboat::ClientBuilder;
+use std::time::Duration;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    api_key: String,
+    #[arg(long, short)]
+    universe_id: UniverseId,
+    /// Ban this user from the universe.
+    #[arg(long)]
+    ban: Option<UserId>,
+    /// How many days the ban lasts for. The ban is permanent if this is not given.
+    #[arg(long, requires = "ban")]
+    days: Option<u64>,
+    #[arg(long, short, default_value = "Breaking the rules")]
+    reason: String,
+    /// Unban this user from the universe.
+    #[arg(long, conflicts_with = "ban")]
+    unban: Option<UserId>,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+
+    let client = ClientBuilder::new().api_key(args.api_key).build();
+
+    if let Some(user_id) = args.ban {
+        let duration = args
+            .days
+            .map(|days| Duration::from_secs(days.saturating_mul(86400)));
+
+        client
+            .ban_user_from_universe(args.universe_id, user_id, duration, &args.reason)
+            .await?;
+
+        println!("Banned {} from universe {}", user_id, args.universe_id);
+    }
+
+    if let Some(user_id) = args.unban {
+        client
+            .unban_user_from_universe(args.universe_id, user_id)
+            .await?;
+
+        println!("Unbanned {} from universe {}", user_id, args.universe_id);
+    }
+
+    let (restrictions, _) = client
+        .universe_user_restrictions(args.universe_id, None)
+        .await?;
+
+    println!("Active bans (first page):");
+
+    for restriction in restrictions.iter().filter(|x| x.active) {
+        match restriction.duration {
+            Some(duration) => println!(
+                "- {} for {} days: {}",
+                restriction.user_id,
+                duration.as_secs() / 86400,
+                restriction.display_reason
+            ),
+            None => println!(
+                "- {} permanently: {}",
+                restriction.user_id, restriction.display_reason
+            ),
+        }
+    }
+
+    Ok(())
+}
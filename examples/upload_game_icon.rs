@@ -0,0 +1,43 @@
+use clap::Parser;
+use roboat::ids::UniverseId;
+use roboat::ClientBuilder;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    roblosecurity: String,
+    #[arg(long, short)]
+    universe_id: UniverseId,
+    #[arg(long, short)]
+    path: String,
+    /// The ids of the game's thumbnails, in the order they should be shown.
+    #[arg(long, short, value_delimiter = ',')]
+    thumbnail_order: Vec<u64>,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+
+    let client = ClientBuilder::new()
+        .roblosecurity(args.roblosecurity)
+        .build();
+
+    let image_bytes = std::fs::read(&args.path)?;
+
+    let icon_id = client
+        .upload_game_icon(args.universe_id, image_bytes)
+        .await?;
+
+    println!("Uploaded icon {}.", icon_id);
+
+    if !args.thumbnail_order.is_empty() {
+        client
+            .set_game_thumbnail_order(args.universe_id, args.thumbnail_order)
+            .await?;
+
+        println!("Reordered thumbnails.");
+    }
+
+    Ok(())
+}
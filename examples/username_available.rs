@@ -0,0 +1,33 @@
+use chrono::NaiveDate;
+use clap::Parser;
+use roboat::ClientBuilder;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    username: String,
+    /// The birthday of the account, in the format YYYY-MM-DD.
+    #[arg(long, short, default_value = "2000-01-01")]
+    birthday: NaiveDate,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+
+    let client = ClientBuilder::new().build();
+
+    let validation = client
+        .username_available(&args.username, args.birthday)
+        .await?;
+
+    match validation.available {
+        true => println!("{} is available.", args.username),
+        false => println!(
+            "{} is not available: {} (code {})",
+            args.username, validation.message, validation.code
+        ),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use clap::Parser;
+use roboat::ids::{PlaceId, UserId};
+use roboat::ClientBuilder;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    user_id: UserId,
+    #[arg(long, short)]
+    place_id: PlaceId,
+    /// Also list the universes the roblosecurity's account can edit.
+    #[arg(long, short)]
+    roblosecurity: Option<String>,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+
+    let list_universes = args.roblosecurity.is_some();
+
+    let client = match args.roblosecurity {
+        Some(roblosecurity) => ClientBuilder::new().roblosecurity(roblosecurity).build(),
+        None => ClientBuilder::new().build(),
+    };
+
+    let can_manage = client.can_manage_place(args.user_id, args.place_id).await?;
+
+    println!(
+        "User {} can manage place {}: {}",
+        args.user_id, args.place_id, can_manage
+    );
+
+    if list_universes {
+        let mut cursor = None;
+
+        loop {
+            let (universes, next_cursor) = client.authorized_universes(cursor).await?;
+
+            for universe in universes {
+                println!(
+                    "{} (universe {}, root place {:?})",
+                    universe.name, universe.universe_id, universe.root_place_id
+                );
+            }
+
+            cursor = match next_cursor {
+                Some(next_cursor) => Some(next_cursor),
+                None => break,
+            };
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use clap::Parser;
+use roboat::economy::AssetMetadataChange;
+use roboat::ids::AssetId;
+use roboat::ClientBuilder;
+use std::time::Duration;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    asset_id: AssetId,
+    /// The amount of seconds between checks.
+    #[arg(long, short, default_value_t = 60)]
+    interval: u64,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+    let client = ClientBuilder::new().build();
+
+    let mut previous = client.asset_version_metadata(args.asset_id).await?;
+
+    println!("Watching {} ({})...", previous.name, previous.asset_id);
+
+    loop {
+        tokio::time::sleep(Duration::from_secs(args.interval)).await;
+
+        let current = client.asset_version_metadata(args.asset_id).await?;
+
+        // Nothing can have changed if the asset was not updated.
+        if current.updated == previous.updated {
+            continue;
+        }
+
+        for change in current.changes_since(&previous) {
+            match change {
+                AssetMetadataChange::Name { old, new } => {
+                    println!("Name changed from {:?} to {:?}", old, new)
+                }
+                AssetMetadataChange::Description { old, new } => {
+                    println!("Description changed from {:?} to {:?}", old, new)
+                }
+            }
+        }
+
+        previous = current;
+    }
+}
@@ -0,0 +1,56 @@
+use clap::Parser;
+use futures_util::StreamExt;
+use roboat::polling::{AdaptivePoller, PollInterval};
+use roboat::ClientBuilder;
+use std::time::Duration;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    roblosecurity: String,
+    #[arg(long, short)]
+    group_id: u64,
+    /// The amount of seconds between polls.
+    #[arg(long, short, default_value_t = 30)]
+    interval: u64,
+    /// Slows down polling when rate limited, when the audit log is quiet,
+    /// and from midnight to 8 AM (UTC).
+    #[arg(long, short)]
+    adaptive: bool,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+
+    let client = ClientBuilder::new()
+        .roblosecurity(args.roblosecurity)
+        .build();
+
+    let interval = Duration::from_secs(args.interval);
+
+    let interval = match args.adaptive {
+        true => PollInterval::from(AdaptivePoller::new(interval).quiet_hours(0, 8, 4)),
+        false => PollInterval::from(interval),
+    };
+
+    let mut entries = client.watch_group_audit_log(args.group_id, interval);
+
+    println!("Watching the audit log of group {}...", args.group_id);
+
+    while let Some(entry) = entries.next().await {
+        match entry {
+            Ok(entry) => println!(
+                "[{}] {} ({}): {} - {}",
+                entry.created,
+                entry.actor.username,
+                entry.actor.role.name,
+                entry.action_type,
+                entry.description
+            ),
+            Err(e) => println!("Failed to poll the audit log: {}", e),
+        }
+    }
+
+    Ok(())
+}
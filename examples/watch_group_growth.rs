@@ -0,0 +1,34 @@
+use clap::Parser;
+use futures_util::StreamExt;
+use roboat::ClientBuilder;
+use std::time::Duration;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    group_id: u64,
+    /// The amount of seconds between polls.
+    #[arg(long, short, default_value_t = 300)]
+    interval: u64,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+
+    let client = ClientBuilder::new().build();
+
+    let mut growth = client.watch_group_growth(args.group_id, Duration::from_secs(args.interval));
+
+    while let Some(growth) = growth.next().await {
+        match growth {
+            Ok(growth) => println!(
+                "[{}] {} members ({:+})",
+                growth.timestamp, growth.member_count, growth.change
+            ),
+            Err(e) => println!("Failed to poll the member count: {}", e),
+        }
+    }
+
+    Ok(())
+}
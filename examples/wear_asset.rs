@@ -0,0 +1,39 @@
+use clap::Parser;
+use roboat::ids::AssetId;
+use roboat::ClientBuilder;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    roblosecurity: String,
+    #[arg(long, short)]
+    asset_id: AssetId,
+    /// Take the asset off instead of putting it on.
+    #[arg(long, short)]
+    unwear: bool,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+
+    let client = ClientBuilder::new()
+        .roblosecurity(args.roblosecurity)
+        .build();
+
+    if args.unwear {
+        client.unwear_asset(args.asset_id).await?;
+        println!("Took off {}", args.asset_id);
+    } else {
+        let removed = client.wear_asset(args.asset_id).await?;
+        println!("Wearing {} (took off {:?})", args.asset_id, removed);
+    }
+
+    let worn_assets = client.wearing_assets().await?;
+
+    for asset in worn_assets {
+        println!("- {} ({})", asset.name, asset.asset_id);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,27 @@
+use clap::Parser;
+use roboat::ClientBuilder;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(long, short)]
+    roblosecurity: String,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+    let client = ClientBuilder::new()
+        .roblosecurity(args.roblosecurity)
+        .build();
+
+    let me = client.whoami().await?;
+
+    println!("Username: {}", me.username);
+    println!("Display Name: {}", me.display_name);
+    println!("User ID: {}", me.user_id);
+    println!("Robux: {}", me.robux);
+    println!("Premium: {}", me.has_premium);
+    println!("2-Step Verification: {}", me.two_step_verification_enabled);
+
+    Ok(())
+}
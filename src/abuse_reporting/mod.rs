@@ -0,0 +1,288 @@
+use crate::ids::{AssetId, UserId};
+use crate::{Client, RoboatError};
+use serde::{Deserialize, Serialize};
+
+mod request_types;
+
+const ABUSE_REPORT_API: &str = "https://apis.roblox.com/abuse-reporting/v1/abuse-report";
+
+/// The longest comment, in characters, that can be attached to an abuse report.
+const COMMENT_LENGTH_LIMIT: usize = 500;
+
+/// The reason a user or asset is reported, used by [`Client::report_user`] and
+/// [`Client::report_asset`].
+#[derive(
+    Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize, Copy,
+)]
+pub enum AbuseCategory {
+    /// Scamming, such as fake trades or phishing links.
+    #[default]
+    Scamming,
+    /// Bullying or harassment.
+    Bullying,
+    /// Swearing or other inappropriate language.
+    Swearing,
+    /// Asking for or sharing personal information.
+    PersonalInformation,
+    /// Links to sites outside of Roblox.
+    OffsiteLinks,
+    /// Dating or romantic roleplay.
+    Dating,
+    /// Exploiting or cheating.
+    Cheating,
+    /// Inappropriate content, such as in an asset or a profile.
+    InappropriateContent,
+    /// Threats of real life harm.
+    Threats,
+    /// Pretending to be another user or Roblox.
+    Impersonation,
+    /// Content that infringes on someone's rights, such as a copied asset.
+    Infringement,
+}
+
+impl AbuseCategory {
+    /// Returns the name of the category as sent in an abuse report.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Scamming => "scamming",
+            Self::Bullying => "bullying",
+            Self::Swearing => "swearing",
+            Self::PersonalInformation => "personal_information",
+            Self::OffsiteLinks => "offsite_links",
+            Self::Dating => "dating",
+            Self::Cheating => "cheating",
+            Self::InappropriateContent => "inappropriate_content",
+            Self::Threats => "threats",
+            Self::Impersonation => "impersonation",
+            Self::Infringement => "infringement",
+        }
+    }
+}
+
+impl Client {
+    /// Reports a user for abuse using <https://apis.roblox.com/abuse-reporting/v1/abuse-report>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    /// * Reports go to Roblox moderation, so only report users that actually broke the rules.
+    ///
+    /// # Argument Notes
+    /// * `comment` can be up to 500 characters long, and can be empty.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    /// * [`RoboatError::InvalidMessage`] - If `comment` is too long.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::abuse_reporting::AbuseCategory;
+    /// use roboat::ids::UserId;
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let user_id = UserId(1);
+    /// let comment = "Sent a phishing link in a trade message.";
+    ///
+    /// client
+    ///     .report_user(user_id, AbuseCategory::Scamming, comment)
+    ///     .await?;
+    ///
+    /// println!("Reported user.");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn report_user(
+        &self,
+        user_id: UserId,
+        category: AbuseCategory,
+        comment: &str,
+    ) -> Result<(), RoboatError> {
+        match self
+            .abuse_report_internal(internal::ReportTarget::User(user_id), category, comment)
+            .await
+        {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.abuse_report_internal(
+                        internal::ReportTarget::User(user_id),
+                        category,
+                        comment,
+                    )
+                    .await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Reports an asset (such as a shirt or a model) for abuse using
+    /// <https://apis.roblox.com/abuse-reporting/v1/abuse-report>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    /// * Reports go to Roblox moderation, so only report assets that actually broke the rules.
+    ///
+    /// # Argument Notes
+    /// * `comment` can be up to 500 characters long, and can be empty.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    /// * [`RoboatError::InvalidMessage`] - If `comment` is too long.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::abuse_reporting::AbuseCategory;
+    /// use roboat::ids::AssetId;
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let asset_id = AssetId(1234567890);
+    /// let comment = "Copy of a limited used to trick buyers.";
+    ///
+    /// client
+    ///     .report_asset(asset_id, AbuseCategory::Scamming, comment)
+    ///     .await?;
+    ///
+    /// println!("Reported asset.");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn report_asset(
+        &self,
+        asset_id: AssetId,
+        category: AbuseCategory,
+        comment: &str,
+    ) -> Result<(), RoboatError> {
+        match self
+            .abuse_report_internal(internal::ReportTarget::Asset(asset_id), category, comment)
+            .await
+        {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.abuse_report_internal(
+                        internal::ReportTarget::Asset(asset_id),
+                        category,
+                        comment,
+                    )
+                    .await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+}
+
+mod internal {
+    use super::{request_types, AbuseCategory, ABUSE_REPORT_API, COMMENT_LENGTH_LIMIT};
+    use crate::ids::{AssetId, UserId};
+    use crate::{Client, RoboatError, XCSRF_HEADER};
+    use reqwest::header;
+    use std::collections::HashMap;
+
+    /// The place reports are made from, as sent in an abuse report.
+    const ENTRY_POINT: &str = "website";
+
+    /// What an abuse report is about.
+    #[derive(Clone, Copy, Debug)]
+    pub(super) enum ReportTarget {
+        User(UserId),
+        Asset(AssetId),
+    }
+
+    impl ReportTarget {
+        /// Returns the tag the id of the target is sent under, the abuse vector, and the id.
+        fn parts(self) -> (&'static str, &'static str, u64) {
+            match self {
+                Self::User(user_id) => ("REPORT_TARGET_USER_ID", "user_profile", user_id.0),
+                Self::Asset(asset_id) => ("REPORT_TARGET_ASSET_ID", "asset", asset_id.0),
+            }
+        }
+    }
+
+    impl Client {
+        pub(super) async fn abuse_report_internal(
+            &self,
+            target: ReportTarget,
+            category: AbuseCategory,
+            comment: &str,
+        ) -> Result<(), RoboatError> {
+            if comment.chars().count() > COMMENT_LENGTH_LIMIT {
+                return Err(RoboatError::InvalidMessage(format!(
+                    "Comment must be at most {} characters",
+                    COMMENT_LENGTH_LIMIT
+                )));
+            }
+
+            let cookie = self.cookie_string()?;
+            let submitter_id = self.user_id().await?;
+
+            let (target_tag, abuse_vector, target_id) = target.parts();
+
+            let tags = HashMap::from([
+                (
+                    "ENTRY_POINT",
+                    request_types::TagRaw::new(ENTRY_POINT.to_string()),
+                ),
+                (
+                    "SUBMITTER_USER_ID",
+                    request_types::TagRaw::new(submitter_id.to_string()),
+                ),
+                (
+                    target_tag,
+                    request_types::TagRaw::new(target_id.to_string()),
+                ),
+                (
+                    "REPORTED_ABUSE_CATEGORY",
+                    request_types::TagRaw::new(category.as_str().to_string()),
+                ),
+                (
+                    "REPORTED_ABUSE_VECTOR",
+                    request_types::TagRaw::new(abuse_vector.to_string()),
+                ),
+                (
+                    "REPORTER_COMMENT",
+                    request_types::TagRaw::new(comment.to_string()),
+                ),
+            ]);
+
+            let body = request_types::AbuseReportReqBody { tags };
+
+            let request = self
+                .reqwest_client
+                .post(ABUSE_REPORT_API)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, self.xcsrf().await)
+                .json(&body);
+
+            let _ = self
+                .send_mutating_request(request, serde_json::json!({}))
+                .await?;
+
+            // We don't need to do anything, we just need a 200 status code.
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Serialize)]
+pub(super) struct AbuseReportReqBody {
+    pub tags: HashMap<&'static str, TagRaw>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct TagRaw {
+    pub value_list: Vec<TagValueRaw>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(super) struct TagValueRaw {
+    pub data: String,
+}
+
+impl TagRaw {
+    pub(super) fn new(data: String) -> Self {
+        Self {
+            value_list: vec![TagValueRaw { data }],
+        }
+    }
+}
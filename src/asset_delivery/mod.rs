@@ -0,0 +1,306 @@
+use crate::ids::AssetId;
+use crate::{Client, RoboatError};
+use reqwest::header::{self, HeaderValue};
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::io::Read;
+
+const ASSET_API: &str = "https://assetdelivery.roblox.com/v1/asset/?id=";
+
+/// The magic bytes at the start of a binary model or place file.
+const BINARY_MAGIC: &[u8] = b"<roblox!";
+/// The magic text at the start of an xml model or place file.
+const XML_MAGIC: &[u8] = b"<roblox";
+/// The size of the header of a binary model or place file.
+const BINARY_HEADER_LEN: usize = 32;
+/// The size of the header of each chunk in a binary model or place file.
+const CHUNK_HEADER_LEN: usize = 16;
+/// The magic bytes at the start of a zstd compressed chunk.
+const ZSTD_MAGIC: &[u8] = &[0x28, 0xB5, 0x2F, 0xFD];
+
+/// The patterns used to reference assets inside content properties (such as `MeshId` and `SoundId`).
+/// Each pattern is directly followed by the asset id.
+const ASSET_REFERENCE_PATTERNS: &[&[u8]] = &[b"rbxassetid://", b"asset/?id=", b"asset?id="];
+
+/// The assets referenced by an asset and all of its dependencies. Created by
+/// [`Client::asset_dependency_graph`].
+#[derive(Debug, Default)]
+pub struct AssetDependencyGraph {
+    /// The asset the graph was built from.
+    pub root_asset_id: AssetId,
+    /// Each asset that was downloaded, mapped to the assets it references directly.
+    /// Assets that are not models or places (such as meshes, images, and audio) do not
+    /// reference anything, so they map to an empty list.
+    pub dependencies: BTreeMap<AssetId, Vec<AssetId>>,
+    /// Assets that could not be downloaded (such as private audio), along with the error.
+    pub failures: Vec<(AssetId, RoboatError)>,
+}
+
+impl AssetDependencyGraph {
+    /// Returns every asset referenced by the root asset, directly or indirectly.
+    pub fn all_dependencies(&self) -> BTreeSet<AssetId> {
+        self.dependencies
+            .values()
+            .flatten()
+            .copied()
+            .filter(|asset_id| *asset_id != self.root_asset_id)
+            .collect()
+    }
+}
+
+impl Client {
+    /// Downloads the raw contents of an asset using <https://assetdelivery.roblox.com/v1/asset/?id={asset_id}>.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity, although Roblox only serves some assets
+    ///   (such as places and private audio) to accounts that have access to them.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ids::AssetId;
+    /// use roboat::ClientBuilder;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let asset_id = AssetId(1365767);
+    /// let bytes = client.download_asset(asset_id).await?;
+    ///
+    /// std::fs::write("valkyrie_helm.rbxm", bytes)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn download_asset(&self, asset_id: AssetId) -> Result<Vec<u8>, RoboatError> {
+        let formatted_url = format!("{}{}", ASSET_API, asset_id);
+
+        let cookie_string = self.cookie_string().unwrap_or(HeaderValue::from_static(""));
+
+        let request = self
+            .reqwest_client
+            .get(formatted_url)
+            .header(header::COOKIE, cookie_string);
+
+        let response = self.send_request(request).await?;
+
+        let bytes = response
+            .bytes()
+            .await
+            .map_err(RoboatError::ReqwestError)?
+            .to_vec();
+
+        Ok(bytes)
+    }
+
+    /// Downloads an asset (usually a model or place) and every asset it references
+    /// (meshes, textures, audio, other models, etc.), building a dependency graph.
+    ///
+    /// Assets are downloaded one at a time using [`Client::download_asset`], and references
+    /// are found using [`referenced_asset_ids`].
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity (see [`Client::download_asset`]).
+    /// * Stops downloading new assets once `max_assets` assets have been downloaded.
+    ///
+    /// # Errors
+    /// * Errors from downloading the root asset are returned. Errors from downloading
+    ///   dependencies are put in [`AssetDependencyGraph::failures`] instead.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ids::AssetId;
+    /// use roboat::ClientBuilder;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let asset_id = AssetId(1365767);
+    /// let graph = client.asset_dependency_graph(asset_id, 100).await?;
+    ///
+    /// for (asset_id, dependencies) in &graph.dependencies {
+    ///     println!("{} -> {:?}", asset_id, dependencies);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn asset_dependency_graph(
+        &self,
+        asset_id: AssetId,
+        max_assets: usize,
+    ) -> Result<AssetDependencyGraph, RoboatError> {
+        let mut graph = AssetDependencyGraph {
+            root_asset_id: asset_id,
+            ..Default::default()
+        };
+
+        let root_bytes = self.download_asset(asset_id).await?;
+        let root_dependencies = referenced_asset_ids(&root_bytes)?;
+
+        let mut seen = BTreeSet::from([asset_id]);
+        let mut queue = VecDeque::new();
+
+        for dependency in &root_dependencies {
+            if seen.insert(*dependency) {
+                queue.push_back(*dependency);
+            }
+        }
+
+        graph.dependencies.insert(asset_id, root_dependencies);
+
+        while let Some(asset_id) = queue.pop_front() {
+            if graph.dependencies.len() >= max_assets {
+                break;
+            }
+
+            let dependencies = match self.download_asset(asset_id).await {
+                // Dependencies that cannot be parsed are treated as having no references.
+                Ok(bytes) => referenced_asset_ids(&bytes).unwrap_or_default(),
+                Err(e) => {
+                    graph.failures.push((asset_id, e));
+                    continue;
+                }
+            };
+
+            for dependency in &dependencies {
+                if seen.insert(*dependency) {
+                    queue.push_back(*dependency);
+                }
+            }
+
+            graph.dependencies.insert(asset_id, dependencies);
+        }
+
+        Ok(graph)
+    }
+}
+
+/// Finds the ids of the assets referenced by a model or place file (such as meshes,
+/// textures, and audio), in both the binary (`rbxm`/`rbxl`) and xml (`rbxmx`/`rbxlx`) formats.
+///
+/// References are found by looking for content urls such as `rbxassetid://123` and
+/// `http://www.roblox.com/asset/?id=123`.
+///
+/// # Return Value Notes
+/// * The ids are sorted and deduplicated.
+/// * Files that are not models or places (such as images or audio) return an empty list.
+///
+/// # Errors
+/// * [`RoboatError::MalformedResponse`] - If the file is a binary model or place that cannot be decompressed.
+///
+/// # Example
+/// ```
+/// use roboat::asset_delivery::referenced_asset_ids;
+/// use roboat::ids::AssetId;
+///
+/// let model = br#"<roblox version="4">
+///     <Item class="MeshPart">
+///         <Properties>
+///             <Content name="MeshId"><url>rbxassetid://456</url></Content>
+///             <Content name="TextureID"><url>http://www.roblox.com/asset/?id=123</url></Content>
+///         </Properties>
+///     </Item>
+/// </roblox>"#;
+///
+/// assert_eq!(referenced_asset_ids(model).unwrap(), vec![AssetId(123), AssetId(456)]);
+/// assert!(referenced_asset_ids(b"\x89PNG").unwrap().is_empty());
+/// ```
+pub fn referenced_asset_ids(bytes: &[u8]) -> Result<Vec<AssetId>, RoboatError> {
+    let mut asset_ids = BTreeSet::new();
+
+    if bytes.starts_with(BINARY_MAGIC) {
+        for chunk in binary_chunks(bytes)? {
+            find_asset_references(&chunk, &mut asset_ids);
+        }
+    } else if bytes.starts_with(XML_MAGIC) {
+        find_asset_references(bytes, &mut asset_ids);
+    }
+
+    Ok(asset_ids.into_iter().collect())
+}
+
+/// Splits a binary model or place file into its decompressed chunks.
+fn binary_chunks(bytes: &[u8]) -> Result<Vec<Vec<u8>>, RoboatError> {
+    let mut chunks = Vec::new();
+    let mut position = BINARY_HEADER_LEN;
+
+    while position + CHUNK_HEADER_LEN <= bytes.len() {
+        let header = &bytes[position..position + CHUNK_HEADER_LEN];
+        let name = &header[0..4];
+        let compressed_len = u32::from_le_bytes(header[4..8].try_into().unwrap()) as usize;
+        let uncompressed_len = u32::from_le_bytes(header[8..12].try_into().unwrap()) as usize;
+
+        if name == b"END\0" {
+            break;
+        }
+
+        position += CHUNK_HEADER_LEN;
+
+        // A compressed length of 0 means the chunk is stored uncompressed.
+        let stored_len = match compressed_len {
+            0 => uncompressed_len,
+            _ => compressed_len,
+        };
+
+        let data = bytes
+            .get(position..position + stored_len)
+            .ok_or(RoboatError::MalformedResponse)?;
+
+        position += stored_len;
+
+        let chunk = match compressed_len {
+            0 => data.to_vec(),
+            _ if data.starts_with(ZSTD_MAGIC) => {
+                let mut decoder = ruzstd::StreamingDecoder::new(data)
+                    .map_err(|_| RoboatError::MalformedResponse)?;
+
+                let mut chunk = Vec::with_capacity(uncompressed_len);
+                decoder
+                    .read_to_end(&mut chunk)
+                    .map_err(|_| RoboatError::MalformedResponse)?;
+
+                chunk
+            }
+            _ => lz4_flex::block::decompress(data, uncompressed_len)
+                .map_err(|_| RoboatError::MalformedResponse)?,
+        };
+
+        chunks.push(chunk);
+    }
+
+    Ok(chunks)
+}
+
+/// Adds the ids of every asset reference in `bytes` to `asset_ids`.
+fn find_asset_references(bytes: &[u8], asset_ids: &mut BTreeSet<AssetId>) {
+    let lowercase = bytes.to_ascii_lowercase();
+
+    for pattern in ASSET_REFERENCE_PATTERNS {
+        let mut start = 0;
+
+        while let Some(offset) = find_subslice(&lowercase[start..], pattern) {
+            let id_start = start + offset + pattern.len();
+
+            let id_len = lowercase[id_start..]
+                .iter()
+                .take_while(|byte| byte.is_ascii_digit())
+                .count();
+
+            let asset_id = std::str::from_utf8(&lowercase[id_start..id_start + id_len])
+                .ok()
+                .and_then(|id| id.parse::<AssetId>().ok());
+
+            if let Some(asset_id) = asset_id {
+                asset_ids.insert(asset_id);
+            }
+
+            start = id_start;
+        }
+    }
+}
+
+/// Returns the index of the first occurrence of `needle` in `haystack`.
+fn find_subslice(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
@@ -0,0 +1,83 @@
+use crate::{Client, RoboatError};
+use chrono::NaiveDate;
+use reqwest::header::{self, HeaderValue};
+use serde::{Deserialize, Serialize};
+
+mod request_types;
+
+const USERNAME_VALIDATION_API: &str = "https://auth.roblox.com/v1/usernames/validate";
+
+/// The code Roblox returns when a username can be used.
+const VALID_USERNAME_CODE: u64 = 0;
+
+/// The result of checking whether a username can be used to sign up.
+/// Retrieved from <https://auth.roblox.com/v1/usernames/validate>.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct UsernameValidation {
+    /// Whether the username can be used to sign up.
+    pub available: bool,
+    /// The code Roblox gave the result. This is `0` if the username is available. Other codes
+    /// give the reason it is not (such as it being taken, too long, or inappropriate).
+    pub code: u64,
+    /// The message Roblox gave the result (such as "Username is valid" or
+    /// "This username is already in use.").
+    pub message: String,
+}
+
+impl Client {
+    /// Checks whether a username is available to sign up with, using
+    /// <https://auth.roblox.com/v1/usernames/validate?request.username={username}&request.birthday={birthday}&request.context=Signup>.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    /// * Roblox applies stricter filtering to the usernames of younger users, which is why
+    ///   the birthday of the account being created is required.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use chrono::NaiveDate;
+    /// use roboat::ClientBuilder;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let birthday = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap();
+    /// let validation = client.username_available("Builderman", birthday).await?;
+    ///
+    /// match validation.available {
+    ///     true => println!("Username is available!"),
+    ///     false => println!("Username is not available: {}", validation.message),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn username_available(
+        &self,
+        username: &str,
+        birthday: NaiveDate,
+    ) -> Result<UsernameValidation, RoboatError> {
+        let cookie_string = self.cookie_string().unwrap_or(HeaderValue::from_static(""));
+
+        let request = self
+            .reqwest_client
+            .get(USERNAME_VALIDATION_API)
+            .query(&[
+                ("request.username", username.to_string()),
+                ("request.birthday", birthday.format("%Y-%m-%d").to_string()),
+                ("request.context", "Signup".to_string()),
+            ])
+            .header(header::COOKIE, cookie_string);
+
+        let response = self.send_request(request).await?;
+        let raw = Self::parse_to_raw::<request_types::UsernameValidationResponse>(response).await?;
+
+        let validation = UsernameValidation {
+            available: raw.code == VALID_USERNAME_CODE,
+            code: raw.code,
+            message: raw.message,
+        };
+
+        Ok(validation)
+    }
+}
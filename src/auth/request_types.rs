@@ -0,0 +1,7 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+pub(super) struct UsernameValidationResponse {
+    pub code: u64,
+    pub message: String,
+}
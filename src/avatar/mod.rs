@@ -0,0 +1,319 @@
+use crate::catalog::avatar_catalog::{AssetType, ItemArgs, ItemType};
+use crate::ids::AssetId;
+use crate::{Client, RoboatError};
+use reqwest::header;
+use serde::{Deserialize, Serialize};
+
+mod request_types;
+
+const AVATAR_API: &str = "https://avatar.roblox.com/v1/avatar";
+const SET_WEARING_ASSETS_API: &str = "https://avatar.roblox.com/v1/avatar/set-wearing-assets";
+
+/// The maximum amount of accessories Roblox allows an avatar to wear at once.
+const MAX_WORN_ACCESSORIES: usize = 10;
+
+/// An asset worn by the avatar of the current account. Retrieved from <https://avatar.roblox.com/v1/avatar>.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct WornAsset {
+    /// The id of the asset.
+    pub asset_id: AssetId,
+    /// The name of the asset.
+    pub name: String,
+    /// The type of the asset. Types not known to this library (such as layered clothing)
+    /// are [`AssetType::Unknown`].
+    pub asset_type: AssetType,
+}
+
+impl Client {
+    /// Grabs the assets worn by the avatar of the current account from <https://avatar.roblox.com/v1/avatar>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let worn_assets = client.wearing_assets().await?;
+    ///
+    /// for asset in worn_assets {
+    ///     println!("Wearing {} ({:?})", asset.name, asset.asset_type);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn wearing_assets(&self) -> Result<Vec<WornAsset>, RoboatError> {
+        let cookie_string = self.cookie_string()?;
+
+        let request = self
+            .reqwest_client
+            .get(AVATAR_API)
+            .header(header::COOKIE, cookie_string);
+
+        let response = self.send_request(request).await?;
+        let raw = Self::parse_to_raw::<request_types::AvatarResponse>(response).await?;
+
+        let worn_assets = raw
+            .assets
+            .into_iter()
+            .map(|asset| WornAsset {
+                asset_id: asset.id,
+                name: asset.name,
+                asset_type: AssetType::from(asset.asset_type.id),
+            })
+            .collect();
+
+        Ok(worn_assets)
+    }
+
+    /// Replaces every asset worn by the avatar of the current account using
+    /// <https://avatar.roblox.com/v1/avatar/set-wearing-assets>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    ///
+    /// # Errors
+    /// * [`RoboatError::InvalidWearingAssets`] - If Roblox refused to wear some of the assets
+    ///   (such as assets that are not owned). The other assets are still worn.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ids::AssetId;
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let asset_ids = vec![AssetId(1365767), AssetId(48474313)];
+    /// client.set_wearing_assets(asset_ids).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_wearing_assets(&self, asset_ids: Vec<AssetId>) -> Result<(), RoboatError> {
+        match self.set_wearing_assets_internal(&asset_ids).await {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.set_wearing_assets_internal(&asset_ids).await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Puts an asset on the avatar of the current account, taking off whatever is in its way.
+    ///
+    /// The currently worn assets are grabbed using [`Client::wearing_assets`], and the type
+    /// of the new asset is grabbed using [`Client::item_details`]. Then:
+    /// * If the asset is an accessory and the avatar is already wearing the maximum amount of
+    ///   accessories (10), an accessory of the same type is taken off (or the first accessory,
+    ///   if none are the same type).
+    /// * Otherwise, any asset of the same type is taken off (an avatar can only wear
+    ///   one shirt, one face, etc.).
+    ///
+    /// The new set of assets is then worn using [`Client::set_wearing_assets`].
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Does nothing if the asset is already worn.
+    ///
+    /// # Return Value Notes
+    /// * Returns the ids of the assets that were taken off to make room for the new asset.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ids::AssetId;
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let asset_id = AssetId(1365767);
+    /// let removed = client.wear_asset(asset_id).await?;
+    ///
+    /// println!("Took off {:?} to wear {}", removed, asset_id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn wear_asset(&self, asset_id: AssetId) -> Result<Vec<AssetId>, RoboatError> {
+        let worn_assets = self.wearing_assets().await?;
+
+        if worn_assets.iter().any(|asset| asset.asset_id == asset_id) {
+            return Ok(Vec::new());
+        }
+
+        let item_args = ItemArgs {
+            item_type: ItemType::Asset,
+            id: asset_id.0,
+        };
+
+        let asset_type = self
+            .item_details(vec![item_args])
+            .await?
+            .pop()
+            .and_then(|details| details.asset_type);
+
+        let removed = conflicting_assets(&worn_assets, asset_type);
+
+        let mut asset_ids = worn_assets
+            .iter()
+            .map(|asset| asset.asset_id)
+            .filter(|worn_asset_id| !removed.contains(worn_asset_id))
+            .collect::<Vec<_>>();
+
+        asset_ids.push(asset_id);
+
+        self.set_wearing_assets(asset_ids).await?;
+
+        Ok(removed)
+    }
+
+    /// Takes an asset off the avatar of the current account. The currently worn assets are
+    /// grabbed using [`Client::wearing_assets`], and every other asset is kept on using
+    /// [`Client::set_wearing_assets`].
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Does nothing if the asset is not worn.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ids::AssetId;
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let asset_id = AssetId(1365767);
+    /// client.unwear_asset(asset_id).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn unwear_asset(&self, asset_id: AssetId) -> Result<(), RoboatError> {
+        let worn_assets = self.wearing_assets().await?;
+
+        if !worn_assets.iter().any(|asset| asset.asset_id == asset_id) {
+            return Ok(());
+        }
+
+        let asset_ids = worn_assets
+            .into_iter()
+            .map(|asset| asset.asset_id)
+            .filter(|worn_asset_id| *worn_asset_id != asset_id)
+            .collect();
+
+        self.set_wearing_assets(asset_ids).await
+    }
+}
+
+/// Returns the worn assets that need to be taken off to wear an asset of type `new_asset_type`.
+fn conflicting_assets(
+    worn_assets: &[WornAsset],
+    new_asset_type: Option<AssetType>,
+) -> Vec<AssetId> {
+    // If the type is not known, nothing is taken off and Roblox decides what happens.
+    let Some(new_asset_type) =
+        new_asset_type.filter(|asset_type| !matches!(asset_type, AssetType::Unknown(_)))
+    else {
+        return Vec::new();
+    };
+
+    if !is_accessory(new_asset_type) {
+        return worn_assets
+            .iter()
+            .filter(|asset| asset.asset_type == new_asset_type)
+            .map(|asset| asset.asset_id)
+            .collect();
+    }
+
+    let worn_accessories = worn_assets
+        .iter()
+        .filter(|asset| is_accessory(asset.asset_type))
+        .collect::<Vec<_>>();
+
+    if worn_accessories.len() < MAX_WORN_ACCESSORIES {
+        return Vec::new();
+    }
+
+    let replaced = worn_accessories
+        .iter()
+        .find(|asset| asset.asset_type == new_asset_type)
+        .or(worn_accessories.first());
+
+    replaced
+        .map(|asset| vec![asset.asset_id])
+        .unwrap_or_default()
+}
+
+fn is_accessory(asset_type: AssetType) -> bool {
+    matches!(
+        asset_type,
+        AssetType::Hat
+            | AssetType::HairAccessory
+            | AssetType::FaceAccessory
+            | AssetType::NeckAccessory
+            | AssetType::ShoulderAccessory
+            | AssetType::FrontAccessory
+            | AssetType::BackAccessory
+            | AssetType::WaistAccessory
+    )
+}
+
+mod internal {
+    use super::{request_types, SET_WEARING_ASSETS_API};
+    use crate::ids::AssetId;
+    use crate::{Client, RoboatError, XCSRF_HEADER};
+    use reqwest::header;
+
+    impl Client {
+        pub(super) async fn set_wearing_assets_internal(
+            &self,
+            asset_ids: &[AssetId],
+        ) -> Result<(), RoboatError> {
+            let cookie = self.cookie_string()?;
+
+            let body = request_types::SetWearingAssetsReqBody {
+                asset_ids: asset_ids.to_vec(),
+            };
+
+            let request = self
+                .reqwest_client
+                .post(SET_WEARING_ASSETS_API)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, self.xcsrf().await)
+                .json(&body);
+
+            let dry_run_body = serde_json::json!({
+                "invalidAssetIds": [],
+                "success": true,
+            });
+
+            let response = self.send_mutating_request(request, dry_run_body).await?;
+            let raw =
+                Self::parse_to_raw::<request_types::SetWearingAssetsResponse>(response).await?;
+
+            match raw.invalid_asset_ids.is_empty() {
+                true => Ok(()),
+                false => Err(RoboatError::InvalidWearingAssets(raw.invalid_asset_ids)),
+            }
+        }
+    }
+}
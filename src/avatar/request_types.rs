@@ -0,0 +1,34 @@
+use crate::ids::AssetId;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct AvatarResponse {
+    pub assets: Vec<AvatarAssetRaw>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct AvatarAssetRaw {
+    pub id: AssetId,
+    pub name: String,
+    pub asset_type: AssetTypeRaw,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(super) struct AssetTypeRaw {
+    pub id: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct SetWearingAssetsReqBody {
+    pub asset_ids: Vec<AssetId>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct SetWearingAssetsResponse {
+    pub invalid_asset_ids: Vec<AssetId>,
+    pub success: bool,
+}
@@ -0,0 +1,65 @@
+use crate::RoboatError;
+
+/// The result of a bulk operation, where each item can succeed or fail on its own.
+///
+/// Instead of one bad item failing the whole batch, every item that succeeded is kept
+/// in `successes`, and every item that failed is kept in `failures` along with its error.
+///
+/// # Example
+/// ```
+/// use roboat::{BatchResult, RoboatError};
+///
+/// let result = BatchResult {
+///     successes: vec![(0, "a"), (2, "c")],
+///     failures: vec![(1, RoboatError::TooManyRequests)],
+/// };
+///
+/// assert!(!result.is_complete());
+/// assert!(result.into_result().is_err());
+/// ```
+#[derive(Debug, Default)]
+pub struct BatchResult<T> {
+    /// The items that succeeded, along with their index in the batch.
+    pub successes: Vec<(usize, T)>,
+    /// The items that failed, along with their index in the batch.
+    pub failures: Vec<(usize, RoboatError)>,
+}
+
+impl<T> BatchResult<T> {
+    /// Returns whether every item in the batch succeeded.
+    pub fn is_complete(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    /// Turns the batch into a regular result, failing with the error of the first
+    /// failed item if there are any. Otherwise, returns the successes in order.
+    pub fn into_result(self) -> Result<Vec<T>, RoboatError> {
+        match self.failures.into_iter().min_by_key(|(i, _)| *i) {
+            Some((_, e)) => Err(e),
+            None => {
+                let mut successes = self.successes;
+                successes.sort_by_key(|(i, _)| *i);
+                Ok(successes.into_iter().map(|(_, x)| x).collect())
+            }
+        }
+    }
+}
+
+impl<T> FromIterator<Result<T, RoboatError>> for BatchResult<T> {
+    /// Collects results into a batch, using the position of each result as its index.
+    fn from_iter<I: IntoIterator<Item = Result<T, RoboatError>>>(iter: I) -> Self {
+        let mut batch_result = Self {
+            successes: Vec::new(),
+            failures: Vec::new(),
+        };
+
+        for (i, result) in iter.into_iter().enumerate() {
+            match result {
+                Ok(x) => batch_result.successes.push((i, x)),
+                Err(e) => batch_result.failures.push((i, e)),
+            }
+        }
+
+        batch_result
+    }
+}
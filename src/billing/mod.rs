@@ -0,0 +1,62 @@
+use crate::{Client, RoboatError};
+use reqwest::header;
+use serde::{Deserialize, Serialize};
+
+mod request_types;
+
+const CREDIT_API: &str = "https://billing.roblox.com/v1/credit";
+
+/// The gift card / credit balance of the account. Retrieved from <https://billing.roblox.com/v1/credit>.
+#[derive(Clone, Debug, PartialEq, PartialOrd, Default, Serialize, Deserialize)]
+pub struct CreditBalance {
+    /// The credit balance of the account in the account's currency (usually USD).
+    pub balance: f64,
+    /// The amount of robux the credit balance can be redeemed for.
+    pub robux_amount: u64,
+    /// Whether the credit can currently be redeemed for robux.
+    pub can_redeem_credit_for_robux: bool,
+}
+
+impl Client {
+    /// Grabs the credit (gift card) balance of the current account from <https://billing.roblox.com/v1/credit>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * This is separate from the robux balance, which can be retrieved with [`Client::robux`].
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let credit = client.billing_balance().await?;
+    /// println!("Credit Balance: {}", credit.balance);
+    /// println!("Redeemable For: {} Robux", credit.robux_amount);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn billing_balance(&self) -> Result<CreditBalance, RoboatError> {
+        let cookie = self.cookie_string()?;
+
+        let request = self
+            .reqwest_client
+            .get(CREDIT_API)
+            .header(header::COOKIE, cookie);
+
+        let response = self.send_request(request).await?;
+        let raw = Self::parse_to_raw::<request_types::CreditResponse>(response).await?;
+
+        let credit_balance = CreditBalance {
+            balance: raw.balance,
+            robux_amount: raw.robux_amount,
+            can_redeem_credit_for_robux: raw.can_redeem_credit_for_robux,
+        };
+
+        Ok(credit_balance)
+    }
+}
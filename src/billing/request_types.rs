@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct CreditResponse {
+    pub balance: f64,
+    pub robux_amount: u64,
+    pub can_redeem_credit_for_robux: bool,
+}
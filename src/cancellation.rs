@@ -0,0 +1,42 @@
+use crate::RoboatError;
+use std::future::Future;
+use std::time::Instant;
+
+/// Runs `future` until it finishes or `deadline` passes, whichever comes first.
+///
+/// Every future returned by this crate can be cancelled by dropping it (requests that are in
+/// flight are aborted, and nothing is left running in the background). This is a small
+/// wrapper around that for when the cancellation should happen at a certain time.
+///
+/// # Errors
+/// * [`RoboatError::DeadlineExceeded`] - If `deadline` passes before `future` finishes.
+/// * Any error returned by `future`.
+///
+/// # Example
+/// ```no_run
+/// use roboat::cancellation::with_deadline;
+/// use roboat::ids::UserId;
+/// use roboat::ClientBuilder;
+/// use std::time::{Duration, Instant};
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = ClientBuilder::new().build();
+///
+/// let user_id = UserId(2207291);
+/// let deadline = Instant::now() + Duration::from_secs(30);
+///
+/// let duplicates = with_deadline(deadline, client.duplicate_collectibles(user_id)).await?;
+/// println!("Found {} duplicates in time.", duplicates.len());
+/// # Ok(())
+/// # }
+/// ```
+pub async fn with_deadline<T, Fut>(deadline: Instant, future: Fut) -> Result<T, RoboatError>
+where
+    Fut: Future<Output = Result<T, RoboatError>>,
+{
+    match tokio::time::timeout_at(deadline.into(), future).await {
+        Ok(result) => result,
+        Err(_) => Err(RoboatError::DeadlineExceeded),
+    }
+}
@@ -1,5 +1,9 @@
-use crate::{Client, RoboatError};
+use crate::ids::{AssetId, ProductId};
+use crate::{BatchResult, Client, RoboatError};
+use futures_util::stream::{self, StreamExt};
+use reqwest::header::{self, HeaderValue};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::convert::TryFrom;
 
 mod request_types;
@@ -7,6 +11,23 @@ mod request_types;
 // A useful link for the encodings for item types: https://create.roblox.com/docs/studio/catalog-api#avatar-catalog-api
 
 const ITEM_DETAILS_API: &str = "https://catalog.roblox.com/v1/catalog/items/details";
+const COLLECTIBLE_ITEM_DETAILS_API: &str =
+    "https://apis.roblox.com/marketplace-items/v1/items/details";
+const CATALOG_SEARCH_API: &str = "https://catalog.roblox.com/v2/search/items/details";
+const CATALOG_SEARCH_IDS_API: &str = "https://catalog.roblox.com/v1/search/items";
+const BUNDLE_DETAILS_API_PART_1: &str = "https://catalog.roblox.com/v1/bundles/";
+const BUNDLE_DETAILS_API_PART_2: &str = "/details";
+
+/// The maximum amount of items the item details endpoint accepts in one request.
+/// [`Client::item_details`] splits larger requests into chunks of this size.
+const ITEM_DETAILS_CHUNK_SIZE: usize = 120;
+
+/// The amount of item detail chunks requested at once by [`Client::item_details`].
+const MAX_CONCURRENT_ITEM_DETAILS_CHUNKS: usize = 4;
+
+/// The amount of items requested per page of [`Client::avatar_catalog_search`].
+/// The catalog only accepts a few page sizes, so this is not a [`Limit`](crate::Limit).
+const CATALOG_SEARCH_PAGE_SIZE: u64 = 30;
 
 /// An enum representing the overall high level type of the item (Asset or Bundle)
 #[derive(
@@ -21,10 +42,23 @@ pub enum ItemType {
 }
 
 /// An enum representing the type of the asset (hat, shirt, gear).
+///
+/// Asset type ids added by Roblox after this version of the library get parsed into
+/// `Unknown` instead of failing the whole response.
+///
+/// # Example
+/// ```
+/// use roboat::catalog::avatar_catalog::AssetType;
+///
+/// assert_eq!(AssetType::from(8), AssetType::Hat);
+/// assert_eq!(AssetType::from(64), AssetType::Unknown(64));
+/// assert_eq!(u64::from(AssetType::Unknown(64)), 64);
+/// ```
 #[allow(missing_docs)]
 #[derive(
     Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize, Copy,
 )]
+#[non_exhaustive]
 pub enum AssetType {
     #[default]
     TShirt,
@@ -58,23 +92,43 @@ pub enum AssetType {
     WalkAnimation,
     PoseAnimation,
     EmoteAnimation,
+    /// An asset type id not yet known to this library.
+    #[serde(untagged)]
+    Unknown(u64),
 }
 
 /// An enum representing the type of bundle (BodyParts or AvatarAnimations).
+///
+/// Bundle type ids added by Roblox after this version of the library get parsed into
+/// `Unknown` instead of failing the whole response.
 #[derive(
     Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize, Copy,
 )]
+#[non_exhaustive]
 #[allow(missing_docs)]
 pub enum BundleType {
     #[default]
     BodyParts,
     AvatarAnimations,
+    /// A bundle type id not yet known to this library.
+    #[serde(untagged)]
+    Unknown(u64),
 }
 
 /// An enum representing the genre of an item (war, funny).
-#[derive(
-    Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize, Copy,
-)]
+///
+/// Like the other catalog enums that come from Roblox responses, values added by Roblox after
+/// this version of the library get parsed into `Unknown` instead of failing the whole response.
+///
+/// # Example
+/// ```
+/// use roboat::catalog::avatar_catalog::Genre;
+///
+/// let genres: Vec<Genre> = serde_json::from_str(r#"["War", "Cooking"]"#).unwrap();
+/// assert_eq!(genres, vec![Genre::War, Genre::Unknown("Cooking".to_string())]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+#[non_exhaustive]
 #[allow(missing_docs)]
 pub enum Genre {
     #[default]
@@ -93,13 +147,15 @@ pub enum Genre {
     Sports,
     Ninja,
     WildWest,
+    /// A value not yet known to this library.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// The status of an item (Sale, Exclusive).
 #[allow(missing_docs)]
-#[derive(
-    Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize, Copy,
-)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+#[non_exhaustive]
 pub enum ItemStatus {
     #[default]
     New,
@@ -109,20 +165,25 @@ pub enum ItemStatus {
     GooglePlayExclusive,
     IosExclusive,
     SaleTimer,
+    /// A value not yet known to this library.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// Restriction on an item (ThirteenPlus, Limited). Not sure why limited
 /// is here but I guess that's how they do it.
 #[allow(missing_docs)]
-#[derive(
-    Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize, Copy,
-)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+#[non_exhaustive]
 pub enum ItemRestriction {
     #[default]
     ThirteenPlus,
     LimitedUnique,
     Limited,
     Rthro,
+    /// A value not yet known to this library.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// Type of creator that created the item (User or Group)
@@ -137,9 +198,8 @@ pub enum CreatorType {
 }
 
 /// The price status of an item. Only applies to items not on sale (Free, Offsale).
-#[derive(
-    Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize, Copy,
-)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+#[non_exhaustive]
 #[allow(missing_docs)]
 pub enum PriceStatus {
     #[default]
@@ -148,6 +208,9 @@ pub enum PriceStatus {
     Offsale,
     #[serde(alias = "No Resellers")]
     NoResellers,
+    /// A value not yet known to this library.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// The broad category of an item for use in search (Clothing, Collectables).
@@ -196,11 +259,58 @@ pub enum SortType {
     PriceDesc,
 }
 
-/// A subcategory for items, used when searching.
-#[allow(missing_docs)]
+/// Which sales of an item to search, used when searching.
 #[derive(
     Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize, Copy,
 )]
+pub enum SalesType {
+    /// Every item.
+    #[default]
+    All,
+    /// Only collectible (limited) items.
+    Collectibles,
+    /// Only items that are exclusive to premium users.
+    Premium,
+}
+
+impl SortType {
+    fn as_query_value(self) -> u8 {
+        match self {
+            Self::Relevance => 0,
+            Self::Favorited => 1,
+            Self::Sales => 2,
+            Self::Updated => 3,
+            Self::PriceAsc => 4,
+            Self::PriceDesc => 5,
+        }
+    }
+}
+
+impl SortAggregation {
+    fn as_query_value(self) -> u8 {
+        match self {
+            Self::PastDay => 1,
+            Self::PastWeek => 3,
+            Self::PastMonth => 4,
+            Self::AllTime => 5,
+        }
+    }
+}
+
+impl SalesType {
+    fn as_query_value(self) -> u8 {
+        match self {
+            Self::All => 1,
+            Self::Collectibles => 2,
+            Self::Premium => 3,
+        }
+    }
+}
+
+/// A subcategory for items, used when searching.
+#[allow(missing_docs)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+#[non_exhaustive]
 pub enum Subcategory {
     Featured,
     #[default]
@@ -237,9 +347,13 @@ pub enum Subcategory {
     Social,
     Building,
     Transport,
+    /// A value not yet known to this library.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
-/// Additional details for premium pricing.
+/// Additional details for premium pricing. Use [`ItemDetails::effective_price`] to get
+/// the price a user would pay.
 #[derive(
     Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize, Copy,
 )]
@@ -269,7 +383,7 @@ pub struct ItemDetails {
     pub description: String,
     /// The product id of the item. This is different from the asset/bundle id.
     /// This is most notably used when buying limiteds.
-    pub product_id: u64,
+    pub product_id: ProductId,
     /// Only exists if the [`ItemDetails::item_type`] is a [`ItemType::Asset`].
     pub genres: Option<Vec<Genre>>,
     /// The statuses of an item (e.g., New, Sale)
@@ -298,6 +412,55 @@ pub struct ItemDetails {
     pub price_status: Option<PriceStatus>,
     /// Only exists if the item has special premium pricing.
     pub premium_pricing: Option<PremiumPricing>,
+    /// The collectible item id of the item. Only exists if the item is a
+    /// collectible (such as a UGC limited).
+    pub collectible_item_id: Option<String>,
+}
+
+impl ItemDetails {
+    /// Returns the price a user would pay for the item, taking premium pricing into account.
+    ///
+    /// If `is_premium` is true and the item has [`ItemDetails::premium_pricing`], the premium
+    /// price is returned. Otherwise, [`ItemDetails::price`] is returned.
+    ///
+    /// # Example
+    /// ```
+    /// use roboat::catalog::avatar_catalog::{ItemDetails, PremiumPricing};
+    ///
+    /// let item = ItemDetails {
+    ///     price: Some(100),
+    ///     premium_pricing: Some(PremiumPricing {
+    ///         premium_discount_percentage: 25,
+    ///         premium_price_in_robux: 75,
+    ///     }),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(item.effective_price(true), Some(75));
+    /// assert_eq!(item.effective_price(false), Some(100));
+    /// ```
+    pub fn effective_price(&self, is_premium: bool) -> Option<u64> {
+        match (is_premium, self.premium_pricing) {
+            (true, Some(premium_pricing)) => Some(premium_pricing.premium_price_in_robux),
+            _ => self.price,
+        }
+    }
+}
+
+/// The different ids that refer to a single asset. Retrieved using [`Client::resolve_item_ids`].
+///
+/// Different endpoints expect different ids, most notably purchasing a limited
+/// requires the product id instead of the asset id.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct ItemIds {
+    /// The asset id of the item. This is the id shown in the url of the item.
+    pub asset_id: AssetId,
+    /// The product id of the item. Used when buying limiteds.
+    pub product_id: ProductId,
+    /// The collectible item id of the item. Only exists if the item is a collectible.
+    pub collectible_item_id: Option<String>,
+    /// The collectible product id of the item. Only exists if the item is a collectible.
+    pub collectible_product_id: Option<String>,
 }
 
 /// Holds information used to retrieve data from the [`Client::item_details`] endpoint.
@@ -312,55 +475,403 @@ pub struct ItemArgs {
     pub id: u64,
 }
 
-impl TryFrom<u64> for AssetType {
-    type Error = RoboatError;
+/// A search of the avatar catalog, used by [`Client::avatar_catalog_search`].
+/// Constructed using [`CatalogQuery::new`] and its builder methods.
+///
+/// # Example
+/// ```
+/// use roboat::catalog::avatar_catalog::CatalogQuery;
+///
+/// let query = CatalogQuery::new()
+///     .keyword("valkyrie".to_string())
+///     .verified_creators_only(true);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct CatalogQuery {
+    keyword: Option<String>,
+    creator_name: Option<String>,
+    verified_creators_only: bool,
+}
+
+impl CatalogQuery {
+    /// Creates a new [`CatalogQuery`] that matches every item.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only matches items related to `keyword`.
+    pub fn keyword(mut self, keyword: String) -> Self {
+        self.keyword = Some(keyword);
+        self
+    }
+
+    /// Only matches items made by the user or group named `creator_name`.
+    pub fn creator_name(mut self, creator_name: String) -> Self {
+        self.creator_name = Some(creator_name);
+        self
+    }
+
+    /// Only matches items whose creator has a verified badge. Most scam items (such as
+    /// fake copies of popular items) are uploaded by unverified creators.
+    ///
+    /// Roblox has no search parameter for this, so results are filtered after each page is
+    /// fetched using [`filter_verified_creators`].
+    pub fn verified_creators_only(mut self, enabled: bool) -> Self {
+        self.verified_creators_only = enabled;
+        self
+    }
+}
+
+/// The type of an item inside a bundle.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub enum BundleItemType {
+    /// An asset, such as a body part or an animation.
+    #[default]
+    Asset,
+    /// The outfit the bundle is worn as.
+    UserOutfit,
+    /// A value not yet known to this library.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// An item inside a bundle. Part of [`BundleDetails`].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct BundleItem {
+    /// The id of the item. This is an asset id if [`Self::item_type`] is [`BundleItemType::Asset`].
+    pub id: u64,
+    /// The name of the item.
+    pub name: String,
+    /// Whether the item is an asset or an outfit.
+    pub item_type: BundleItemType,
+}
+
+/// The details of a bundle, including the items inside it. Retrieved from
+/// <https://catalog.roblox.com/v1/bundles/{bundle_id}/details>.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct BundleDetails {
+    /// The id of the bundle.
+    pub id: u64,
+    /// The name of the bundle.
+    pub name: String,
+    /// The description of the bundle.
+    pub description: String,
+    /// The type of the bundle.
+    pub bundle_type: BundleType,
+    /// The items inside the bundle.
+    pub items: Vec<BundleItem>,
+    /// The id of the creator. The value is 1 if the creator is Roblox.
+    pub creator_id: u64,
+    /// The name of the creator.
+    pub creator_name: String,
+    /// Whether the creator is a user or a group.
+    pub creator_type: CreatorType,
+    /// The product id of the bundle. Used for purchasing.
+    pub product_id: Option<ProductId>,
+    /// Whether the bundle is for sale.
+    pub is_for_sale: bool,
+    /// The price of the bundle in robux. This is `None` if the bundle is not for sale.
+    pub price: Option<u64>,
+}
+
+/// The filters, sort order, and page of a search made with [`Client::search_catalog`].
+/// Constructed using [`CatalogSearchQuery::new`] and its builder methods.
+///
+/// # Example
+/// ```
+/// use roboat::catalog::avatar_catalog::{
+///     CatalogSearchQuery, Category, SalesType, SortType, Subcategory,
+/// };
+///
+/// let query = CatalogSearchQuery::new()
+///     .keyword("sparkle".to_string())
+///     .category(Category::Accessories)
+///     .subcategory(Subcategory::Hats)
+///     .price_range(Some(100), Some(5000))
+///     .sales_type(SalesType::Collectibles)
+///     .sort_type(SortType::PriceAsc);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct CatalogSearchQuery {
+    keyword: Option<String>,
+    category: Option<Category>,
+    subcategory: Option<Subcategory>,
+    min_price: Option<u64>,
+    max_price: Option<u64>,
+    creator_name: Option<String>,
+    creator: Option<(CreatorType, u64)>,
+    sort_type: Option<SortType>,
+    sort_aggregation: Option<SortAggregation>,
+    sales_type: Option<SalesType>,
+    cursor: Option<String>,
+}
+
+impl CatalogSearchQuery {
+    /// Creates a new [`CatalogSearchQuery`] for the first page of every item.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only matches items related to `keyword`.
+    pub fn keyword(mut self, keyword: String) -> Self {
+        self.keyword = Some(keyword);
+        self
+    }
+
+    /// Only matches items in `category`.
+    pub fn category(mut self, category: Category) -> Self {
+        self.category = Some(category);
+        self
+    }
+
+    /// Only matches items in `subcategory`. This should be a subcategory of the category
+    /// set with [`CatalogSearchQuery::category`].
+    pub fn subcategory(mut self, subcategory: Subcategory) -> Self {
+        self.subcategory = Some(subcategory);
+        self
+    }
 
-    fn try_from(value: u64) -> Result<Self, Self::Error> {
+    /// Only matches items priced between `min_price` and `max_price` (inclusive).
+    /// Either bound can be left out with `None`.
+    pub fn price_range(mut self, min_price: Option<u64>, max_price: Option<u64>) -> Self {
+        self.min_price = min_price;
+        self.max_price = max_price;
+        self
+    }
+
+    /// Only matches items made by the user or group named `creator_name`.
+    pub fn creator_name(mut self, creator_name: String) -> Self {
+        self.creator_name = Some(creator_name);
+        self
+    }
+
+    /// Only matches items made by the user or group with the id `creator_id`.
+    pub fn creator(mut self, creator_type: CreatorType, creator_id: u64) -> Self {
+        self.creator = Some((creator_type, creator_id));
+        self
+    }
+
+    /// Sorts the results by `sort_type`. Results are sorted by relevance if this is not set.
+    pub fn sort_type(mut self, sort_type: SortType) -> Self {
+        self.sort_type = Some(sort_type);
+        self
+    }
+
+    /// Sets the time period used by sorts such as [`SortType::Sales`] and [`SortType::Favorited`].
+    pub fn sort_aggregation(mut self, sort_aggregation: SortAggregation) -> Self {
+        self.sort_aggregation = Some(sort_aggregation);
+        self
+    }
+
+    /// Only matches items of `sales_type`, such as collectibles.
+    pub fn sales_type(mut self, sales_type: SalesType) -> Self {
+        self.sales_type = Some(sales_type);
+        self
+    }
+
+    /// Fetches the page after the one that returned `cursor`.
+    pub fn cursor(mut self, cursor: String) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    /// Returns the query parameters of the search.
+    fn parameters(&self) -> Vec<(&'static str, String)> {
+        let mut parameters = vec![("limit", CATALOG_SEARCH_PAGE_SIZE.to_string())];
+
+        if let Some(keyword) = &self.keyword {
+            parameters.push(("keyword", keyword.clone()));
+        }
+
+        if let Some(category) = &self.category {
+            parameters.push(("category", query_value(category)));
+        }
+
+        if let Some(subcategory) = &self.subcategory {
+            parameters.push(("subcategory", query_value(subcategory)));
+        }
+
+        if let Some(min_price) = self.min_price {
+            parameters.push(("minPrice", min_price.to_string()));
+        }
+
+        if let Some(max_price) = self.max_price {
+            parameters.push(("maxPrice", max_price.to_string()));
+        }
+
+        if let Some(creator_name) = &self.creator_name {
+            parameters.push(("creatorName", creator_name.clone()));
+        }
+
+        if let Some((creator_type, creator_id)) = &self.creator {
+            parameters.push(("creatorType", query_value(creator_type)));
+            parameters.push(("creatorTargetId", creator_id.to_string()));
+        }
+
+        if let Some(sort_type) = self.sort_type {
+            parameters.push(("sortType", sort_type.as_query_value().to_string()));
+        }
+
+        if let Some(sort_aggregation) = self.sort_aggregation {
+            parameters.push((
+                "sortAggregation",
+                sort_aggregation.as_query_value().to_string(),
+            ));
+        }
+
+        if let Some(sales_type) = self.sales_type {
+            parameters.push(("salesTypeFilter", sales_type.as_query_value().to_string()));
+        }
+
+        if let Some(cursor) = &self.cursor {
+            parameters.push(("cursor", cursor.clone()));
+        }
+
+        parameters
+    }
+}
+
+/// Returns the name of an enum variant as it is sent in a query parameter.
+fn query_value<T: Serialize>(value: &T) -> String {
+    match serde_json::to_value(value) {
+        Ok(serde_json::Value::String(value)) => value,
+        _ => String::new(),
+    }
+}
+
+/// The result of [`Client::search_until`].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct SearchUntilResult {
+    /// The first item that matched the predicate, or `None` if no item matched.
+    pub item: Option<ItemDetails>,
+    /// The amount of pages fetched before stopping.
+    pub pages_scanned: usize,
+}
+
+/// Removes every item whose creator does not have a verified badge. This is the filter
+/// used by [`CatalogQuery::verified_creators_only`], and can be used on items from
+/// any other endpoint, such as [`Client::item_details`].
+///
+/// # Example
+/// ```
+/// use roboat::catalog::avatar_catalog::{filter_verified_creators, ItemDetails};
+///
+/// let verified = ItemDetails {
+///     id: 1,
+///     creator_has_verified_badge: true,
+///     ..Default::default()
+/// };
+///
+/// let unverified = ItemDetails {
+///     id: 2,
+///     creator_has_verified_badge: false,
+///     ..Default::default()
+/// };
+///
+/// let items = filter_verified_creators(vec![verified.clone(), unverified]);
+/// assert_eq!(items, vec![verified]);
+/// ```
+pub fn filter_verified_creators(items: Vec<ItemDetails>) -> Vec<ItemDetails> {
+    items
+        .into_iter()
+        .filter(|item| item.creator_has_verified_badge)
+        .collect()
+}
+
+impl From<u64> for AssetType {
+    fn from(value: u64) -> Self {
         match value {
-            2 => Ok(AssetType::TShirt),
-            8 => Ok(AssetType::Hat),
-            11 => Ok(AssetType::Shirt),
-            12 => Ok(AssetType::Pants),
-            17 => Ok(AssetType::Head),
-            18 => Ok(AssetType::Face),
-            19 => Ok(AssetType::Gear),
-            25 => Ok(AssetType::Arms),
-            26 => Ok(AssetType::Legs),
-            27 => Ok(AssetType::Torso),
-            28 => Ok(AssetType::RightArm),
-            29 => Ok(AssetType::LeftArm),
-            30 => Ok(AssetType::LeftLeg),
-            31 => Ok(AssetType::RightLeg),
-            41 => Ok(AssetType::HairAccessory),
-            42 => Ok(AssetType::FaceAccessory),
-            43 => Ok(AssetType::NeckAccessory),
-            44 => Ok(AssetType::ShoulderAccessory),
-            45 => Ok(AssetType::FrontAccessory),
-            46 => Ok(AssetType::BackAccessory),
-            47 => Ok(AssetType::WaistAccessory),
-            48 => Ok(AssetType::ClimbAnimation),
-            49 => Ok(AssetType::DeathAnimation),
-            50 => Ok(AssetType::FallAnimation),
-            51 => Ok(AssetType::IdleAnimation),
-            52 => Ok(AssetType::JumpAnimation),
-            53 => Ok(AssetType::RunAnimation),
-            54 => Ok(AssetType::SwimAnimation),
-            55 => Ok(AssetType::WalkAnimation),
-            56 => Ok(AssetType::PoseAnimation),
-            61 => Ok(AssetType::EmoteAnimation),
-            _ => Err(RoboatError::MalformedResponse),
+            2 => AssetType::TShirt,
+            8 => AssetType::Hat,
+            11 => AssetType::Shirt,
+            12 => AssetType::Pants,
+            17 => AssetType::Head,
+            18 => AssetType::Face,
+            19 => AssetType::Gear,
+            25 => AssetType::Arms,
+            26 => AssetType::Legs,
+            27 => AssetType::Torso,
+            28 => AssetType::RightArm,
+            29 => AssetType::LeftArm,
+            30 => AssetType::LeftLeg,
+            31 => AssetType::RightLeg,
+            41 => AssetType::HairAccessory,
+            42 => AssetType::FaceAccessory,
+            43 => AssetType::NeckAccessory,
+            44 => AssetType::ShoulderAccessory,
+            45 => AssetType::FrontAccessory,
+            46 => AssetType::BackAccessory,
+            47 => AssetType::WaistAccessory,
+            48 => AssetType::ClimbAnimation,
+            49 => AssetType::DeathAnimation,
+            50 => AssetType::FallAnimation,
+            51 => AssetType::IdleAnimation,
+            52 => AssetType::JumpAnimation,
+            53 => AssetType::RunAnimation,
+            54 => AssetType::SwimAnimation,
+            55 => AssetType::WalkAnimation,
+            56 => AssetType::PoseAnimation,
+            61 => AssetType::EmoteAnimation,
+            _ => AssetType::Unknown(value),
         }
     }
 }
 
-impl TryFrom<u64> for BundleType {
-    type Error = RoboatError;
+impl From<AssetType> for u64 {
+    fn from(value: AssetType) -> Self {
+        match value {
+            AssetType::TShirt => 2,
+            AssetType::Hat => 8,
+            AssetType::Shirt => 11,
+            AssetType::Pants => 12,
+            AssetType::Head => 17,
+            AssetType::Face => 18,
+            AssetType::Gear => 19,
+            AssetType::Arms => 25,
+            AssetType::Legs => 26,
+            AssetType::Torso => 27,
+            AssetType::RightArm => 28,
+            AssetType::LeftArm => 29,
+            AssetType::LeftLeg => 30,
+            AssetType::RightLeg => 31,
+            AssetType::HairAccessory => 41,
+            AssetType::FaceAccessory => 42,
+            AssetType::NeckAccessory => 43,
+            AssetType::ShoulderAccessory => 44,
+            AssetType::FrontAccessory => 45,
+            AssetType::BackAccessory => 46,
+            AssetType::WaistAccessory => 47,
+            AssetType::ClimbAnimation => 48,
+            AssetType::DeathAnimation => 49,
+            AssetType::FallAnimation => 50,
+            AssetType::IdleAnimation => 51,
+            AssetType::JumpAnimation => 52,
+            AssetType::RunAnimation => 53,
+            AssetType::SwimAnimation => 54,
+            AssetType::WalkAnimation => 55,
+            AssetType::PoseAnimation => 56,
+            AssetType::EmoteAnimation => 61,
+            AssetType::Unknown(id) => id,
+        }
+    }
+}
 
-    fn try_from(value: u64) -> Result<Self, Self::Error> {
+impl From<u64> for BundleType {
+    fn from(value: u64) -> Self {
         match value {
-            1 => Ok(BundleType::BodyParts),
-            2 => Ok(BundleType::AvatarAnimations),
-            _ => Err(RoboatError::MalformedResponse),
+            1 => BundleType::BodyParts,
+            2 => BundleType::AvatarAnimations,
+            _ => BundleType::Unknown(value),
+        }
+    }
+}
+
+impl From<BundleType> for u64 {
+    fn from(value: BundleType) -> Self {
+        match value {
+            BundleType::BodyParts => 1,
+            BundleType::AvatarAnimations => 2,
+            BundleType::Unknown(id) => id,
         }
     }
 }
@@ -369,21 +880,8 @@ impl TryFrom<request_types::ItemDetailsRaw> for ItemDetails {
     type Error = RoboatError;
 
     fn try_from(value: request_types::ItemDetailsRaw) -> Result<Self, Self::Error> {
-        let asset_type = match value.asset_type {
-            Some(asset_type_id) => {
-                let asset_type = AssetType::try_from(asset_type_id)?;
-                Some(asset_type)
-            }
-            None => None,
-        };
-
-        let bundle_type = match value.bundle_type {
-            Some(bundle_type_id) => {
-                let bundle_type = BundleType::try_from(bundle_type_id)?;
-                Some(bundle_type)
-            }
-            None => None,
-        };
+        let asset_type = value.asset_type.map(AssetType::from);
+        let bundle_type = value.bundle_type.map(BundleType::from);
 
         let id = value.id.ok_or(RoboatError::MalformedResponse)?;
         let item_type = value.item_type.ok_or(RoboatError::MalformedResponse)?;
@@ -414,6 +912,7 @@ impl TryFrom<request_types::ItemDetailsRaw> for ItemDetails {
         let favorite_count = value.favorite_count;
         let price_status = value.price_status;
         let premium_pricing = value.premium_pricing;
+        let collectible_item_id = value.collectible_item_id;
 
         // If the price is None, use the lowest price (used for limiteds).
         // If neither exists, the item has no resellers and the price
@@ -442,6 +941,7 @@ impl TryFrom<request_types::ItemDetailsRaw> for ItemDetails {
             favorite_count,
             price_status,
             premium_pricing,
+            collectible_item_id,
         })
     }
 }
@@ -451,13 +951,20 @@ impl Client {
     ///
     /// # Notes
     /// * Does not require a valid roblosecurity.
-    /// * This endpoint will accept up to 120 items at a time.
-    /// * Will repeat once if the x-csrf-token is invalid.
+    /// * This endpoint accepts up to 120 items at a time. Larger requests are split into
+    ///   chunks of 120 items, and up to 4 chunks are requested at once.
+    /// * Will repeat once per chunk if the x-csrf-token is invalid.
     ///
     /// # Argument Notes
     /// * The `id` parameter is that acts differently for this endpoint than others.
-    /// If the `item_type` is [`ItemType::Asset`], then `id` is the item ID.
-    /// Otherwise, if the `item_type` is [`ItemType::Bundle`], then `id` is the bundle ID.
+    ///   If the `item_type` is [`ItemType::Asset`], then `id` is the item ID.
+    ///   Otherwise, if the `item_type` is [`ItemType::Bundle`], then `id` is the bundle ID.
+    ///
+    /// # Return Value Notes
+    /// * Items are returned in the same order as `items`. Items that do not exist
+    ///   are left out.
+    /// * If any chunk fails, the error of the first failed chunk is returned. Use
+    ///   [`Client::try_item_details`] to keep the chunks that succeeded.
     ///
     /// # Example
     /// ```no_run
@@ -489,6 +996,121 @@ impl Client {
     pub async fn item_details(
         &self,
         items: Vec<ItemArgs>,
+    ) -> Result<Vec<ItemDetails>, RoboatError> {
+        self.try_item_details(items).await.into_result()
+    }
+
+    /// Grabs details of one or more items from <https://catalog.roblox.com/v1/catalog/items/details>,
+    /// keeping the chunks that succeeded when others fail.
+    ///
+    /// This is the same as [`Client::item_details`], except that a failed chunk does not
+    /// fail the whole call.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    /// * This endpoint accepts up to 120 items at a time. Larger requests are split into
+    ///   chunks of 120 items, and up to 4 chunks are requested at once.
+    /// * Will repeat once per chunk if the x-csrf-token is invalid.
+    ///
+    /// # Return Value Notes
+    /// * Each success holds the index of the item in `items`. Items that do not exist
+    ///   are left out.
+    /// * Each failure holds the index of the first item of the failed chunk. The failure
+    ///   covers that item and the (up to) 119 items after it.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::catalog::avatar_catalog::{ItemArgs, ItemType};
+    /// use roboat::ClientBuilder;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let items = (1..=500)
+    ///     .map(|id| ItemArgs {
+    ///         item_type: ItemType::Asset,
+    ///         id,
+    ///     })
+    ///     .collect();
+    ///
+    /// let batch = client.try_item_details(items).await;
+    ///
+    /// for (i, details) in &batch.successes {
+    ///     println!("Item {}: {}", i, details.name);
+    /// }
+    ///
+    /// for (i, e) in &batch.failures {
+    ///     println!("Chunk starting at item {} failed: {}", i, e);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn try_item_details(&self, items: Vec<ItemArgs>) -> BatchResult<ItemDetails> {
+        let chunks = items
+            .chunks(ITEM_DETAILS_CHUNK_SIZE)
+            .map(|chunk| chunk.to_vec())
+            .enumerate()
+            .collect::<Vec<_>>();
+
+        let results = stream::iter(chunks)
+            .map(|(chunk_index, chunk)| async move {
+                let offset = chunk_index * ITEM_DETAILS_CHUNK_SIZE;
+                (offset, self.item_details_chunk(chunk, offset).await)
+            })
+            .buffered(MAX_CONCURRENT_ITEM_DETAILS_CHUNKS)
+            .collect::<Vec<_>>()
+            .await;
+
+        let mut batch_result = BatchResult {
+            successes: Vec::new(),
+            failures: Vec::new(),
+        };
+
+        for (offset, result) in results {
+            match result {
+                Ok(item_details) => batch_result.successes.extend(item_details),
+                Err(e) => batch_result.failures.push((offset, e)),
+            }
+        }
+
+        batch_result
+    }
+
+    /// Grabs the details of up to 120 items, sorted in the same order as `items`. Each
+    /// item is paired with its index in `items` plus `offset`. Details of items that were
+    /// not asked for are left out, since they have no index.
+    async fn item_details_chunk(
+        &self,
+        items: Vec<ItemArgs>,
+        offset: usize,
+    ) -> Result<Vec<(usize, ItemDetails)>, RoboatError> {
+        let item_details = self.item_details_with_retry(items.clone()).await?;
+
+        let positions = items
+            .iter()
+            .enumerate()
+            .rev()
+            .map(|(i, item)| ((item.item_type, item.id), offset + i))
+            .collect::<HashMap<_, _>>();
+
+        let mut item_details = item_details
+            .into_iter()
+            .filter_map(|details| {
+                let position = positions.get(&(details.item_type, details.id)).copied()?;
+
+                Some((position, details))
+            })
+            .collect::<Vec<_>>();
+
+        item_details.sort_by_key(|(position, _)| *position);
+
+        Ok(item_details)
+    }
+
+    async fn item_details_with_retry(
+        &self,
+        items: Vec<ItemArgs>,
     ) -> Result<Vec<ItemDetails>, RoboatError> {
         match self.item_details_internal(items.clone()).await {
             Ok(x) => Ok(x),
@@ -502,12 +1124,447 @@ impl Client {
             },
         }
     }
+
+    /// Searches the avatar catalog using <https://catalog.roblox.com/v2/search/items/details>.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    /// * Each page holds up to 30 items. Pages can hold fewer items when
+    ///   [`CatalogQuery::verified_creators_only`] is enabled, as items are filtered after
+    ///   the page is fetched.
+    ///
+    /// # Argument Notes
+    /// * The cursor is used to get the a certain page of results. If you want the starting page, use `None`.
+    ///
+    /// # Return Value Notes
+    /// * The first value is a vector of items.
+    /// * The second value is the cursor for the next page of results. If there are no more pages, this will be `None`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::catalog::avatar_catalog::CatalogQuery;
+    /// use roboat::ClientBuilder;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let query = CatalogQuery::new()
+    ///     .keyword("headphones".to_string())
+    ///     .verified_creators_only(true);
+    ///
+    /// let (items, next_page_cursor) = client.avatar_catalog_search(&query, None).await?;
+    ///
+    /// for item in items {
+    ///     println!("{} by {}", item.name, item.creator_name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn avatar_catalog_search(
+        &self,
+        query: &CatalogQuery,
+        cursor: Option<String>,
+    ) -> Result<(Vec<ItemDetails>, Option<String>), RoboatError> {
+        let mut parameters = vec![("limit", CATALOG_SEARCH_PAGE_SIZE.to_string())];
+
+        if let Some(keyword) = &query.keyword {
+            parameters.push(("keyword", keyword.clone()));
+        }
+
+        if let Some(creator_name) = &query.creator_name {
+            parameters.push(("creatorName", creator_name.clone()));
+        }
+
+        if let Some(cursor) = cursor {
+            parameters.push(("cursor", cursor));
+        }
+
+        let cookie_string = self.cookie_string().unwrap_or(HeaderValue::from_static(""));
+
+        let request = self
+            .reqwest_client
+            .get(CATALOG_SEARCH_API)
+            .query(&parameters)
+            .header(header::COOKIE, cookie_string);
+
+        let response = self.send_request(request).await?;
+        let raw = Self::parse_to_raw::<request_types::CatalogSearchResponse>(response).await?;
+
+        let items = raw
+            .data
+            .into_iter()
+            .map(ItemDetails::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let items = match query.verified_creators_only {
+            true => filter_verified_creators(items),
+            false => items,
+        };
+
+        Ok((items, raw.next_page_cursor))
+    }
+
+    /// Searches the catalog using <https://catalog.roblox.com/v1/search/items>, then grabs
+    /// the details of the items found using [`Client::item_details`].
+    ///
+    /// Unlike [`Client::avatar_catalog_search`], this supports every filter of the catalog,
+    /// such as categories, price ranges, sales types, and sort orders.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid (when grabbing item details).
+    /// * Each page holds up to 30 items.
+    ///
+    /// # Return Value Notes
+    /// * The first value is a vector of items, in the order of the search.
+    /// * The second value is the cursor for the next page of results. Give it to
+    ///   [`CatalogSearchQuery::cursor`] to fetch the next page. If there are no more pages,
+    ///   this will be `None`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::catalog::avatar_catalog::{CatalogSearchQuery, Category, SortType};
+    /// use roboat::ClientBuilder;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let query = CatalogSearchQuery::new()
+    ///     .category(Category::Collectibles)
+    ///     .price_range(None, Some(1000))
+    ///     .sort_type(SortType::PriceAsc);
+    ///
+    /// let (items, next_page_cursor) = client.search_catalog(query.clone()).await?;
+    ///
+    /// for item in items {
+    ///     println!("{}: {:?}", item.name, item.price);
+    /// }
+    ///
+    /// if let Some(cursor) = next_page_cursor {
+    ///     let (next_items, _) = client.search_catalog(query.cursor(cursor)).await?;
+    ///     println!("Next page has {} items.", next_items.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn search_catalog(
+        &self,
+        query: CatalogSearchQuery,
+    ) -> Result<(Vec<ItemDetails>, Option<String>), RoboatError> {
+        let cookie_string = self.cookie_string().unwrap_or(HeaderValue::from_static(""));
+
+        let request = self
+            .reqwest_client
+            .get(CATALOG_SEARCH_IDS_API)
+            .query(&query.parameters())
+            .header(header::COOKIE, cookie_string);
+
+        let response = self.send_request(request).await?;
+        let raw = Self::parse_to_raw::<request_types::CatalogSearchIdsResponse>(response).await?;
+
+        if raw.data.is_empty() {
+            return Ok((Vec::new(), raw.next_page_cursor));
+        }
+
+        let items = raw
+            .data
+            .into_iter()
+            .map(|item| ItemArgs {
+                item_type: item.item_type,
+                id: item.id,
+            })
+            .collect();
+
+        let item_details = self.item_details(items).await?;
+
+        Ok((item_details, raw.next_page_cursor))
+    }
+
+    /// Grabs the details of a bundle, including the items inside it, from
+    /// <https://catalog.roblox.com/v1/bundles/{bundle_id}/details>.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    /// * Roblox only sends the name of the bundle type, so the id of a type not known to this
+    ///   library is grabbed using [`Client::item_details`]. This second request is only made
+    ///   for such bundles.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let bundle_id = 39;
+    /// let details = client.bundle_details(bundle_id).await?;
+    ///
+    /// println!("{} has {} items.", details.name, details.items.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn bundle_details(&self, bundle_id: u64) -> Result<BundleDetails, RoboatError> {
+        let formatted_url = format!(
+            "{}{}{}",
+            BUNDLE_DETAILS_API_PART_1, bundle_id, BUNDLE_DETAILS_API_PART_2
+        );
+
+        let cookie_string = self.cookie_string().unwrap_or(HeaderValue::from_static(""));
+
+        let request = self
+            .reqwest_client
+            .get(formatted_url)
+            .header(header::COOKIE, cookie_string);
+
+        let response = self.send_request(request).await?;
+        let raw = Self::parse_to_raw::<request_types::BundleDetailsRaw>(response).await?;
+
+        let bundle_type = match raw.bundle_type.as_str() {
+            "BodyParts" => BundleType::BodyParts,
+            "AvatarAnimations" => BundleType::AvatarAnimations,
+            _ => {
+                let item = ItemArgs {
+                    item_type: ItemType::Bundle,
+                    id: bundle_id,
+                };
+
+                self.item_details(vec![item])
+                    .await?
+                    .pop()
+                    .and_then(|details| details.bundle_type)
+                    .ok_or(RoboatError::MalformedResponse)?
+            }
+        };
+
+        let items = raw
+            .items
+            .into_iter()
+            .map(|item| BundleItem {
+                id: item.id,
+                name: item.name,
+                item_type: item.item_type,
+            })
+            .collect();
+
+        let (product_id, is_for_sale, price) = match raw.product {
+            Some(product) => (
+                Some(ProductId(product.id)),
+                product.is_for_sale,
+                product.price_in_robux,
+            ),
+            None => (None, false, None),
+        };
+
+        Ok(BundleDetails {
+            id: raw.id,
+            name: raw.name,
+            description: raw.description,
+            bundle_type,
+            items,
+            creator_id: raw.creator.id,
+            creator_name: raw.creator.name,
+            creator_type: raw.creator.creator_type,
+            product_id,
+            is_for_sale,
+            price: price.filter(|_| is_for_sale),
+        })
+    }
+
+    /// Grabs the item details of every asset inside a bundle, such as for pricing a bundle
+    /// by its parts. This is done by grabbing the items of the bundle using
+    /// [`Client::bundle_details`], and then grabbing the details of its assets using
+    /// [`Client::item_details`].
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid (when grabbing item details).
+    ///
+    /// # Return Value Notes
+    /// * Only assets are returned. The outfit the bundle is worn as is left out.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let bundle_id = 39;
+    /// let contents = client.bundle_contents(bundle_id).await?;
+    ///
+    /// for asset in contents {
+    ///     println!("{} - {:?} robux", asset.name, asset.price);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn bundle_contents(&self, bundle_id: u64) -> Result<Vec<ItemDetails>, RoboatError> {
+        let details = self.bundle_details(bundle_id).await?;
+
+        let assets = details
+            .items
+            .into_iter()
+            .filter(|item| item.item_type == BundleItemType::Asset)
+            .map(|item| ItemArgs {
+                item_type: ItemType::Asset,
+                id: item.id,
+            })
+            .collect::<Vec<_>>();
+
+        if assets.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        self.item_details(assets).await
+    }
+
+    /// Goes through the pages of [`Client::avatar_catalog_search`] until an item matches
+    /// `predicate`, and returns that item along with how many pages were fetched.
+    ///
+    /// This stops as soon as a match is found, so it uses fewer requests than
+    /// collecting every page and searching them afterwards.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    ///
+    /// # Return Value Notes
+    /// * [`SearchUntilResult::item`] is `None` if every page was scanned without a match.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::catalog::avatar_catalog::CatalogQuery;
+    /// use roboat::ClientBuilder;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let query = CatalogQuery::new().keyword("valkyrie".to_string());
+    /// let result = client.search_until(&query, |item| item.id == 1365767).await?;
+    ///
+    /// match result.item {
+    ///     Some(item) => println!("Found {} after {} pages.", item.name, result.pages_scanned),
+    ///     None => println!("Not found in {} pages.", result.pages_scanned),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn search_until<F>(
+        &self,
+        query: &CatalogQuery,
+        mut predicate: F,
+    ) -> Result<SearchUntilResult, RoboatError>
+    where
+        F: FnMut(&ItemDetails) -> bool,
+    {
+        let mut cursor = None;
+        let mut pages_scanned = 0;
+
+        loop {
+            let (items, next_page_cursor) = self.avatar_catalog_search(query, cursor).await?;
+            pages_scanned += 1;
+
+            if let Some(item) = items.into_iter().find(|item| predicate(item)) {
+                return Ok(SearchUntilResult {
+                    item: Some(item),
+                    pages_scanned,
+                });
+            }
+
+            match next_page_cursor {
+                Some(next_page_cursor) => cursor = Some(next_page_cursor),
+                None => {
+                    return Ok(SearchUntilResult {
+                        item: None,
+                        pages_scanned,
+                    })
+                }
+            }
+        }
+    }
+
+    /// Resolves the product id, collectible item id, and collectible product id of an asset
+    /// using <https://catalog.roblox.com/v1/catalog/items/details> and
+    /// <https://apis.roblox.com/marketplace-items/v1/items/details>.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    /// * Makes a second request only if the item is a collectible.
+    ///
+    /// # Return Value Notes
+    /// * The collectible ids are `None` if the item is not a collectible.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ids::AssetId;
+    /// use roboat::ClientBuilder;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let ids = client.resolve_item_ids(AssetId(1365767)).await?;
+    /// println!("Product Id: {}", ids.product_id);
+    /// println!("Collectible Item Id: {:?}", ids.collectible_item_id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn resolve_item_ids(&self, asset_id: AssetId) -> Result<ItemIds, RoboatError> {
+        let item = ItemArgs {
+            item_type: ItemType::Asset,
+            id: asset_id.0,
+        };
+
+        let details = self
+            .item_details(vec![item])
+            .await?
+            .pop()
+            .ok_or(RoboatError::MalformedResponse)?;
+
+        let collectible_product_id = match &details.collectible_item_id {
+            Some(collectible_item_id) => {
+                match self
+                    .collectible_product_id_internal(collectible_item_id.clone())
+                    .await
+                {
+                    Ok(x) => x,
+                    Err(e) => match e {
+                        RoboatError::InvalidXcsrf(new_xcsrf) => {
+                            self.set_xcsrf(new_xcsrf).await;
+
+                            self.collectible_product_id_internal(collectible_item_id.clone())
+                                .await?
+                        }
+                        _ => return Err(e),
+                    },
+                }
+            }
+            None => None,
+        };
+
+        let ids = ItemIds {
+            asset_id,
+            product_id: details.product_id,
+            collectible_item_id: details.collectible_item_id,
+            collectible_product_id,
+        };
+
+        Ok(ids)
+    }
 }
 
 mod internal {
-    use super::{request_types, ItemArgs, ItemDetails, ITEM_DETAILS_API};
+    use super::{
+        request_types, ItemArgs, ItemDetails, COLLECTIBLE_ITEM_DETAILS_API, ITEM_DETAILS_API,
+    };
     use crate::XCSRF_HEADER;
     use crate::{Client, RoboatError};
+    use reqwest::header::{self, HeaderValue};
     use std::convert::TryFrom;
 
     impl Client {
@@ -523,15 +1580,13 @@ mod internal {
                     .collect(),
             };
 
-            let request_result = self
+            let request = self
                 .reqwest_client
                 .post(ITEM_DETAILS_API)
                 .header(XCSRF_HEADER, self.xcsrf().await)
-                .json(&request_body)
-                .send()
-                .await;
+                .json(&request_body);
 
-            let response = Self::validate_request_result(request_result).await?;
+            let response = self.send_request(request).await?;
             let raw = Self::parse_to_raw::<request_types::ItemDetailsResponse>(response).await?;
 
             let mut item_details = Vec::new();
@@ -543,5 +1598,34 @@ mod internal {
 
             Ok(item_details)
         }
+
+        pub(super) async fn collectible_product_id_internal(
+            &self,
+            collectible_item_id: String,
+        ) -> Result<Option<String>, RoboatError> {
+            let request_body = request_types::CollectibleItemDetailsReqBody {
+                item_ids: vec![collectible_item_id],
+            };
+
+            let cookie_string = self.cookie_string().unwrap_or(HeaderValue::from_static(""));
+
+            let request = self
+                .reqwest_client
+                .post(COLLECTIBLE_ITEM_DETAILS_API)
+                .header(header::COOKIE, cookie_string)
+                .header(XCSRF_HEADER, self.xcsrf().await)
+                .json(&request_body);
+
+            let response = self.send_request(request).await?;
+            let raw = Self::parse_to_raw::<Vec<request_types::CollectibleItemDetailsRaw>>(response)
+                .await?;
+
+            let collectible_details = raw
+                .into_iter()
+                .next()
+                .ok_or(RoboatError::MalformedResponse)?;
+
+            Ok(collectible_details.collectible_product_id)
+        }
     }
 }
@@ -1,15 +1,37 @@
 use super::{
-    CreatorType, Genre, ItemArgs, ItemRestriction, ItemStatus, ItemType, PremiumPricing,
-    PriceStatus,
+    BundleItemType, CreatorType, Genre, ItemArgs, ItemRestriction, ItemStatus, ItemType,
+    PremiumPricing, PriceStatus,
 };
+use crate::ids::ProductId;
 
 use serde::{Deserialize, Serialize};
 
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct CatalogSearchIdsResponse {
+    pub next_page_cursor: Option<String>,
+    pub data: Vec<CatalogSearchItemRaw>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct CatalogSearchItemRaw {
+    pub id: u64,
+    pub item_type: ItemType,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub(super) struct ItemDetailsResponse {
     pub data: Vec<ItemDetailsRaw>,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct CatalogSearchResponse {
+    pub next_page_cursor: Option<String>,
+    pub data: Vec<ItemDetailsRaw>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub(super) struct ItemDetailsRaw {
@@ -19,7 +41,7 @@ pub(super) struct ItemDetailsRaw {
     pub asset_type: Option<u64>,
     pub name: Option<String>,
     pub description: Option<String>,
-    pub product_id: Option<u64>,
+    pub product_id: Option<ProductId>,
     pub genres: Option<Vec<Genre>>,
     pub item_status: Option<Vec<ItemStatus>>,
     pub item_restrictions: Option<Vec<ItemRestriction>>,
@@ -34,6 +56,8 @@ pub(super) struct ItemDetailsRaw {
     pub favorite_count: Option<u64>,
     pub premium_pricing: Option<PremiumPricing>,
     pub price_status: Option<PriceStatus>,
+    /// Only exists if the item is a collectible (limited) item.
+    pub collectible_item_id: Option<String>,
     /// It is unknown as to what type this value is.
     /// The farthest it can be tracked by reverse engineering is that the value
     /// is fed into a `new Date()` constructor in js.
@@ -55,6 +79,19 @@ pub(super) struct ItemArgsReq {
     pub id: u64,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct CollectibleItemDetailsReqBody {
+    pub item_ids: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct CollectibleItemDetailsRaw {
+    pub collectible_item_id: String,
+    pub collectible_product_id: Option<String>,
+}
+
 impl From<ItemArgs> for ItemArgsReq {
     fn from(item: ItemArgs) -> Self {
         Self {
@@ -63,3 +100,42 @@ impl From<ItemArgs> for ItemArgsReq {
         }
     }
 }
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct BundleDetailsRaw {
+    pub id: u64,
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    pub bundle_type: String,
+    pub items: Vec<BundleItemRaw>,
+    pub creator: BundleCreatorRaw,
+    pub product: Option<BundleProductRaw>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct BundleItemRaw {
+    pub id: u64,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub item_type: BundleItemType,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct BundleCreatorRaw {
+    pub id: u64,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub creator_type: CreatorType,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct BundleProductRaw {
+    pub id: u64,
+    pub is_for_sale: bool,
+    pub price_in_robux: Option<u64>,
+}
@@ -0,0 +1,89 @@
+use crate::{CircuitBreakerConfig, RoboatError};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// The longest a circuit stays open. Longer cooldowns are clamped to this, so that the end
+/// of the cooldown always fits in an [`Instant`].
+const MAX_COOLDOWN: Duration = Duration::from_secs(365 * 24 * 60 * 60);
+
+/// Stops sending requests to a host for a while after it fails too many times in a row,
+/// so that monitors do not burn their rate limits while Roblox is down.
+///
+/// Once the cooldown is over, requests are let through again (including any sent at the
+/// same time). If one fails, the circuit opens again right away; if one succeeds, the
+/// circuit closes.
+#[derive(Debug)]
+pub(crate) struct CircuitBreaker {
+    failure_threshold: u32,
+    cooldown: Duration,
+    hosts: Mutex<HashMap<String, HostState>>,
+}
+
+#[derive(Debug, Default)]
+struct HostState {
+    consecutive_failures: u32,
+    open_until: Option<Instant>,
+}
+
+impl CircuitBreaker {
+    /// Creates a circuit breaker from its config. A threshold of 0 is treated as 1, and a
+    /// cooldown longer than a year is treated as a year.
+    pub(crate) fn new(config: CircuitBreakerConfig) -> Self {
+        Self {
+            failure_threshold: config.failure_threshold.max(1),
+            cooldown: Duration::from_millis(config.cooldown_millis).min(MAX_COOLDOWN),
+            hosts: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns [`RoboatError::CircuitOpen`] if requests to `host` are currently blocked.
+    pub(crate) fn check(&self, host: &str) -> Result<(), RoboatError> {
+        let hosts = self.hosts.lock().unwrap();
+
+        let open_until = match hosts.get(host).and_then(|state| state.open_until) {
+            Some(open_until) => open_until,
+            None => return Ok(()),
+        };
+
+        let now = Instant::now();
+
+        match open_until > now {
+            true => Err(RoboatError::CircuitOpen {
+                host: host.to_string(),
+                retry_after: open_until - now,
+            }),
+            false => Ok(()),
+        }
+    }
+
+    /// Records the result of a request sent to `host`.
+    pub(crate) fn record<T>(&self, host: &str, result: &Result<T, RoboatError>) {
+        let mut hosts = self.hosts.lock().unwrap();
+
+        if !result.as_ref().is_err_and(is_outage_error) {
+            hosts.remove(host);
+            return;
+        }
+
+        let state = hosts.entry(host.to_string()).or_default();
+        state.consecutive_failures += 1;
+
+        if state.consecutive_failures >= self.failure_threshold {
+            let now = Instant::now();
+            state.open_until = Some(now.checked_add(self.cooldown).unwrap_or(now));
+        }
+    }
+}
+
+/// Whether an error means the host is likely having an outage, as opposed to the request
+/// itself being wrong.
+fn is_outage_error(error: &RoboatError) -> bool {
+    match error {
+        RoboatError::InternalServerError => true,
+        // Bad gateway, service unavailable, and gateway timeout.
+        RoboatError::UnidentifiedStatusCode(502..=504) => true,
+        RoboatError::ReqwestError(e) => e.is_timeout() || e.is_connect(),
+        _ => false,
+    }
+}
@@ -0,0 +1,203 @@
+use crate::cancellation::with_deadline;
+use crate::retry::Backoff;
+use crate::{Client, RoboatError, API_KEY_HEADER};
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, Instant};
+
+mod request_types;
+
+/// Where operations returned by the assets api (such as asset uploads) are polled from.
+const ASSETS_OPERATIONS_API: &str = "https://apis.roblox.com/assets/v1/";
+
+/// Where every other operation is polled from.
+const CLOUD_V2_API: &str = "https://apis.roblox.com/cloud/v2/";
+
+/// How long [`Client::wait_for_operation`] waits before polling an operation the first time.
+const OPERATION_POLL_INITIAL_DELAY: Duration = Duration::from_millis(500);
+/// The longest [`Client::wait_for_operation`] waits between polls.
+const OPERATION_POLL_MAX_DELAY: Duration = Duration::from_secs(10);
+
+/// A long-running Open Cloud operation, such as an asset upload. Returned by endpoints
+/// that finish their work in the background, and waited on using [`Client::wait_for_operation`].
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Operation {
+    /// The path of the operation, such as `"operations/{operation_id}"`.
+    pub path: String,
+    /// Whether the operation has finished.
+    pub done: bool,
+    /// The result of the operation. Only exists if the operation finished successfully.
+    pub response: Option<serde_json::Value>,
+    /// Why the operation failed. Only exists if the operation finished with an error.
+    pub error: Option<OperationError>,
+}
+
+/// Why an Open Cloud [`Operation`] failed.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct OperationError {
+    /// The error code, such as `"INVALID_ARGUMENT"`. Empty if Roblox did not include one.
+    pub code: String,
+    /// A description of the error.
+    pub message: String,
+}
+
+impl Operation {
+    /// Creates an unfinished operation from its path, such as one returned by an endpoint
+    /// this library does not wrap.
+    pub fn new(path: String) -> Self {
+        Self {
+            path,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the url the operation is polled from.
+    fn url(&self) -> String {
+        match self.path.starts_with("operations/") {
+            true => format!("{}{}", ASSETS_OPERATIONS_API, self.path),
+            false => format!("{}{}", CLOUD_V2_API, self.path),
+        }
+    }
+
+    /// Returns the result of a finished operation, or `None` if it has not finished yet.
+    fn into_result(self) -> Option<Result<serde_json::Value, RoboatError>> {
+        if !self.done {
+            return None;
+        }
+
+        match self.error {
+            Some(error) => Some(Err(RoboatError::OperationFailed(error))),
+            None => Some(Ok(self.response.unwrap_or_default())),
+        }
+    }
+}
+
+impl From<request_types::OperationRaw> for Operation {
+    fn from(raw: request_types::OperationRaw) -> Self {
+        let error = raw.error.map(|error| {
+            let code = match error.code {
+                Some(serde_json::Value::String(code)) => code,
+                Some(code) => code.to_string(),
+                None => String::new(),
+            };
+
+            OperationError {
+                code,
+                message: error.message,
+            }
+        });
+
+        Self {
+            path: raw.path,
+            done: raw.done,
+            response: raw.response,
+            error,
+        }
+    }
+}
+
+impl Client {
+    /// Waits for an Open Cloud operation to finish, polling it with a growing delay, and
+    /// returns its result.
+    ///
+    /// Operations from the assets api (paths starting with `operations/`) are polled from
+    /// <https://apis.roblox.com/assets/v1/operations/{operation_id}>, and every other operation
+    /// is polled from <https://apis.roblox.com/cloud/v2/{path}>.
+    ///
+    /// # Notes
+    /// * Requires an Open Cloud api key set using [`ClientBuilder::api_key`](crate::ClientBuilder::api_key).
+    /// * Does not require a valid roblosecurity.
+    /// * Returns right away without a request if `operation` has already finished.
+    /// * Polls start 0.5 seconds apart and grow to at most 10 seconds apart.
+    ///
+    /// # Return Value Notes
+    /// * Returns the `response` of the operation, which depends on the endpoint that started it.
+    ///   This is `null` if the operation finished without a response.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * [`RoboatError::ApiKeyNotSet`] - If no api key is set.
+    /// * [`RoboatError::OperationFailed`] - If the operation finished with an error.
+    /// * [`RoboatError::DeadlineExceeded`] - If the operation did not finish within `timeout`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::cloud::Operation;
+    /// use roboat::ClientBuilder;
+    /// use std::time::Duration;
+    ///
+    /// const API_KEY: &str = "api_key";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().api_key(API_KEY.to_string()).build();
+    ///
+    /// let operation = Operation::new("operations/0a1b2c3d".to_string());
+    /// let response = client
+    ///     .wait_for_operation(operation, Duration::from_secs(60))
+    ///     .await?;
+    ///
+    /// println!("Operation finished: {}", response);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn wait_for_operation(
+        &self,
+        operation: Operation,
+        timeout: Duration,
+    ) -> Result<serde_json::Value, RoboatError> {
+        let deadline = Instant::now() + timeout;
+
+        with_deadline(deadline, async {
+            let mut backoff =
+                Backoff::new(OPERATION_POLL_INITIAL_DELAY).max_delay(OPERATION_POLL_MAX_DELAY);
+
+            let mut operation = operation;
+
+            loop {
+                let url = operation.url();
+
+                if let Some(result) = operation.into_result() {
+                    return result;
+                }
+
+                tokio::time::sleep(backoff.next_delay()).await;
+
+                operation = self.operation(url).await?;
+            }
+        })
+        .await
+    }
+
+    /// Grabs the current state of an operation from `url`.
+    async fn operation(&self, url: String) -> Result<Operation, RoboatError> {
+        let api_key = self.api_key()?;
+
+        let request = self.reqwest_client.get(url).header(API_KEY_HEADER, api_key);
+
+        let response = self.send_request(request).await?;
+        let raw = Self::parse_to_raw::<request_types::OperationRaw>(response).await?;
+
+        Ok(Operation::from(raw))
+    }
+}
+
+/// Parses an Open Cloud duration, such as `"3600s"` or `"1.5s"`.
+///
+/// Open Cloud sends durations as a number of seconds followed by an `s`, rather than as
+/// ISO 8601 durations.
+pub(crate) fn parse_duration(duration: &str) -> Result<Duration, RoboatError> {
+    duration
+        .strip_suffix('s')
+        .and_then(|seconds| seconds.parse::<f64>().ok())
+        .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+        .ok_or(RoboatError::MalformedResponse)
+}
+
+/// Formats a duration the way Open Cloud expects it, such as `"3600s"`. Fractions of a
+/// second are kept only if there are any.
+pub(crate) fn format_duration(duration: Duration) -> String {
+    match duration.subsec_nanos() {
+        0 => format!("{}s", duration.as_secs()),
+        _ => format!("{}s", duration.as_secs_f64()),
+    }
+}
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct OperationRaw {
+    pub path: String,
+    #[serde(default)]
+    pub done: bool,
+    pub response: Option<serde_json::Value>,
+    pub error: Option<OperationErrorRaw>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(super) struct OperationErrorRaw {
+    /// Either a number or a name such as `"INVALID_ARGUMENT"`, depending on the api.
+    pub code: Option<serde_json::Value>,
+    #[serde(default)]
+    pub message: String,
+}
@@ -0,0 +1,141 @@
+use crate::{ClientBuilder, ClientConfig, RoboatError};
+use serde::Deserialize;
+use std::path::Path;
+use std::str::FromStr;
+
+const ROBLOSECURITY_ENV: &str = "ROBOAT_ROBLOSECURITY";
+const API_KEY_ENV: &str = "ROBOAT_API_KEY";
+const PROXY_ENV: &str = "ROBOAT_PROXY";
+const TIMEOUT_MILLIS_ENV: &str = "ROBOAT_TIMEOUT_MILLIS";
+const REQUESTS_PER_SECOND_ENV: &str = "ROBOAT_REQUESTS_PER_SECOND";
+const LOCALE_ENV: &str = "ROBOAT_LOCALE";
+
+/// The layout of a file read by [`ClientBuilder::from_toml`]. This is a [`ClientConfig`]
+/// with an optional roblosecurity and api key next to its fields.
+#[derive(Deserialize)]
+struct ConfigFile {
+    roblosecurity: Option<String>,
+    api_key: Option<String>,
+    #[serde(flatten)]
+    config: ClientConfig,
+}
+
+impl ClientBuilder {
+    /// Creates a new [`ClientBuilder`] from environment variables. Variables that are not
+    /// set are left at their defaults.
+    ///
+    /// | Variable | Setting |
+    /// |----------|---------|
+    /// | `ROBOAT_ROBLOSECURITY` | [`ClientBuilder::roblosecurity`] |
+    /// | `ROBOAT_API_KEY` | [`ClientBuilder::api_key`] |
+    /// | `ROBOAT_PROXY` | [`ClientBuilder::proxy`] |
+    /// | `ROBOAT_TIMEOUT_MILLIS` | [`ClientBuilder::timeout`] |
+    /// | `ROBOAT_REQUESTS_PER_SECOND` | [`ClientBuilder::rate_limit`] |
+    /// | `ROBOAT_LOCALE` | [`ClientBuilder::locale`] |
+    ///
+    /// # Errors
+    /// * [`RoboatError::InvalidConfig`] - If a variable is set to a value that cannot be parsed.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::from_env()?.build();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_env() -> Result<Self, RoboatError> {
+        let config = ClientConfig {
+            proxy: std::env::var(PROXY_ENV).ok(),
+            timeout_millis: parse_env(TIMEOUT_MILLIS_ENV)?,
+            requests_per_second: parse_env(REQUESTS_PER_SECOND_ENV)?,
+            locale: std::env::var(LOCALE_ENV).ok(),
+            ..Default::default()
+        };
+
+        builder_from_parts(
+            std::env::var(ROBLOSECURITY_ENV).ok(),
+            std::env::var(API_KEY_ENV).ok(),
+            config,
+        )
+    }
+
+    /// Creates a new [`ClientBuilder`] from a TOML file. The file holds the fields of a
+    /// [`ClientConfig`], plus an optional `roblosecurity` and `api_key`. Missing fields are left at their defaults.
+    ///
+    /// ```toml
+    /// roblosecurity = "roblosecurity"
+    /// proxy = "http://127.0.0.1:8080"
+    /// timeout_millis = 10000
+    /// requests_per_second = 5
+    ///
+    /// [dns_overrides]
+    /// "economy.roblox.com" = "128.116.0.1:443"
+    /// ```
+    ///
+    /// # Errors
+    /// * [`RoboatError::IoError`] - If the file cannot be read.
+    /// * [`RoboatError::InvalidConfig`] - If the file is not valid TOML or has a field of the wrong type.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::from_toml("roboat.toml")?.build();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_toml(path: impl AsRef<Path>) -> Result<Self, RoboatError> {
+        let contents = std::fs::read_to_string(path).map_err(RoboatError::IoError)?;
+
+        let config_file = toml::from_str::<ConfigFile>(&contents)
+            .map_err(|e| RoboatError::InvalidConfig(e.to_string()))?;
+
+        builder_from_parts(
+            config_file.roblosecurity,
+            config_file.api_key,
+            config_file.config,
+        )
+    }
+}
+
+/// Builds a [`ClientBuilder`], checking the proxy url and locale up front so that
+/// [`ClientBuilder::build`] does not panic on them later.
+fn builder_from_parts(
+    roblosecurity: Option<String>,
+    api_key: Option<String>,
+    config: ClientConfig,
+) -> Result<ClientBuilder, RoboatError> {
+    if let Some(proxy_url) = &config.proxy {
+        reqwest::Proxy::all(proxy_url)
+            .map_err(|_| RoboatError::InvalidConfig(format!("Invalid proxy url {}", proxy_url)))?;
+    }
+
+    if let Some(locale) = &config.locale {
+        reqwest::header::HeaderValue::from_str(locale)
+            .map_err(|_| RoboatError::InvalidConfig(format!("Invalid locale {}", locale)))?;
+    }
+
+    let builder = ClientBuilder::from_config(config);
+
+    let builder = match roblosecurity {
+        Some(roblosecurity) => builder.roblosecurity(roblosecurity),
+        None => builder,
+    };
+
+    Ok(match api_key {
+        Some(api_key) => builder.api_key(api_key),
+        None => builder,
+    })
+}
+
+fn parse_env<T: FromStr>(name: &str) -> Result<Option<T>, RoboatError> {
+    match std::env::var(name) {
+        Ok(value) => value.parse().map(Some).map_err(|_| {
+            RoboatError::InvalidConfig(format!("Invalid value {} for {}", value, name))
+        }),
+        Err(_) => Ok(None),
+    }
+}
@@ -0,0 +1,176 @@
+use crate::{Client, RoboatError};
+use chrono::{DateTime, Utc};
+use futures_util::future::FutureExt;
+use futures_util::stream::{self, BoxStream, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::any::Any;
+use std::fmt::Debug;
+use std::future::Future;
+use std::panic::AssertUnwindSafe;
+use std::path::PathBuf;
+
+/// What a watcher (or a task in a [`Scope`](crate::Scope)) was doing when it panicked.
+/// Saved to the [`StateStore`] set using [`ClientBuilder::state_store`](crate::ClientBuilder::state_store)
+/// and returned in [`RoboatError::WatcherCrashed`].
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct CrashReport {
+    /// The name of the method that started the watcher, such as `"watch_group_audit_log"`.
+    pub watcher: String,
+    /// The message the watcher panicked with. This is `"<unknown panic>"` if the panic
+    /// was not given a string.
+    pub panic_message: String,
+    /// The state of the watcher right before the poll that panicked, such as the last
+    /// value seen and entries that were not yielded yet.
+    pub state: serde_json::Value,
+    /// When the panic was caught.
+    pub crashed_at: DateTime<Utc>,
+}
+
+/// Somewhere to save a [`CrashReport`] when a watcher panics, such as a directory or a channel.
+/// Set using [`ClientBuilder::state_store`](crate::ClientBuilder::state_store).
+///
+/// Implemented for:
+/// * [`PathBuf`], which writes each report as a JSON file named
+///   `{watcher}-{timestamp}.json` inside the directory. Inside a tokio runtime, the file is
+///   written on the blocking thread pool so that the crashed stream is not held up.
+/// * `std::sync::mpsc::Sender<CrashReport>` and `tokio::sync::mpsc::UnboundedSender<CrashReport>`,
+///   which send each report through the channel (ignoring a closed receiver).
+///
+/// # Example
+/// ```
+/// use roboat::crash::{CrashReport, StateStore};
+/// use roboat::ClientBuilder;
+/// use std::sync::Arc;
+///
+/// #[derive(Debug)]
+/// struct CrashPrinter;
+///
+/// impl StateStore for CrashPrinter {
+///     fn save(&self, report: &CrashReport) {
+///         eprintln!("{} crashed: {}", report.watcher, report.panic_message);
+///     }
+/// }
+///
+/// let client = ClientBuilder::new()
+///     .state_store(Arc::new(CrashPrinter))
+///     .build();
+/// ```
+pub trait StateStore: Debug + Send + Sync {
+    /// Saves a report. This is called from inside the stream that crashed, so it should not block.
+    fn save(&self, report: &CrashReport);
+}
+
+impl StateStore for PathBuf {
+    fn save(&self, report: &CrashReport) {
+        let Ok(contents) = serde_json::to_vec_pretty(report) else {
+            return;
+        };
+
+        let file_name = format!(
+            "{}-{}.json",
+            report.watcher,
+            report.crashed_at.format("%Y%m%dT%H%M%S%.3fZ")
+        );
+
+        let directory = self.clone();
+
+        let write = move || {
+            let _ = std::fs::create_dir_all(&directory);
+            let _ = std::fs::write(directory.join(file_name), contents);
+        };
+
+        match tokio::runtime::Handle::try_current() {
+            Ok(handle) => {
+                handle.spawn_blocking(write);
+            }
+            Err(_) => write(),
+        }
+    }
+}
+
+impl StateStore for std::sync::mpsc::Sender<CrashReport> {
+    fn save(&self, report: &CrashReport) {
+        let _ = self.send(report.clone());
+    }
+}
+
+impl StateStore for tokio::sync::mpsc::UnboundedSender<CrashReport> {
+    fn save(&self, report: &CrashReport) {
+        let _ = self.send(report.clone());
+    }
+}
+
+/// The state of a `watch_*` helper that is saved in a [`CrashReport`] if a poll panics.
+pub(crate) trait WatcherState {
+    /// The name of the method that starts the watcher.
+    const NAME: &'static str;
+
+    /// Returns the parts of the state worth saving, such as the last value seen.
+    fn snapshot(&self) -> serde_json::Value;
+}
+
+impl Client {
+    /// Builds a report for a caught panic, saves it to the state store (if one is set),
+    /// and returns it as a [`RoboatError::WatcherCrashed`].
+    pub(crate) fn report_crash(
+        &self,
+        watcher: &str,
+        state: serde_json::Value,
+        panic: Box<dyn Any + Send>,
+    ) -> RoboatError {
+        let report = CrashReport {
+            watcher: watcher.to_string(),
+            panic_message: panic_message(panic.as_ref()),
+            state,
+            crashed_at: Utc::now(),
+        };
+
+        log::error!("{} crashed: {}", report.watcher, report.panic_message);
+
+        if let Some(state_store) = &self.state_store {
+            state_store.save(&report);
+        }
+
+        RoboatError::WatcherCrashed(Box::new(report))
+    }
+}
+
+/// Turns a watcher into a stream like [`stream::unfold`], except that a panic during a
+/// poll is caught. The panic is reported using [`Client::report_crash`] and yielded as
+/// an error, after which the stream ends.
+pub(crate) fn guarded_watcher<'a, W, T, F, Fut>(
+    client: &'a Client,
+    watcher: W,
+    poll: F,
+) -> BoxStream<'a, Result<T, RoboatError>>
+where
+    W: WatcherState + Send + 'a,
+    T: Send + 'a,
+    F: Fn(W) -> Fut + Send + 'a,
+    Fut: Future<Output = Option<(Result<T, RoboatError>, W)>> + Send + 'a,
+{
+    stream::unfold(Some(watcher), move |watcher| {
+        let poll = watcher.map(|watcher| (watcher.snapshot(), poll(watcher)));
+
+        async move {
+            let (state, poll) = poll?;
+
+            match AssertUnwindSafe(poll).catch_unwind().await {
+                Ok(next) => next.map(|(item, watcher)| (item, Some(watcher))),
+                Err(panic) => Some((Err(client.report_crash(W::NAME, state, panic)), None)),
+            }
+        }
+    })
+    .boxed()
+}
+
+/// Returns the message of a caught panic.
+fn panic_message(panic: &(dyn Any + Send)) -> String {
+    match panic.downcast_ref::<&str>() {
+        Some(message) => message.to_string(),
+        None => match panic.downcast_ref::<String>() {
+            Some(message) => message.clone(),
+            None => "<unknown panic>".to_string(),
+        },
+    }
+}
@@ -0,0 +1,90 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::Debug;
+use std::io::Write;
+use std::sync::Mutex;
+
+/// A request sent by a [`Client`](crate::Client) and the response it got back. Given to a
+/// [`DebugSink`] set using [`ClientBuilder::debug_capture`](crate::ClientBuilder::debug_capture).
+///
+/// Headers are never captured, as they contain the roblosecurity and x-csrf-token.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct CapturedExchange {
+    /// The method of the request (such as "GET" or "POST").
+    pub method: String,
+    /// The full url of the request.
+    pub url: String,
+    /// The body of the request. Does not exist if the request had no body.
+    /// Bodies that are streamed (such as file uploads) are replaced with `"<streamed body>"`.
+    pub request_body: Option<String>,
+    /// The status code of the response. Does not exist if the request failed before a
+    /// response was received (such as from a connection error).
+    pub status: Option<u16>,
+    /// The body of the response. Does not exist if no response was received.
+    pub response_body: Option<String>,
+}
+
+/// Somewhere to send every [`CapturedExchange`], such as a file or a channel.
+/// Set using [`ClientBuilder::debug_capture`](crate::ClientBuilder::debug_capture).
+///
+/// Implemented for:
+/// * `std::sync::mpsc::Sender<CapturedExchange>` and `tokio::sync::mpsc::UnboundedSender<CapturedExchange>`,
+///   which send each exchange through the channel (ignoring a closed receiver).
+/// * `Mutex<std::fs::File>`, which writes each exchange as a line of JSON.
+///
+/// # Example
+/// ```
+/// use roboat::{CapturedExchange, ClientBuilder, DebugSink};
+/// use std::sync::Arc;
+///
+/// #[derive(Debug)]
+/// struct FailedRequestPrinter;
+///
+/// impl DebugSink for FailedRequestPrinter {
+///     fn capture(&self, exchange: CapturedExchange) {
+///         if exchange.status != Some(200) {
+///             println!("{} {} -> {:?}", exchange.method, exchange.url, exchange.response_body);
+///         }
+///     }
+/// }
+///
+/// let client = ClientBuilder::new()
+///     .debug_capture(Arc::new(FailedRequestPrinter))
+///     .build();
+/// ```
+pub trait DebugSink: Debug + Send + Sync {
+    /// Receives a request and its response. This is called before the response is
+    /// returned to the caller, so it should not block.
+    fn capture(&self, exchange: CapturedExchange);
+}
+
+impl DebugSink for std::sync::mpsc::Sender<CapturedExchange> {
+    fn capture(&self, exchange: CapturedExchange) {
+        let _ = self.send(exchange);
+    }
+}
+
+impl DebugSink for tokio::sync::mpsc::UnboundedSender<CapturedExchange> {
+    fn capture(&self, exchange: CapturedExchange) {
+        let _ = self.send(exchange);
+    }
+}
+
+impl DebugSink for Mutex<std::fs::File> {
+    fn capture(&self, exchange: CapturedExchange) {
+        let Ok(line) = serde_json::to_string(&exchange) else {
+            return;
+        };
+
+        // A poisoned lock only means another capture panicked, the file is still usable.
+        let mut file = self.lock().unwrap_or_else(|e| e.into_inner());
+        let _ = writeln!(file, "{}", line);
+    }
+}
+
+/// Returns the body of a request as a string, for use in [`CapturedExchange::request_body`].
+pub(crate) fn request_body_string(request: &reqwest::Request) -> Option<String> {
+    request.body().map(|body| match body.as_bytes() {
+        Some(bytes) => String::from_utf8_lossy(bytes).into_owned(),
+        None => "<streamed body>".to_string(),
+    })
+}
@@ -0,0 +1,269 @@
+use crate::catalog::avatar_catalog::CreatorType;
+use crate::ids::{PlaceId, UniverseId, UserId};
+use crate::{Client, RoboatError};
+use chrono::{DateTime, Utc};
+use reqwest::header;
+use serde::{Deserialize, Serialize};
+
+mod request_types;
+
+const UNIVERSES_API: &str = "https://develop.roblox.com/v1/universes/";
+const CAN_MANAGE_API: &str = "https://develop.roblox.com/v1/user/{user_id}/canmanage/{place_id}";
+const AUTHORIZED_UNIVERSES_API: &str = "https://develop.roblox.com/v1/user/universes";
+
+/// The page size used by [`Client::authorized_universes`]. The endpoint only accepts 10, 25 or 50.
+const AUTHORIZED_UNIVERSES_PAGE_SIZE: u64 = 50;
+
+/// A universe the authenticated user is allowed to edit, returned from [`Client::authorized_universes`].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct AuthorizedUniverse {
+    /// The id of the universe.
+    pub universe_id: UniverseId,
+    /// The name of the universe.
+    pub name: String,
+    /// The id of the universe's start place. May be missing for universes without places.
+    pub root_place_id: Option<PlaceId>,
+    /// Whether the universe is archived.
+    pub is_archived: bool,
+    /// Whether the universe is public.
+    pub is_active: bool,
+    /// Whether the universe belongs to a user or a group.
+    pub creator_type: CreatorType,
+    /// The id of the user or group that owns the universe.
+    pub creator_id: u64,
+    /// When the universe was created.
+    pub created: DateTime<Utc>,
+    /// When the universe was last updated.
+    pub updated: DateTime<Utc>,
+}
+
+impl Client {
+    /// Checks whether a user can edit a place using
+    /// <https://develop.roblox.com/v1/user/{user_id}/canmanage/{place_id}>.
+    ///
+    /// This is useful for servers that need to check that a requester really owns
+    /// (or has edit access to) the game they claim, such as plugin license servers.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    ///
+    /// # Return Value Notes
+    /// * Returns `true` if the user owns the place or has been given permission to edit it.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * [`RoboatError::MalformedResponse`] - If the endpoint reports that the check did not succeed.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ids::{PlaceId, UserId};
+    /// use roboat::ClientBuilder;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let user_id = UserId(2207291);
+    /// let place_id = PlaceId(1818);
+    ///
+    /// let can_manage = client.can_manage_place(user_id, place_id).await?;
+    /// println!("User {} can manage place {}: {}", user_id, place_id, can_manage);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn can_manage_place(
+        &self,
+        user_id: UserId,
+        place_id: PlaceId,
+    ) -> Result<bool, RoboatError> {
+        let formatted_url = CAN_MANAGE_API
+            .replace("{user_id}", &user_id.to_string())
+            .replace("{place_id}", &place_id.to_string());
+
+        let request = self.reqwest_client.get(formatted_url);
+
+        let response = self.send_request(request).await?;
+        let raw = Self::parse_to_raw::<request_types::CanManageResponse>(response).await?;
+
+        if !raw.success {
+            return Err(RoboatError::MalformedResponse);
+        }
+
+        Ok(raw.can_manage)
+    }
+
+    /// Gets a page of the universes the authenticated user can edit using
+    /// <https://develop.roblox.com/v1/user/universes>.
+    ///
+    /// Pair this with [`Client::can_manage_place`] to check a claim from the other side.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Only includes universes owned by the user, not group universes.
+    /// * Pages hold up to 50 universes.
+    ///
+    /// # Return Value Notes
+    /// * The first value is a vector of universes.
+    /// * The second value is the cursor for the next page of results. If there are no more pages, this will be `None`.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let (universes, next_cursor) = client.authorized_universes(None).await?;
+    ///
+    /// for universe in universes {
+    ///     println!("{} ({})", universe.name, universe.universe_id);
+    /// }
+    ///
+    /// println!("Next Cursor: {}", next_cursor.unwrap_or_default());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn authorized_universes(
+        &self,
+        cursor: Option<String>,
+    ) -> Result<(Vec<AuthorizedUniverse>, Option<String>), RoboatError> {
+        let cookie = self.cookie_string()?;
+
+        let mut query = vec![("limit", AUTHORIZED_UNIVERSES_PAGE_SIZE.to_string())];
+
+        if let Some(cursor) = cursor {
+            query.push(("cursor", cursor));
+        }
+
+        let request = self
+            .reqwest_client
+            .get(AUTHORIZED_UNIVERSES_API)
+            .query(&query)
+            .header(header::COOKIE, cookie);
+
+        let response = self.send_request(request).await?;
+        let raw =
+            Self::parse_to_raw::<request_types::AuthorizedUniversesResponse>(response).await?;
+
+        let mut universes = Vec::new();
+
+        for universe in raw.data {
+            let creator_type = match universe.creator_type.as_str() {
+                "User" => CreatorType::User,
+                "Group" => CreatorType::Group,
+                _ => return Err(RoboatError::MalformedResponse),
+            };
+
+            universes.push(AuthorizedUniverse {
+                universe_id: universe.id,
+                name: universe.name,
+                root_place_id: universe.root_place_id,
+                is_archived: universe.is_archived,
+                is_active: universe.is_active,
+                creator_type,
+                creator_id: universe.creator_target_id,
+                created: universe.created,
+                updated: universe.updated,
+            });
+        }
+
+        Ok((universes, raw.next_page_cursor))
+    }
+
+    /// Sets the order of the thumbnails (store images) of a game using
+    /// <https://develop.roblox.com/v1/universes/{universe_id}/thumbnails/order>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * The current account must have permission to edit the universe.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    ///
+    /// # Argument Notes
+    /// * `thumbnail_ids` are the ids of the game's thumbnails, in the order they should be shown.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ids::UniverseId;
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let universe_id = UniverseId(1234567890);
+    /// let thumbnail_ids = vec![333, 111, 222];
+    ///
+    /// client.set_game_thumbnail_order(universe_id, thumbnail_ids).await?;
+    /// println!("Reordered thumbnails.");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_game_thumbnail_order(
+        &self,
+        universe_id: UniverseId,
+        thumbnail_ids: Vec<u64>,
+    ) -> Result<(), RoboatError> {
+        match self
+            .set_game_thumbnail_order_internal(universe_id, &thumbnail_ids)
+            .await
+        {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.set_game_thumbnail_order_internal(universe_id, &thumbnail_ids)
+                        .await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+}
+
+mod internal {
+    use super::UNIVERSES_API;
+    use crate::ids::UniverseId;
+    use crate::{Client, RoboatError, XCSRF_HEADER};
+    use reqwest::header;
+
+    impl Client {
+        pub(super) async fn set_game_thumbnail_order_internal(
+            &self,
+            universe_id: UniverseId,
+            thumbnail_ids: &[u64],
+        ) -> Result<(), RoboatError> {
+            let formatted_url = format!("{}{}/thumbnails/order", UNIVERSES_API, universe_id);
+
+            let cookie = self.cookie_string()?;
+
+            let json = serde_json::json!({
+                "thumbnailIds": thumbnail_ids,
+            });
+
+            let request = self
+                .reqwest_client
+                .post(formatted_url)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, self.xcsrf().await)
+                .json(&json);
+
+            let _ = self
+                .send_mutating_request(request, serde_json::json!({}))
+                .await?;
+
+            // We don't need to do anything, we just need a 200 status code.
+
+            Ok(())
+        }
+    }
+}
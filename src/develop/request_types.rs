@@ -0,0 +1,32 @@
+use crate::ids::{PlaceId, UniverseId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct CanManageResponse {
+    pub success: bool,
+    pub can_manage: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct AuthorizedUniversesResponse {
+    pub previous_page_cursor: Option<String>,
+    pub next_page_cursor: Option<String>,
+    pub data: Vec<AuthorizedUniverseRaw>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct AuthorizedUniverseRaw {
+    pub id: UniverseId,
+    pub name: String,
+    pub root_place_id: Option<PlaceId>,
+    pub is_archived: bool,
+    pub is_active: bool,
+    pub creator_type: String,
+    pub creator_target_id: u64,
+    pub created: DateTime<Utc>,
+    pub updated: DateTime<Utc>,
+}
@@ -1,6 +1,15 @@
-use crate::{Client, Limit, RoboatError};
+use crate::catalog::avatar_catalog::{ItemArgs, ItemRestriction, ItemType, PriceStatus};
+use crate::crash::{guarded_watcher, WatcherState};
+use crate::ids::{AssetId, PlaceId, ProductId, Uaid, UniverseId, UserId};
+use crate::polling::{PollInterval, PollOutcome};
+use crate::retry::{Backoff, RetryPolicy};
+use crate::{ApiVersion, Client, Limit, RoboatError};
+use chrono::{DateTime, Utc};
+use futures_util::stream::BoxStream;
 use reqwest::header;
 use serde::{Deserialize, Serialize};
+use std::future::Future;
+use std::time::Duration;
 
 mod request_types;
 
@@ -10,13 +19,37 @@ const ROBUX_API_PART_2: &str = "/currency";
 const RESELLERS_API_PART_1: &str = "https://economy.roblox.com/v1/assets/";
 const RESELLERS_API_PART_2: &str = "/resellers";
 
-const TRANSACTIONS_API_PART_1: &str = "https://economy.roblox.com/v2/users/";
-const TRANSACTIONS_API_PART_2: &str = "/transactions";
+const RESALE_DATA_API_PART_1: &str = "https://economy.roblox.com/v1/assets/";
+const RESALE_DATA_API_PART_2: &str = "/resale-data";
+
+const SECONDS_PER_DAY: f64 = 86400.0;
+
+const GROUP_ECONOMY_API: &str = "https://economy.roblox.com/v1/groups/";
+
+/// The time frame of the revenue summary used by [`Client::group_funds`]. Pending robux
+/// are the same for every time frame, so the shortest one is used.
+const GROUP_FUNDS_TIME_FRAME: RevenueTimeFrame = RevenueTimeFrame::Day;
+
+const ASSET_DETAILS_API_PART_1: &str = "https://economy.roblox.com/v2/assets/";
+const ASSET_DETAILS_API_PART_2: &str = "/details";
+
+const TRANSACTIONS_API_PART_1: &str = "https://economy.roblox.com/";
+const TRANSACTIONS_API_PART_2: &str = "/users/";
+const GROUP_TRANSACTIONS_API_PART_2: &str = "/groups/";
+const TRANSACTIONS_API_PART_3: &str = "/transactions";
 
 const TOGGLE_SALE_API_PART_1: &str = "https://economy.roblox.com/v1/assets/";
 const TOGGLE_SALE_API_PART_2: &str = "/resellable-copies/";
 
-const USER_SALES_TRANSACTION_TYPE: &str = "Sale";
+/// How many times [`Client::buy_and_relist`] checks the inventory for the purchased copy.
+const RELIST_INVENTORY_ATTEMPTS: usize = 10;
+/// How long [`Client::buy_and_relist`] waits between inventory checks.
+const RELIST_INVENTORY_DELAY: Duration = Duration::from_secs(2);
+
+/// How long [`Client::purchase_limited_with_retries`] waits after the first failed attempt.
+const PURCHASE_RETRY_INITIAL_DELAY: Duration = Duration::from_millis(500);
+/// The longest [`Client::purchase_limited_with_retries`] waits between attempts.
+const PURCHASE_RETRY_MAX_DELAY: Duration = Duration::from_secs(8);
 
 /// Custom Roblox errors that occur when using [`Client::purchase_limited`].
 #[derive(
@@ -37,6 +70,7 @@ pub enum PurchaseLimitedError {
     /// However, Roblox will also throw this when it doesn't know what error to give.
     /// If you are trying to keep buying a limited item, ignore this error and try again until
     /// [`PurchaseLimitedError::ItemNotForSale`] is thrown.
+    /// [`Client::purchase_limited_with_retries`] does this for you.
     #[default]
     #[error("Pending Transaction.")]
     PendingTransaction,
@@ -60,21 +94,90 @@ pub enum PurchaseLimitedError {
     /// trying to buy the item until [`PurchaseLimitedError::ItemNotForSale`] is thrown.
     #[error("Unknown Roblox Error Message: {0}")]
     UnknownRobloxErrorMsg(String),
+    /// Thrown by [`Client::buy_and_relist`] when the cheapest listing costs more than the
+    /// max price. Contains the price of the cheapest listing.
+    #[error("Cheapest Listing Above Max Price: {0}")]
+    AboveMaxPrice(u64),
+}
+
+/// Custom Roblox errors that occur when using [`Client::purchase_product`].
+///
+/// These are kept apart from [`PurchaseLimitedError`] as Roblox words the errors for
+/// non-limited products differently.
+#[derive(
+    thiserror::Error,
+    Debug,
+    Default,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize,
+)]
+pub enum PurchaseError {
+    /// Thrown when the user has a pending transaction. Trying again after a minute usually works.
+    #[default]
+    #[error("Pending Transaction.")]
+    PendingTransaction,
+    /// Thrown when the product is not for sale. There is no point in retrying after this error.
+    #[error("Product Not For Sale.")]
+    NotForSale,
+    /// Thrown when the user does not have enough robux to buy the product.
+    /// There is no point in retrying after this error.
+    #[error("Not Enough Robux.")]
+    NotEnoughRobux,
+    /// Thrown when the price of the product is not the expected price. Check the new price
+    /// before trying again.
+    #[error("Price Changed.")]
+    PriceChanged,
+    /// Thrown when the user already owns the product. Developer products can be bought more
+    /// than once, so this is only thrown for gamepasses and catalog items.
+    #[error("Already Owned.")]
+    AlreadyOwned,
+    /// Thrown when an unknown error occurs. Contains the error message given by Roblox.
+    #[error("Unknown Roblox Error Message: {0}")]
+    UnknownRobloxErrorMsg(String),
+}
+
+/// Whether a seller (or purchaser) is a user or a group.
+#[allow(missing_docs)]
+#[derive(
+    Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize, Copy,
+)]
+pub enum SellerType {
+    #[default]
+    User,
+    Group,
+}
+
+impl SellerType {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::User => "User",
+            Self::Group => "Group",
+        }
+    }
 }
 
 /// A reseller of a resale listing.
 #[allow(missing_docs)]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
 pub struct Reseller {
+    /// The id of the reseller. This is a group id if `seller_type` is [`SellerType::Group`].
     pub user_id: u64,
     pub name: String,
+    /// Whether the reseller is a user or a group.
+    pub seller_type: SellerType,
 }
 
 /// A resale listing of a limited item.
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
 pub struct Listing {
     /// The unique asset id of the item.
-    pub uaid: u64,
+    pub uaid: Uaid,
     /// The price of the listing.
     pub price: u64,
     /// The reseller of the listing.
@@ -93,7 +196,7 @@ pub struct UserSale {
     /// Whether the sale is still pending
     pub is_pending: bool,
     /// The id if the user that purchased the asset.
-    pub user_id: u64,
+    pub user_id: UserId,
     /// The display name of the user that purchased the asset.
     pub user_display_name: String,
     /// The robux the user received after tax. Note that it's not certain that every
@@ -101,9 +204,734 @@ pub struct UserSale {
     /// that the item sold at (assuming 30% tax), use `robux_received * 1.428`.
     pub robux_received: u64,
     /// The asset id of the item that was sold.
-    pub asset_id: u64,
+    pub asset_id: AssetId,
+    /// The name of the asset that was sold.
+    pub asset_name: String,
+}
+
+/// A commission (affiliate) sale from the user's transaction history. These are earned when
+/// an item is sold inside an experience owned by the user. Retrieved from
+/// <https://economy.roblox.com/v2/users/{user_id}/transactions?transactionType=AffiliateSale>.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct CommissionSale {
+    /// The id of the transaction.
+    pub sale_id: u64,
+    /// Whether the sale is still pending.
+    pub is_pending: bool,
+    /// The id of the user that purchased the asset.
+    pub user_id: UserId,
+    /// The display name of the user that purchased the asset.
+    pub user_display_name: String,
+    /// The robux the user received as commission.
+    pub robux_received: u64,
+    /// The asset id of the item that was sold.
+    pub asset_id: AssetId,
     /// The name of the asset that was sold.
     pub asset_name: String,
+    /// The id of the universe the sale happened in. Does not exist if Roblox did not include the source.
+    pub universe_id: Option<UniverseId>,
+    /// The id of the place the sale happened in. Does not exist if Roblox did not include the source.
+    pub place_id: Option<PlaceId>,
+    /// The name of the place the sale happened in. Does not exist if Roblox did not include the source.
+    pub place_name: Option<String>,
+}
+
+/// The type of a transaction in the transaction history of the current account. Used by
+/// [`Client::transactions`].
+#[derive(
+    Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize, Copy,
+)]
+pub enum TransactionType {
+    /// Sales of items made by the account.
+    #[default]
+    Sale,
+    /// Purchases made by the account.
+    Purchase,
+    /// Commission earned from items sold inside experiences owned by the account.
+    AffiliateSale,
+    /// Robux exchanged for real money through the Developer Exchange.
+    DevEx,
+    /// Payouts received from groups.
+    GroupPayout,
+    /// Robux earned from ads shown in experiences owned by the account.
+    AdImpressionPayout,
+    /// Robux bought with real money.
+    CurrencyPurchase,
+    /// Robux sent or received as part of trades.
+    TradeRobux,
+    /// The monthly robux given to premium members.
+    PremiumStipend,
+    /// Robux earned from premium members playing experiences owned by the account.
+    EngagementPayout,
+    /// Robux earned from premium members playing experiences owned by groups of the account.
+    GroupEngagementPayout,
+}
+
+impl TransactionType {
+    /// Returns the name of the type as used by the `transactionType` parameter.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Sale => "Sale",
+            Self::Purchase => "Purchase",
+            Self::AffiliateSale => "AffiliateSale",
+            Self::DevEx => "DevEx",
+            Self::GroupPayout => "GroupPayout",
+            Self::AdImpressionPayout => "AdImpressionPayout",
+            Self::CurrencyPurchase => "CurrencyPurchase",
+            Self::TradeRobux => "TradeRobux",
+            Self::PremiumStipend => "PremiumStipend",
+            Self::EngagementPayout => "EngagementPayout",
+            Self::GroupEngagementPayout => "GroupEngagementPayout",
+        }
+    }
+}
+
+/// A purchase from the transaction history of the current account. Part of [`Transaction::Purchase`].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct PurchaseTransaction {
+    /// The id of the transaction.
+    pub id: u64,
+    /// When the purchase was made.
+    pub created: Option<DateTime<Utc>>,
+    /// Whether the purchase is still pending.
+    pub is_pending: bool,
+    /// The id of the user or group that sold the item.
+    pub seller_id: u64,
+    /// The name of the user or group that sold the item.
+    pub seller_name: String,
+    /// Whether the seller is a user or a group.
+    pub seller_type: SellerType,
+    /// The robux spent on the purchase.
+    pub robux_spent: u64,
+    /// The id of the item that was bought, if Roblox included it.
+    pub item_id: Option<u64>,
+    /// The name of the item that was bought, if Roblox included it.
+    pub item_name: Option<String>,
+}
+
+/// A transaction from the transaction history of the current account that is not a sale
+/// or a purchase, such as a group payout or a premium stipend. Part of [`Transaction`].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct TransactionRecord {
+    /// The id of the transaction.
+    pub id: u64,
+    /// When the transaction was made.
+    pub created: Option<DateTime<Utc>>,
+    /// Whether the transaction is still pending.
+    pub is_pending: bool,
+    /// The id of the other side of the transaction (such as the group of a group payout),
+    /// if there is one.
+    pub agent_id: Option<u64>,
+    /// The name of the other side of the transaction, if there is one.
+    pub agent_name: Option<String>,
+    /// The robux received (positive) or spent (negative).
+    pub robux: i64,
+    /// A description of what the transaction was for, if Roblox included one.
+    pub description: Option<String>,
+}
+
+/// A transaction from the transaction history of the current account, typed by its
+/// [`TransactionType`]. Retrieved from
+/// <https://economy.roblox.com/v2/users/{user_id}/transactions> using [`Client::transactions`].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub enum Transaction {
+    Sale(UserSale),
+    Purchase(PurchaseTransaction),
+    AffiliateSale(CommissionSale),
+    DevEx(TransactionRecord),
+    GroupPayout(TransactionRecord),
+    AdImpressionPayout(TransactionRecord),
+    CurrencyPurchase(TransactionRecord),
+    TradeRobux(TransactionRecord),
+    PremiumStipend(TransactionRecord),
+    EngagementPayout(TransactionRecord),
+    GroupEngagementPayout(TransactionRecord),
+}
+
+impl Transaction {
+    /// Returns the type of the transaction.
+    pub fn transaction_type(&self) -> TransactionType {
+        match self {
+            Self::Sale(_) => TransactionType::Sale,
+            Self::Purchase(_) => TransactionType::Purchase,
+            Self::AffiliateSale(_) => TransactionType::AffiliateSale,
+            Self::DevEx(_) => TransactionType::DevEx,
+            Self::GroupPayout(_) => TransactionType::GroupPayout,
+            Self::AdImpressionPayout(_) => TransactionType::AdImpressionPayout,
+            Self::CurrencyPurchase(_) => TransactionType::CurrencyPurchase,
+            Self::TradeRobux(_) => TransactionType::TradeRobux,
+            Self::PremiumStipend(_) => TransactionType::PremiumStipend,
+            Self::EngagementPayout(_) => TransactionType::EngagementPayout,
+            Self::GroupEngagementPayout(_) => TransactionType::GroupEngagementPayout,
+        }
+    }
+
+    /// Parses a raw transaction as the given type.
+    fn from_raw(
+        transaction_type: TransactionType,
+        raw: request_types::TransactionRaw,
+    ) -> Result<Self, RoboatError> {
+        let transaction = match transaction_type {
+            TransactionType::Sale => Self::Sale(UserSale::try_from(raw)?),
+            TransactionType::AffiliateSale => Self::AffiliateSale(CommissionSale::try_from(raw)?),
+            TransactionType::Purchase => {
+                let agent = raw.agent.ok_or(RoboatError::MalformedResponse)?;
+
+                let seller_type = match agent.agent_type.as_deref() {
+                    Some("Group") => SellerType::Group,
+                    _ => SellerType::User,
+                };
+
+                let (item_id, item_name) = match raw.details {
+                    Some(details) => (details.id, details.name),
+                    None => (None, None),
+                };
+
+                Self::Purchase(PurchaseTransaction {
+                    id: raw.id,
+                    created: raw.created,
+                    is_pending: raw.is_pending,
+                    seller_id: agent.id,
+                    seller_name: agent.name,
+                    seller_type,
+                    robux_spent: raw.currency.amount.unsigned_abs(),
+                    item_id,
+                    item_name,
+                })
+            }
+            _ => {
+                let record = TransactionRecord {
+                    id: raw.id,
+                    created: raw.created,
+                    is_pending: raw.is_pending,
+                    agent_id: raw.agent.as_ref().map(|agent| agent.id),
+                    agent_name: raw.agent.map(|agent| agent.name),
+                    robux: raw.currency.amount,
+                    description: raw.details.and_then(|details| details.name),
+                };
+
+                match transaction_type {
+                    TransactionType::DevEx => Self::DevEx(record),
+                    TransactionType::GroupPayout => Self::GroupPayout(record),
+                    TransactionType::AdImpressionPayout => Self::AdImpressionPayout(record),
+                    TransactionType::CurrencyPurchase => Self::CurrencyPurchase(record),
+                    TransactionType::TradeRobux => Self::TradeRobux(record),
+                    TransactionType::PremiumStipend => Self::PremiumStipend(record),
+                    TransactionType::EngagementPayout => Self::EngagementPayout(record),
+                    _ => Self::GroupEngagementPayout(record),
+                }
+            }
+        };
+
+        Ok(transaction)
+    }
+}
+
+impl TryFrom<request_types::TransactionRaw> for UserSale {
+    type Error = RoboatError;
+
+    fn try_from(raw: request_types::TransactionRaw) -> Result<Self, Self::Error> {
+        let agent = raw.agent.ok_or(RoboatError::MalformedResponse)?;
+        let details = raw.details.ok_or(RoboatError::MalformedResponse)?;
+
+        Ok(UserSale {
+            sale_id: raw.id,
+            is_pending: raw.is_pending,
+            user_id: UserId(agent.id),
+            user_display_name: agent.name,
+            robux_received: raw.currency.amount.unsigned_abs(),
+            asset_id: AssetId(details.id.ok_or(RoboatError::MalformedResponse)?),
+            asset_name: details.name.unwrap_or_default(),
+        })
+    }
+}
+
+impl TryFrom<request_types::TransactionRaw> for CommissionSale {
+    type Error = RoboatError;
+
+    fn try_from(raw: request_types::TransactionRaw) -> Result<Self, Self::Error> {
+        let agent = raw.agent.ok_or(RoboatError::MalformedResponse)?;
+        let details = raw.details.ok_or(RoboatError::MalformedResponse)?;
+
+        let (universe_id, place_id, place_name) = match details.place {
+            Some(place) => (
+                Some(UniverseId(place.universe_id)),
+                Some(PlaceId(place.place_id)),
+                Some(place.name),
+            ),
+            None => (None, None, None),
+        };
+
+        Ok(CommissionSale {
+            sale_id: raw.id,
+            is_pending: raw.is_pending,
+            user_id: UserId(agent.id),
+            user_display_name: agent.name,
+            robux_received: raw.currency.amount.unsigned_abs(),
+            asset_id: AssetId(details.id.ok_or(RoboatError::MalformedResponse)?),
+            asset_name: details.name.unwrap_or_default(),
+            universe_id,
+            place_id,
+            place_name,
+        })
+    }
+}
+
+/// What was bought in a successful purchase. Returned by [`Client::purchase_limited`] and
+/// [`Client::purchase_limited_for_group`] so that ledgers can record what actually happened.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct PurchaseReceipt {
+    /// The product id that was purchased.
+    pub product_id: ProductId,
+    /// The asset id of the purchased item. Does not exist if Roblox did not include it.
+    pub asset_id: Option<AssetId>,
+    /// The uaid of the purchased copy. A copy keeps its uaid when it is resold, so this is
+    /// the uaid of the listing that was bought.
+    pub uaid: Uaid,
+    /// The price paid in robux. This is the price Roblox reports, or the expected price if
+    /// Roblox did not include one.
+    pub price: u64,
+    /// The id of the reseller. This is a group id if the reseller was a group.
+    pub seller_id: u64,
+    /// When Roblox confirmed the purchase, according to the local clock.
+    pub purchased_at: DateTime<Utc>,
+    /// Whether the copy was found in the inventory of the current account after the purchase.
+    /// Only checked if enabled with [`ClientBuilder::verify_purchases`](crate::ClientBuilder::verify_purchases)
+    /// and the purchase was not made for a group; otherwise (or if the check failed) this is `None`.
+    pub in_inventory: Option<bool>,
+}
+
+/// What was bought in a successful purchase of a non-limited product. Returned by
+/// [`Client::purchase_product`].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct ProductReceipt {
+    /// The product id that was purchased.
+    pub product_id: ProductId,
+    /// The asset id of the purchased item. Does not exist if Roblox did not include it
+    /// (such as for developer products).
+    pub asset_id: Option<AssetId>,
+    /// The price paid in robux. This is the price Roblox reports, or the expected price if
+    /// Roblox did not include one.
+    pub price: u64,
+    /// The id of the seller (the creator of the product). This is a group id if the product
+    /// is owned by a group.
+    pub seller_id: u64,
+    /// When Roblox confirmed the purchase, according to the local clock.
+    pub purchased_at: DateTime<Utc>,
+}
+
+/// The arguments of [`Client::purchase_limited`], bundled together so that a purchase can
+/// be attempted more than once. Used by [`Client::purchase_limited_with_retries`].
+#[derive(
+    Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize, Copy,
+)]
+pub struct PurchaseArgs {
+    /// The product id of the limited, NOT the item id.
+    pub product_id: ProductId,
+    /// The id of the reseller.
+    pub seller_id: u64,
+    /// The uaid of the listing.
+    pub uaid: Uaid,
+    /// The price of the listing.
+    pub price: u64,
+}
+
+/// A single failed attempt made by [`Client::purchase_limited_with_retries`].
+#[derive(Debug)]
+pub struct PurchaseAttempt {
+    /// When the attempt was made.
+    pub attempted_at: DateTime<Utc>,
+    /// Why the attempt failed.
+    pub error: RoboatError,
+}
+
+/// The outcome of [`Client::purchase_limited_with_retries`].
+#[derive(Debug)]
+pub struct RetriedPurchase {
+    /// The receipt of the purchase, or the error of the last attempt.
+    pub result: Result<PurchaseReceipt, RoboatError>,
+    /// Every attempt that failed, oldest first. The last attempt is not included if it
+    /// succeeded or if its error is in [`RetriedPurchase::result`].
+    pub failed_attempts: Vec<PurchaseAttempt>,
+}
+
+/// The ids of a limited item needed to buy it and list it again. Used by [`Client::buy_and_relist`].
+#[derive(
+    Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize, Copy,
+)]
+pub struct ProductInfo {
+    /// The asset id of the item, used to find listings and to list the purchased copy.
+    pub asset_id: AssetId,
+    /// The product id of the item, used to purchase it.
+    pub product_id: ProductId,
+}
+
+/// The cheapest way to buy an item, along with everything needed to buy it. Returned by [`Client::best_price`].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, Copy)]
+pub enum BestPrice {
+    /// The item is cheapest (or only available) from the primary sale.
+    Primary {
+        /// The product id of the item.
+        product_id: ProductId,
+        /// The id of the creator, which is the expected seller of a primary sale. This is
+        /// a group id if the item was created by a group.
+        seller_id: u64,
+        /// The price of the primary sale.
+        price: u64,
+    },
+    /// The item is cheapest from a reseller. The arguments can be given straight to
+    /// [`Client::purchase_limited_with_retries`].
+    Resale(PurchaseArgs),
+}
+
+impl BestPrice {
+    /// Returns the price of the cheapest channel.
+    pub fn price(&self) -> u64 {
+        match self {
+            Self::Primary { price, .. } => *price,
+            Self::Resale(args) => args.price,
+        }
+    }
+}
+
+/// The outcome of [`Client::buy_and_relist`]. The purchase always went through, but the relist may not have.
+#[derive(Debug)]
+pub struct RelistOutcome {
+    /// The receipt of the purchase.
+    pub receipt: PurchaseReceipt,
+    /// The uaid that was listed, or the reason it could not be listed.
+    pub result: Result<Uaid, RoboatError>,
+}
+
+/// A summary of the robux of the current account, split into available and pending robux.
+/// Created by [`Client::pending_robux`].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct RobuxSummary {
+    /// The robux the user can currently spend. This is the same value returned by [`Client::robux`].
+    pub available: u64,
+    /// The robux from sales that are still pending.
+    pub pending: u64,
+    /// The sum of the available and pending robux.
+    pub total: u64,
+}
+
+/// The robux of a group, split into available and pending robux. Created by [`Client::group_funds`].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct GroupFunds {
+    /// The robux the group can currently spend or pay out.
+    pub available: u64,
+    /// The robux from sales that are still on hold. These cannot be paid out until they
+    /// become available.
+    pub pending: u64,
+    /// The sum of the available and pending robux.
+    pub total: u64,
+}
+
+/// The time frame of a [`RevenueSummary`], used by [`Client::group_revenue_summary`].
+#[derive(
+    Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize, Copy,
+)]
+pub enum RevenueTimeFrame {
+    /// The past day.
+    #[default]
+    Day,
+    /// The past week.
+    Week,
+    /// The past month.
+    Month,
+    /// The past year.
+    Year,
+}
+
+impl RevenueTimeFrame {
+    /// Returns the name of the time frame as used in the revenue summary url.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Day => "day",
+            Self::Week => "week",
+            Self::Month => "month",
+            Self::Year => "year",
+        }
+    }
+}
+
+/// The revenue of a group over a [`RevenueTimeFrame`], split by source. Retrieved from
+/// <https://economy.roblox.com/v1/groups/{group_id}/revenue/summary/{time_frame}>.
+///
+/// Robux that left the group (such as payouts) are negative.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct RevenueSummary {
+    /// Robux from sales of items owned by the group.
+    pub item_sale_robux: i64,
+    /// Robux from commission (affiliate) sales in experiences owned by the group.
+    pub commission_robux: i64,
+    /// Robux from premium members playing experiences owned by the group.
+    pub premium_payouts: i64,
+    /// Robux from premium payouts given to the group.
+    pub group_premium_payouts: i64,
+    /// Robux given to the group by individual users.
+    pub individual_to_group_robux: i64,
+    /// Robux paid out from the group to its members.
+    pub group_payout_robux: i64,
+    /// Robux from sales that are still on hold. This is the same for every time frame.
+    pub pending_robux: u64,
+}
+
+/// The remaining stock of a Limited U item that is still selling out. Yielded by
+/// [`Client::watch_remaining`] every time the remaining count changes.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct RemainingStock {
+    /// How many copies are left to buy from the primary sale.
+    pub remaining: u64,
+    /// How many copies sold since the previous count. This is 0 for the first count.
+    pub sold: u64,
+    /// When the remaining count was polled.
+    pub timestamp: DateTime<Utc>,
+}
+
+/// The state kept between polls by [`Client::watch_remaining`].
+struct RemainingWatcher<'a> {
+    client: &'a Client,
+    asset_id: AssetId,
+    interval: PollInterval,
+    /// Whether the first poll has happened. The first poll happens immediately.
+    polled: bool,
+    /// The remaining count from the latest successful poll. `None` until the first poll has finished.
+    last_remaining: Option<u64>,
+}
+
+impl WatcherState for RemainingWatcher<'_> {
+    const NAME: &'static str = "watch_remaining";
+
+    fn snapshot(&self) -> serde_json::Value {
+        serde_json::json!({
+            "asset_id": self.asset_id,
+            "last_remaining": self.last_remaining,
+        })
+    }
+}
+
+/// A single day of a limited item's price or sales volume history.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct ResaleDataPoint {
+    /// The day the data point is for.
+    pub date: DateTime<Utc>,
+    /// The average sale price (for price data points), or the amount of sales (for volume data points).
+    pub value: u64,
+}
+
+/// The resale statistics and history of a limited item. Retrieved from
+/// <https://economy.roblox.com/v1/assets/{item_id}/resale-data>.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct ResaleData {
+    /// The amount of copies that were sold originally. Only exists for Limited U items.
+    pub asset_stock: Option<u64>,
+    /// The total amount of resales of the item.
+    pub sales: u64,
+    /// The amount of copies still for sale from Roblox. Only exists for Limited U items.
+    pub number_remaining: Option<u64>,
+    /// The recent average price (RAP) of the item.
+    pub recent_average_price: u64,
+    /// The price the item originally sold for.
+    pub original_price: Option<u64>,
+    /// The average sale price of each day with sales, newest first.
+    pub price_data_points: Vec<ResaleDataPoint>,
+    /// The amount of sales of each day with sales, newest first.
+    pub volume_data_points: Vec<ResaleDataPoint>,
+}
+
+/// How quickly a limited item is selling and how its price is moving, over a window of time.
+/// Created by [`Client::sales_velocity`] or [`ResaleData::sales_velocity`].
+#[derive(Clone, Debug, PartialEq, PartialOrd, Default, Serialize, Deserialize)]
+pub struct SalesVelocity {
+    /// The amount of sales within the window.
+    pub total_sales: u64,
+    /// The average amount of sales per day within the window. This can be used as
+    /// [`ItemStats::sales_per_day`](crate::pricing::ItemStats::sales_per_day).
+    pub average_daily_sales: f64,
+    /// The average sale price within the window, weighted by the amount of sales of each day.
+    /// Does not exist if there were no sales within the window.
+    pub average_price: Option<u64>,
+    /// The percent change between the average price of the older half of the window and
+    /// the newer half (e.g. `12.5` means the price went up by 12.5%).
+    /// Does not exist if either half had no sales.
+    pub price_trend: Option<f64>,
+}
+
+/// The name and description of an asset at the time it was fetched. Retrieved from
+/// <https://economy.roblox.com/v2/assets/{asset_id}/details> using [`Client::asset_version_metadata`].
+///
+/// Roblox only exposes the current name and description of an asset, so edits are found by
+/// keeping an older snapshot around and comparing it with [`AssetVersionMetadata::changes_since`].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct AssetVersionMetadata {
+    /// The id of the asset.
+    pub asset_id: AssetId,
+    /// The current name of the asset.
+    pub name: String,
+    /// The current description of the asset.
+    pub description: String,
+    /// When the asset was created.
+    pub created: DateTime<Utc>,
+    /// When the asset (including its name or description) was last changed.
+    pub updated: DateTime<Utc>,
+}
+
+/// A change to the name or description of an asset between two [`AssetVersionMetadata`] snapshots.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum AssetMetadataChange {
+    /// The name of the asset changed.
+    Name {
+        /// The name in the older snapshot.
+        old: String,
+        /// The name in the newer snapshot.
+        new: String,
+    },
+    /// The description of the asset changed.
+    Description {
+        /// The description in the older snapshot.
+        old: String,
+        /// The description in the newer snapshot.
+        new: String,
+    },
+}
+
+impl AssetVersionMetadata {
+    /// Returns the changes to the name and description since `previous` (an older snapshot
+    /// of the same asset). Returns an empty vector if nothing changed.
+    ///
+    /// # Example
+    /// ```
+    /// use roboat::economy::{AssetMetadataChange, AssetVersionMetadata};
+    ///
+    /// let previous = AssetVersionMetadata {
+    ///     name: "Valkyrie Helm".to_string(),
+    ///     description: "A helm.".to_string(),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let current = AssetVersionMetadata {
+    ///     name: "Valkyrie Helm".to_string(),
+    ///     description: "A winged helm.".to_string(),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(
+    ///     current.changes_since(&previous),
+    ///     vec![AssetMetadataChange::Description {
+    ///         old: "A helm.".to_string(),
+    ///         new: "A winged helm.".to_string(),
+    ///     }]
+    /// );
+    /// ```
+    pub fn changes_since(&self, previous: &AssetVersionMetadata) -> Vec<AssetMetadataChange> {
+        let mut changes = Vec::new();
+
+        if self.name != previous.name {
+            changes.push(AssetMetadataChange::Name {
+                old: previous.name.clone(),
+                new: self.name.clone(),
+            });
+        }
+
+        if self.description != previous.description {
+            changes.push(AssetMetadataChange::Description {
+                old: previous.description.clone(),
+                new: self.description.clone(),
+            });
+        }
+
+        changes
+    }
+}
+
+impl ResaleData {
+    /// Calculates the [`SalesVelocity`] of the item over the `window` of time ending at `now`.
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use roboat::economy::{ResaleData, ResaleDataPoint};
+    /// use std::time::Duration;
+    ///
+    /// let day = |d| Utc.with_ymd_and_hms(2023, 6, d, 0, 0, 0).unwrap();
+    /// let point = |d, value| ResaleDataPoint { date: day(d), value };
+    ///
+    /// let resale_data = ResaleData {
+    ///     price_data_points: vec![point(4, 1200), point(2, 1000)],
+    ///     volume_data_points: vec![point(4, 3), point(2, 1)],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let velocity = resale_data.sales_velocity(Duration::from_secs(4 * 86400), day(5));
+    ///
+    /// assert_eq!(velocity.total_sales, 4);
+    /// assert_eq!(velocity.average_daily_sales, 1.0);
+    /// assert_eq!(velocity.average_price, Some(1150));
+    /// assert_eq!(velocity.price_trend, Some(20.0));
+    /// ```
+    pub fn sales_velocity(&self, window: Duration, now: DateTime<Utc>) -> SalesVelocity {
+        // A window reaching past the earliest representable date covers everything.
+        let window_start = chrono::Duration::from_std(window)
+            .ok()
+            .and_then(|window| now.checked_sub_signed(window))
+            .unwrap_or(DateTime::<Utc>::MIN_UTC);
+        let window_middle = window_start + (now - window_start) / 2;
+
+        // (sales, sales * price) for the older and newer half of the window.
+        let mut older: (u64, u64) = (0, 0);
+        let mut newer: (u64, u64) = (0, 0);
+
+        for volume_point in &self.volume_data_points {
+            if volume_point.date < window_start || volume_point.date > now {
+                continue;
+            }
+
+            let price = self
+                .price_data_points
+                .iter()
+                .find(|price_point| price_point.date == volume_point.date)
+                .map(|price_point| price_point.value);
+
+            let half = match volume_point.date < window_middle {
+                true => &mut older,
+                false => &mut newer,
+            };
+
+            // Saturates instead of overflowing on absurd values from Roblox.
+            half.0 = half.0.saturating_add(volume_point.value);
+
+            if let Some(price) = price {
+                half.1 = half
+                    .1
+                    .saturating_add(volume_point.value.saturating_mul(price));
+            }
+        }
+
+        let total_sales = older.0.saturating_add(newer.0);
+        let window_days = (window.as_secs_f64() / SECONDS_PER_DAY).max(1.0);
+
+        let average = |(sales, weighted_total): (u64, u64)| match sales {
+            0 => None,
+            _ => Some(weighted_total as f64 / sales as f64),
+        };
+
+        let price_trend = match (average(older), average(newer)) {
+            (Some(older_price), Some(newer_price)) if older_price > 0.0 => {
+                Some((newer_price - older_price) / older_price * 100.0)
+            }
+            _ => None,
+        };
+
+        SalesVelocity {
+            total_sales,
+            average_daily_sales: total_sales as f64 / window_days,
+            average_price: average((total_sales, older.1.saturating_add(newer.1)))
+                .map(|x| x.round() as u64),
+            price_trend,
+        }
+    }
 }
 
 impl Client {
@@ -132,14 +960,12 @@ impl Client {
         let formatted_url = format!("{}{}{}", ROBUX_API_PART_1, user_id, ROBUX_API_PART_2);
         let cookie = self.cookie_string()?;
 
-        let request_result = self
+        let request = self
             .reqwest_client
             .get(formatted_url)
-            .header(header::COOKIE, cookie)
-            .send()
-            .await;
+            .header(header::COOKIE, cookie);
 
-        let response = Self::validate_request_result(request_result).await?;
+        let response = self.send_request(request).await?;
         let raw = Self::parse_to_raw::<request_types::CurrencyResponse>(response).await?;
 
         let robux = raw.robux;
@@ -161,6 +987,7 @@ impl Client {
     ///
     /// # Example
     /// ```no_run
+    /// use roboat::ids::AssetId;
     /// use roboat::Limit;
     /// use roboat::ClientBuilder;
     ///
@@ -170,7 +997,7 @@ impl Client {
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
     ///
-    /// let item_id = 1365767;
+    /// let item_id = AssetId(1365767);
     /// let limit = Limit::Ten;
     /// let cursor = None;
     ///
@@ -181,7 +1008,7 @@ impl Client {
     /// ```
     pub async fn resellers(
         &self,
-        item_id: u64,
+        item_id: AssetId,
         limit: Limit,
         cursor: Option<String>,
     ) -> Result<(Vec<Listing>, Option<String>), RoboatError> {
@@ -194,14 +1021,12 @@ impl Client {
             RESELLERS_API_PART_1, item_id, RESELLERS_API_PART_2, cursor, limit
         );
 
-        let request_result = self
+        let request = self
             .reqwest_client
             .get(formatted_url)
-            .header(header::COOKIE, cookie)
-            .send()
-            .await;
+            .header(header::COOKIE, cookie);
 
-        let response = Self::validate_request_result(request_result).await?;
+        let response = self.send_request(request).await?;
         let raw = Self::parse_to_raw::<request_types::ResellersResponse>(response).await?;
 
         let next_page_cursor = raw.next_page_cursor;
@@ -209,13 +1034,19 @@ impl Client {
         let mut listings = Vec::new();
 
         for listing in raw.data {
+            let seller_type = match listing.seller.seller_type.as_deref() {
+                Some("Group") => SellerType::Group,
+                _ => SellerType::User,
+            };
+
             let reseller = Reseller {
                 user_id: listing.seller.id,
                 name: listing.seller.name,
+                seller_type,
             };
 
             let listing = Listing {
-                uaid: listing.user_asset_id,
+                uaid: Uaid(listing.user_asset_id),
                 price: listing.price,
                 reseller,
                 serial_number: listing.serial_number,
@@ -227,21 +1058,15 @@ impl Client {
         Ok((listings, next_page_cursor))
     }
 
-    /// Grabs user sales from <https://economy.roblox.com/v2/users/{user_id}/transactions?transactionType=Sale&cursor={cursor}&limit={limit}>.
+    /// Grabs the resale statistics and price/volume history of a limited item from
+    /// <https://economy.roblox.com/v1/assets/{item_id}/resale-data>.
     ///
     /// # Notes
     /// * Requires a valid roblosecurity.
     ///
-    /// # Argument Notes
-    /// * The cursor is used to get the a certain page of results. If you want the starting page, use `None`.
-    ///
-    /// # Return Value Notes
-    /// * The first value is a vector of user sales.
-    /// * The second value is the cursor for the next page of results. If there are no more pages, this will be `None`.
-    ///
     /// # Example
     /// ```no_run
-    /// use roboat::Limit;
+    /// use roboat::ids::AssetId;
     /// use roboat::ClientBuilder;
     ///
     /// const ROBLOSECURITY: &str = "roblosecurity";
@@ -250,94 +1075,64 @@ impl Client {
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
     ///
-    /// let limit = Limit::Ten;
-    /// let cursor = None;
-    ///
-    /// let (user_sales, next_page_cursor) = client.user_sales(limit, cursor).await?;
-    ///
-    /// let sale_amount = user_sales.len();
-    /// let total_robux_earned = user_sales
-    ///     .iter()
-    ///     .map(|sale| sale.robux_received)
-    ///     .sum::<u64>();
+    /// let item_id = AssetId(1365767);
+    /// let resale_data = client.resale_data(item_id).await?;
     ///
-    /// println!("Robux gained from last {} sales: {}", sale_amount, total_robux_earned);
+    /// println!("RAP: {}", resale_data.recent_average_price);
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn user_sales(
-        &self,
-        limit: Limit,
-        cursor: Option<String>,
-    ) -> Result<(Vec<UserSale>, Option<String>), RoboatError> {
-        let limit = limit.to_u64();
-        let cursor = cursor.unwrap_or_default();
-
-        let user_id = self.user_id().await?;
-
+    pub async fn resale_data(&self, item_id: AssetId) -> Result<ResaleData, RoboatError> {
         let formatted_url = format!(
-            "{}{}{}?cursor={}&limit={}&transactionType={}",
-            TRANSACTIONS_API_PART_1,
-            user_id,
-            TRANSACTIONS_API_PART_2,
-            cursor,
-            limit,
-            USER_SALES_TRANSACTION_TYPE
+            "{}{}{}",
+            RESALE_DATA_API_PART_1, item_id, RESALE_DATA_API_PART_2
         );
 
-        let cookie = self.cookie_string()?;
+        let cookie_string = self.cookie_string()?;
 
-        let request_result = self
+        let request = self
             .reqwest_client
             .get(formatted_url)
-            .header(header::COOKIE, cookie)
-            .send()
-            .await;
-
-        let response = Self::validate_request_result(request_result).await?;
-        let raw = Self::parse_to_raw::<request_types::UserSalesResponse>(response).await?;
-
-        let next_page_cursor = raw.next_page_cursor;
-
-        let mut sales = Vec::new();
-
-        for raw_sale in raw.data {
-            let sale_id = raw_sale.id;
-            let asset_id = raw_sale.details.id;
-            let robux_received = raw_sale.currency.amount;
-            let is_pending = raw_sale.is_pending;
-            let user_id = raw_sale.agent.id;
-            let user_display_name = raw_sale.agent.name;
-            let asset_name = raw_sale.details.name;
-
-            let sale = UserSale {
-                sale_id,
-                asset_id,
-                robux_received,
-                is_pending,
-                user_id,
-                user_display_name,
-                asset_name,
-            };
-
-            sales.push(sale);
-        }
-
-        Ok((sales, next_page_cursor))
+            .header(header::COOKIE, cookie_string);
+
+        let response = self.send_request(request).await?;
+        let raw = Self::parse_to_raw::<request_types::ResaleDataResponse>(response).await?;
+
+        let to_data_points = |raw_points: Vec<request_types::DataPointRaw>| {
+            raw_points
+                .into_iter()
+                .map(|point| ResaleDataPoint {
+                    date: point.date,
+                    value: point.value,
+                })
+                .collect()
+        };
+
+        let resale_data = ResaleData {
+            asset_stock: raw.asset_stock,
+            sales: raw.sales,
+            number_remaining: raw.number_remaining,
+            recent_average_price: raw.recent_average_price,
+            original_price: raw.original_price,
+            price_data_points: to_data_points(raw.price_data_points),
+            volume_data_points: to_data_points(raw.volume_data_points),
+        };
+
+        Ok(resale_data)
     }
 
-    /// Puts a limited item on sale using the endpoint <https://economy.roblox.com/v1/assets/{item_id}/resellable-copies/{uaid}>.
+    /// Calculates how quickly a limited item is selling, and how its price is moving, over the
+    /// last `window` of time. Uses [`Client::resale_data`] and [`ResaleData::sales_velocity`].
     ///
     /// # Notes
     /// * Requires a valid roblosecurity.
-    /// * Will repeat once if the x-csrf-token is invalid.
-    ///
-    /// # Return Value Notes
-    /// * Will return `Ok(())` if the item was successfully put on sale.
+    /// * Roblox only keeps about 180 days of resale history, so longer windows are not useful.
     ///
     /// # Example
     /// ```no_run
+    /// use roboat::ids::AssetId;
     /// use roboat::ClientBuilder;
+    /// use std::time::Duration;
     ///
     /// const ROBLOSECURITY: &str = "roblosecurity";
     ///
@@ -345,25 +1140,1149 @@ impl Client {
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
     ///
-    /// let item_id = 123456789;
-    /// let uaid = 987654321;
-    /// let price = 5000;
+    /// let item_id = AssetId(1365767);
+    /// let window = Duration::from_secs(30 * 86400);
+    /// let velocity = client.sales_velocity(item_id, window).await?;
     ///
-    /// match client.put_limited_on_sale(item_id, uaid, price).await {
-    ///    Ok(_) => println!("Successfully put item on sale!"),
-    ///    Err(e) => println!("Error: {}", e),
-    /// }
+    /// println!("Sales per day: {:.2}", velocity.average_daily_sales);
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn put_limited_on_sale(
+    pub async fn sales_velocity(
         &self,
-        item_id: u64,
-        uaid: u64,
-        price: u64,
-    ) -> Result<(), RoboatError> {
+        item_id: AssetId,
+        window: Duration,
+    ) -> Result<SalesVelocity, RoboatError> {
+        let resale_data = self.resale_data(item_id).await?;
+        Ok(resale_data.sales_velocity(window, Utc::now()))
+    }
+
+    /// Grabs the current name and description of an asset from
+    /// <https://economy.roblox.com/v2/assets/{asset_id}/details>.
+    ///
+    /// Roblox does not keep a public history of names and descriptions, so to track edits,
+    /// call this periodically and compare the results using [`AssetVersionMetadata::changes_since`].
+    /// [`AssetVersionMetadata::updated`] can be checked first to skip comparing unchanged assets.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ids::AssetId;
+    /// use roboat::ClientBuilder;
+    /// use std::time::Duration;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let asset_id = AssetId(1365767);
+    /// let mut previous = client.asset_version_metadata(asset_id).await?;
+    ///
+    /// loop {
+    ///     tokio::time::sleep(Duration::from_secs(300)).await;
+    ///
+    ///     let current = client.asset_version_metadata(asset_id).await?;
+    ///
+    ///     for change in current.changes_since(&previous) {
+    ///         println!("{:?}", change);
+    ///     }
+    ///
+    ///     previous = current;
+    /// }
+    /// # }
+    /// ```
+    pub async fn asset_version_metadata(
+        &self,
+        asset_id: AssetId,
+    ) -> Result<AssetVersionMetadata, RoboatError> {
+        let raw = self.asset_details(asset_id).await?;
+
+        Ok(AssetVersionMetadata {
+            asset_id: AssetId(raw.asset_id),
+            name: raw.name,
+            description: raw.description.unwrap_or_default(),
+            created: raw.created,
+            updated: raw.updated,
+        })
+    }
+
+    /// Polls the remaining stock of a Limited U item every `interval` using
+    /// <https://economy.roblox.com/v2/assets/{asset_id}/details>, and yields the count
+    /// every time it changes as a stream. This is meant for timing purchases near sell-out.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    /// * The first poll happens immediately and always yields the current count.
+    /// * The stream ends after the item sells out (a remaining count of 0 is yielded first).
+    ///   Items that are not Limited U items count as sold out.
+    ///
+    /// # Argument Notes
+    /// * `interval` can be a [`Duration`] for a fixed interval, or an
+    ///   [`AdaptivePoller`](crate::polling::AdaptivePoller) to back off when rate limited or
+    ///   when the remaining count does not change.
+    ///
+    /// # Return Value Notes
+    /// * A failed poll yields the error, and the next poll happens as normal.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use futures_util::StreamExt;
+    /// use roboat::ids::AssetId;
+    /// use roboat::ClientBuilder;
+    /// use std::time::Duration;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let asset_id = AssetId(1365767);
+    /// let mut stock = client.watch_remaining(asset_id, Duration::from_secs(5));
+    ///
+    /// while let Some(stock) = stock.next().await {
+    ///     let stock = stock?;
+    ///     println!("{} left ({} sold)", stock.remaining, stock.sold);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn watch_remaining(
+        &self,
+        asset_id: AssetId,
+        interval: impl Into<PollInterval>,
+    ) -> BoxStream<'_, Result<RemainingStock, RoboatError>> {
+        let watcher = RemainingWatcher {
+            client: self,
+            asset_id,
+            interval: interval.into(),
+            polled: false,
+            last_remaining: None,
+        };
+
+        guarded_watcher(self, watcher, |mut watcher| async move {
+            loop {
+                // The last count yielded was 0, so the item sold out.
+                if watcher.last_remaining == Some(0) {
+                    return None;
+                }
+
+                if watcher.polled {
+                    tokio::time::sleep(watcher.interval.next_interval(Utc::now())).await;
+                }
+
+                watcher.polled = true;
+
+                let remaining = match watcher.client.asset_details(watcher.asset_id).await {
+                    Ok(raw) => raw.remaining.unwrap_or_default(),
+                    Err(e) => {
+                        let outcome = match e {
+                            RoboatError::TooManyRequests => PollOutcome::RateLimited,
+                            _ => PollOutcome::Failed,
+                        };
+
+                        watcher.interval.record(outcome);
+                        return Some((Err(e), watcher));
+                    }
+                };
+
+                let previous_remaining = watcher.last_remaining;
+                watcher.last_remaining = Some(remaining);
+
+                if previous_remaining == Some(remaining) {
+                    watcher.interval.record(PollOutcome::Idle);
+                    continue;
+                }
+
+                watcher.interval.record(PollOutcome::Activity);
+
+                let stock = RemainingStock {
+                    remaining,
+                    sold: previous_remaining
+                        .map(|previous_remaining| previous_remaining.saturating_sub(remaining))
+                        .unwrap_or_default(),
+                    timestamp: Utc::now(),
+                };
+
+                return Some((Ok(stock), watcher));
+            }
+        })
+    }
+
+    /// Grabs user sales from <https://economy.roblox.com/v2/users/{user_id}/transactions?transactionType=Sale&cursor={cursor}&limit={limit}>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Falls back to the v1 endpoint if the v2 endpoint returns a 404 or 410 status code,
+    ///   unless a version is pinned using [`ClientBuilder::pin_api_version`](crate::ClientBuilder::pin_api_version).
+    ///
+    /// # Argument Notes
+    /// * The cursor is used to get the a certain page of results. If you want the starting page, use `None`.
+    ///
+    /// # Return Value Notes
+    /// * The first value is a vector of user sales.
+    /// * The second value is the cursor for the next page of results. If there are no more pages, this will be `None`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::Limit;
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let limit = Limit::Ten;
+    /// let cursor = None;
+    ///
+    /// let (user_sales, next_page_cursor) = client.user_sales(limit, cursor).await?;
+    ///
+    /// let sale_amount = user_sales.len();
+    /// let total_robux_earned = user_sales
+    ///     .iter()
+    ///     .map(|sale| sale.robux_received)
+    ///     .sum::<u64>();
+    ///
+    /// println!("Robux gained from last {} sales: {}", sale_amount, total_robux_earned);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn user_sales(
+        &self,
+        limit: Limit,
+        cursor: Option<String>,
+    ) -> Result<(Vec<UserSale>, Option<String>), RoboatError> {
+        let raw = self
+            .transactions_page(None, TransactionType::Sale, limit, cursor)
+            .await?;
+
+        let sales = raw
+            .data
+            .into_iter()
+            .map(UserSale::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok((sales, raw.next_page_cursor))
+    }
+
+    /// Grabs commission (affiliate) sales from <https://economy.roblox.com/v2/users/{user_id}/transactions?transactionType=AffiliateSale&cursor={cursor}&limit={limit}>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Falls back to the v1 endpoint if the v2 endpoint returns a 404 or 410 status code,
+    ///   unless a version is pinned using [`ClientBuilder::pin_api_version`](crate::ClientBuilder::pin_api_version).
+    ///
+    /// # Argument Notes
+    /// * The cursor is used to get the a certain page of results. If you want the starting page, use `None`.
+    ///
+    /// # Return Value Notes
+    /// * The first value is a vector of commission sales.
+    /// * The second value is the cursor for the next page of results. If there are no more pages, this will be `None`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::Limit;
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let limit = Limit::Ten;
+    /// let cursor = None;
+    ///
+    /// let (commission_sales, next_page_cursor) = client.commission_sales(limit, cursor).await?;
+    ///
+    /// for sale in commission_sales {
+    ///     println!(
+    ///         "Earned {} robux from {} in {:?}",
+    ///         sale.robux_received, sale.asset_name, sale.place_name
+    ///     );
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn commission_sales(
+        &self,
+        limit: Limit,
+        cursor: Option<String>,
+    ) -> Result<(Vec<CommissionSale>, Option<String>), RoboatError> {
+        let raw = self
+            .transactions_page(None, TransactionType::AffiliateSale, limit, cursor)
+            .await?;
+
+        let sales = raw
+            .data
+            .into_iter()
+            .map(CommissionSale::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok((sales, raw.next_page_cursor))
+    }
+
+    /// Grabs transactions of one type from the transaction history of the current account from
+    /// <https://economy.roblox.com/v2/users/{user_id}/transactions?transactionType={transaction_type}&cursor={cursor}&limit={limit}>.
+    ///
+    /// This covers every type of transaction, unlike [`Client::user_sales`] and
+    /// [`Client::commission_sales`] which only cover sales.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Falls back to the v1 endpoint if the v2 endpoint returns a 404 or 410 status code,
+    ///   unless a version is pinned using [`ClientBuilder::pin_api_version`](crate::ClientBuilder::pin_api_version).
+    ///
+    /// # Argument Notes
+    /// * The cursor is used to get the a certain page of results. If you want the starting page, use `None`.
+    ///
+    /// # Return Value Notes
+    /// * The first value is a vector of transactions. Every transaction is the [`Transaction`]
+    ///   variant matching `transaction_type`.
+    /// * The second value is the cursor for the next page of results. If there are no more pages, this will be `None`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::economy::{Transaction, TransactionType};
+    /// use roboat::ClientBuilder;
+    /// use roboat::Limit;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let (transactions, _) = client
+    ///     .transactions(TransactionType::Purchase, Limit::Ten, None)
+    ///     .await?;
+    ///
+    /// for transaction in transactions {
+    ///     if let Transaction::Purchase(purchase) = transaction {
+    ///         println!("Spent {} robux on {:?}", purchase.robux_spent, purchase.item_name);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn transactions(
+        &self,
+        transaction_type: TransactionType,
+        limit: Limit,
+        cursor: Option<String>,
+    ) -> Result<(Vec<Transaction>, Option<String>), RoboatError> {
+        let raw = self
+            .transactions_page(None, transaction_type, limit, cursor)
+            .await?;
+
+        let transactions = raw
+            .data
+            .into_iter()
+            .map(|transaction| Transaction::from_raw(transaction_type, transaction))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok((transactions, raw.next_page_cursor))
+    }
+
+    /// Grabs the robux bought with real money (including redeemed credit) by the current
+    /// account from <https://economy.roblox.com/v2/users/{user_id}/transactions?transactionType=CurrencyPurchase&cursor={cursor}&limit={limit}>.
+    ///
+    /// This is a shortcut for [`Client::transactions`] with [`TransactionType::CurrencyPurchase`].
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Falls back to the v1 endpoint like [`Client::transactions`].
+    ///
+    /// # Argument Notes
+    /// * The cursor is used to get the a certain page of results. If you want the starting page, use `None`.
+    ///
+    /// # Return Value Notes
+    /// * The first value is a vector of purchases. [`TransactionRecord::robux`]
+    ///   is the amount of robux bought.
+    /// * The second value is the cursor for the next page of results. If there are no more pages, this will be `None`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    /// use roboat::Limit;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let (purchases, _) = client.billing_history(Limit::Ten, None).await?;
+    ///
+    /// for purchase in purchases {
+    ///     println!("Bought {} robux at {:?}", purchase.robux, purchase.created);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn billing_history(
+        &self,
+        limit: Limit,
+        cursor: Option<String>,
+    ) -> Result<(Vec<TransactionRecord>, Option<String>), RoboatError> {
+        let (transactions, next_cursor) = self
+            .transactions(TransactionType::CurrencyPurchase, limit, cursor)
+            .await?;
+
+        let purchases = transactions
+            .into_iter()
+            .filter_map(|transaction| match transaction {
+                Transaction::CurrencyPurchase(record) => Some(record),
+                _ => None,
+            })
+            .collect();
+
+        Ok((purchases, next_cursor))
+    }
+
+    /// Grabs transactions of one type from the transaction history of a group from
+    /// <https://economy.roblox.com/v2/groups/{group_id}/transactions?transactionType={transaction_type}&cursor={cursor}&limit={limit}>.
+    ///
+    /// The transactions are parsed into the same [`Transaction`] variants as [`Client::transactions`],
+    /// with the group in place of the current account.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * The current account needs permission to view the group's revenue.
+    /// * Falls back to the v1 endpoint if the v2 endpoint returns a 404 or 410 status code,
+    ///   unless a version is pinned using [`ClientBuilder::pin_api_version`](crate::ClientBuilder::pin_api_version).
+    ///
+    /// # Argument Notes
+    /// * The cursor is used to get the a certain page of results. If you want the starting page, use `None`.
+    ///
+    /// # Return Value Notes
+    /// * The first value is a vector of transactions. Every transaction is the [`Transaction`]
+    ///   variant matching `transaction_type`.
+    /// * The second value is the cursor for the next page of results. If there are no more pages, this will be `None`.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::economy::{Transaction, TransactionType};
+    /// use roboat::ClientBuilder;
+    /// use roboat::Limit;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let group_id = 1200769;
+    /// let (transactions, _) = client
+    ///     .group_transactions(group_id, TransactionType::Sale, Limit::Hundred, None)
+    ///     .await?;
+    ///
+    /// for transaction in transactions {
+    ///     if let Transaction::Sale(sale) = transaction {
+    ///         println!("{} bought {}", sale.user_display_name, sale.asset_name);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn group_transactions(
+        &self,
+        group_id: u64,
+        transaction_type: TransactionType,
+        limit: Limit,
+        cursor: Option<String>,
+    ) -> Result<(Vec<Transaction>, Option<String>), RoboatError> {
+        let raw = self
+            .transactions_page(Some(group_id), transaction_type, limit, cursor)
+            .await?;
+
+        let transactions = raw
+            .data
+            .into_iter()
+            .map(|transaction| Transaction::from_raw(transaction_type, transaction))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok((transactions, raw.next_page_cursor))
+    }
+
+    /// Used by [`Client::transactions`], [`Client::group_transactions`], [`Client::user_sales`],
+    /// and [`Client::commission_sales`], which only differ in how the transactions are parsed.
+    /// The transactions of `group_id` are grabbed if it is given, and the transactions of the
+    /// current account are grabbed otherwise.
+    async fn transactions_page(
+        &self,
+        group_id: Option<u64>,
+        transaction_type: TransactionType,
+        limit: Limit,
+        cursor: Option<String>,
+    ) -> Result<request_types::TransactionsResponse, RoboatError> {
+        let limit = limit.to_u64();
+        let cursor = cursor.unwrap_or_default();
+
+        let (owner_api_part, owner_id) = match group_id {
+            Some(group_id) => (GROUP_TRANSACTIONS_API_PART_2, group_id),
+            None => (TRANSACTIONS_API_PART_2, self.user_id().await?.0),
+        };
+
+        let cookie = self.cookie_string()?;
+
+        let request = |api_version: ApiVersion| {
+            let formatted_url = format!(
+                "{}{}{}{}{}?cursor={}&limit={}&transactionType={}",
+                TRANSACTIONS_API_PART_1,
+                api_version.as_str(),
+                owner_api_part,
+                owner_id,
+                TRANSACTIONS_API_PART_3,
+                cursor,
+                limit,
+                transaction_type.as_str()
+            );
+
+            self.reqwest_client
+                .get(formatted_url)
+                .header(header::COOKIE, cookie.clone())
+        };
+
+        let response = self.send_versioned_request(ApiVersion::V2, request).await?;
+
+        Self::parse_to_raw::<request_types::TransactionsResponse>(response).await
+    }
+
+    /// Grabs the available robux of the current account along with the robux from pending sales.
+    ///
+    /// The available robux come from <https://economy.roblox.com/v1/users/{user_id}/currency>, and
+    /// the pending robux are summed from <https://economy.roblox.com/v2/users/{user_id}/transactions?transactionType=Sale>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Pages through sales (newest first) until a page contains no pending sales, so this
+    ///   may make several requests if the user has a lot of pending sales.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let summary = client.pending_robux().await?;
+    /// println!("Available: {}", summary.available);
+    /// println!("Pending: {}", summary.pending);
+    /// println!("Total: {}", summary.total);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn pending_robux(&self) -> Result<RobuxSummary, RoboatError> {
+        let available = self.robux().await?;
+
+        let mut pending = 0;
+        let mut cursor = None;
+
+        loop {
+            let (sales, next_page_cursor) = self.user_sales(Limit::Hundred, cursor).await?;
+
+            let page_pending = sales
+                .iter()
+                .filter(|sale| sale.is_pending)
+                .map(|sale| sale.robux_received)
+                .collect::<Vec<_>>();
+
+            pending += page_pending.iter().sum::<u64>();
+
+            // Sales are sorted newest first, so once a page has no pending sales there are no more.
+            if page_pending.is_empty() || next_page_cursor.is_none() {
+                break;
+            }
+
+            cursor = next_page_cursor;
+        }
+
+        let summary = RobuxSummary {
+            available,
+            pending,
+            total: available + pending,
+        };
+
+        Ok(summary)
+    }
+
+    /// Grabs the robux of a group, split into available and pending robux, from
+    /// <https://economy.roblox.com/v1/groups/{group_id}/currency> and
+    /// <https://economy.roblox.com/v1/groups/{group_id}/revenue/summary/day>.
+    ///
+    /// Only [`GroupFunds::available`] can be paid out, so payout automation should check it
+    /// instead of the total.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * The current account needs permission to view the group's revenue.
+    /// * This makes two requests, so the values can be slightly out of sync if robux
+    ///   move between them.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let group_id = 1200769;
+    /// let funds = client.group_funds(group_id).await?;
+    ///
+    /// println!("Available: {}, Pending: {}", funds.available, funds.pending);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn group_funds(&self, group_id: u64) -> Result<GroupFunds, RoboatError> {
+        let cookie = self.cookie_string()?;
+
+        let currency_url = format!("{}{}/currency", GROUP_ECONOMY_API, group_id);
+
+        let request = self
+            .reqwest_client
+            .get(currency_url)
+            .header(header::COOKIE, cookie.clone());
+
+        let response = self.send_request(request).await?;
+        let available = Self::parse_to_raw::<request_types::CurrencyResponse>(response)
+            .await?
+            .robux;
+
+        let pending = self
+            .group_revenue_summary(group_id, GROUP_FUNDS_TIME_FRAME)
+            .await?
+            .pending_robux;
+
+        Ok(GroupFunds {
+            available,
+            pending,
+            total: available + pending,
+        })
+    }
+
+    /// Grabs the revenue of a group over a time frame, split by source, from
+    /// <https://economy.roblox.com/v1/groups/{group_id}/revenue/summary/{time_frame}>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * The current account needs permission to view the group's revenue.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::economy::RevenueTimeFrame;
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let group_id = 1200769;
+    /// let summary = client
+    ///     .group_revenue_summary(group_id, RevenueTimeFrame::Month)
+    ///     .await?;
+    ///
+    /// println!("Sales this month: {}", summary.item_sale_robux);
+    /// println!("Payouts this month: {}", summary.group_payout_robux);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn group_revenue_summary(
+        &self,
+        group_id: u64,
+        time_frame: RevenueTimeFrame,
+    ) -> Result<RevenueSummary, RoboatError> {
+        let formatted_url = format!(
+            "{}{}/revenue/summary/{}",
+            GROUP_ECONOMY_API,
+            group_id,
+            time_frame.as_str()
+        );
+
+        let cookie = self.cookie_string()?;
+
+        let request = self
+            .reqwest_client
+            .get(formatted_url)
+            .header(header::COOKIE, cookie);
+
+        let response = self.send_request(request).await?;
+        let raw =
+            Self::parse_to_raw::<request_types::GroupRevenueSummaryResponse>(response).await?;
+
+        Ok(RevenueSummary {
+            item_sale_robux: raw.item_sale_robux,
+            commission_robux: raw.commission_robux,
+            premium_payouts: raw.premium_payouts,
+            group_premium_payouts: raw.group_premium_payouts,
+            individual_to_group_robux: raw.individual_to_group_robux,
+            group_payout_robux: raw.group_payout_robux,
+            pending_robux: raw.pending_robux,
+        })
+    }
+
+    /// Puts a limited item on sale using the endpoint <https://economy.roblox.com/v1/assets/{item_id}/resellable-copies/{uaid}>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Only works on copies held by the current account. This endpoint does not accept a
+    ///   group as the seller, so group-held copies must be managed from the group holder account.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    ///
+    /// # Return Value Notes
+    /// * Will return `Ok(())` if the item was successfully put on sale.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    /// * [`RoboatError::BelowPriceFloor`] - If Roblox rejects the listing and the price is below
+    ///   the price floor of the item's asset type. See [`Client::validate_listing_price`].
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ids::{AssetId, Uaid};
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let item_id = AssetId(123456789);
+    /// let uaid = Uaid(987654321);
+    /// let price = 5000;
+    ///
+    /// match client.put_limited_on_sale(item_id, uaid, price).await {
+    ///    Ok(_) => println!("Successfully put item on sale!"),
+    ///    Err(e) => println!("Error: {}", e),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn put_limited_on_sale(
+        &self,
+        item_id: AssetId,
+        uaid: Uaid,
+        price: u64,
+    ) -> Result<(), RoboatError> {
+        let result = match self
+            .put_limited_on_sale_internal(item_id, uaid, price)
+            .await
+        {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.put_limited_on_sale_internal(item_id, uaid, price)
+                        .await
+                }
+                _ => Err(e),
+            },
+        };
+
+        // Roblox only returns a generic 400 for prices below the price floor, so
+        // we only look up the price floor once we know the listing was rejected.
+        match result {
+            Err(RoboatError::BadRequest) => {
+                match self.validate_listing_price(item_id, price).await {
+                    Err(e @ RoboatError::BelowPriceFloor { .. }) => Err(e),
+                    _ => Err(RoboatError::BadRequest),
+                }
+            }
+            _ => result,
+        }
+    }
+
+    /// Checks whether a limited item can be listed at `price`, by comparing it against the
+    /// price floor of the item's asset type from [`Client::clothing_price_floors`].
+    ///
+    /// Use this before [`Client::put_limited_on_sale`] to avoid a rejected listing.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Items whose asset type has no price floor always pass.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * [`RoboatError::BelowPriceFloor`] - If `price` is below the price floor.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ids::AssetId;
+    /// use roboat::{ClientBuilder, RoboatError};
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let item_id = AssetId(123456789);
+    ///
+    /// match client.validate_listing_price(item_id, 5).await {
+    ///     Ok(()) => println!("The price is allowed."),
+    ///     Err(RoboatError::BelowPriceFloor { floor }) => println!("Must be at least {}.", floor),
+    ///     Err(e) => return Err(e.into()),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn validate_listing_price(
+        &self,
+        item_id: AssetId,
+        price: u64,
+    ) -> Result<(), RoboatError> {
+        let item_args = ItemArgs {
+            item_type: ItemType::Asset,
+            id: item_id.0,
+        };
+
+        let asset_type = match self.item_details(vec![item_args]).await?.pop() {
+            Some(item_details) => match item_details.asset_type {
+                Some(asset_type) => asset_type,
+                // Bundles and other items without an asset type have no price floor.
+                None => return Ok(()),
+            },
+            None => return Err(RoboatError::MalformedResponse),
+        };
+
+        let floor = self
+            .clothing_price_floors()
+            .await?
+            .into_iter()
+            .find(|price_floor| price_floor.asset_type == asset_type)
+            .map(|price_floor| price_floor.price_floor);
+
+        match floor {
+            Some(floor) if price < floor => Err(RoboatError::BelowPriceFloor { floor }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Takes a limited item off sale using the endpoint <https://economy.roblox.com/v1/assets/{item_id}/resellable-copies/{uaid}>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Only works on copies held by the current account. This endpoint does not accept a
+    ///   group as the seller, so group-held copies must be managed from the group holder account.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    ///
+    /// # Return Value Notes
+    /// * Will return `Ok(())` if the item was successfully taken off sale.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ids::{AssetId, Uaid};
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let item_id = AssetId(123456789);
+    /// let uaid = Uaid(987654321);
+    ///
+    /// match client.take_limited_off_sale(item_id, uaid).await {
+    ///    Ok(_) => println!("Successfully took item off sale!"),
+    ///    Err(e) => println!("Error: {}", e),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn take_limited_off_sale(
+        &self,
+        item_id: AssetId,
+        uaid: Uaid,
+    ) -> Result<(), RoboatError> {
+        match self.take_limited_off_sale_internal(item_id, uaid).await {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.take_limited_off_sale_internal(item_id, uaid).await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Purchases a limited (including limited u) using  <https://economy.roblox.com/v1/purchases/products/{product_id}>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    /// * The repeat costs time when sniping. A token can be fetched ahead of time (or shared
+    ///   by another client) and set using [`Client::set_xcsrf_manual`], and the token in use
+    ///   can be read using [`Client::current_xcsrf`].
+    ///
+    /// # Return Value Notes
+    /// * Will return a [`PurchaseReceipt`] if the limited was successfully purchased.
+    ///
+    /// # Argument Notes
+    /// * `product_id` if the product id of the limited, NOT the item id.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    /// * [`RoboatError::PurchaseLimitedError`] - Nested inside this error, all variants of [`PurchaseLimitedError`] may be thrown.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ids::{ProductId, Uaid};
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let product_id = ProductId(12345679);
+    /// let seller_id = 5656565656;
+    /// let uaid = Uaid(987654321);
+    /// let price = 5000;
+    ///
+    /// let receipt = client.purchase_limited(product_id, seller_id, uaid, price).await?;
+    /// println!("Successfully Purchased for {} robux!", receipt.price);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn purchase_limited(
+        &self,
+        product_id: ProductId,
+        seller_id: u64,
+        uaid: Uaid,
+        price: u64,
+    ) -> Result<PurchaseReceipt, RoboatError> {
+        let mut receipt = match self
+            .purchase_limited_internal(product_id, price, seller_id, uaid, None)
+            .await
+        {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.purchase_limited_internal(product_id, price, seller_id, uaid, None)
+                        .await
+                }
+                _ => Err(e),
+            },
+        }?;
+
+        if self.verify_purchases && !self.dry_run {
+            // A failed check does not mean the purchase failed, so the receipt is still returned.
+            receipt.in_inventory = self.uaid_in_inventory(uaid).await.ok();
+        }
+
+        Ok(receipt)
+    }
+
+    /// Purchases a limited using [`Client::purchase_limited`], retrying on errors that
+    /// Roblox throws for purchases that can still go through.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Retries on [`PurchaseLimitedError::PendingTransaction`], [`PurchaseLimitedError::PriceChanged`],
+    ///   [`PurchaseLimitedError::UnknownRobloxErrorMsg`], rate limits, and server errors.
+    /// * Stops right away on any other error, such as [`PurchaseLimitedError::ItemNotForSale`]
+    ///   or [`PurchaseLimitedError::NotEnoughRobux`].
+    /// * Waits 0.5 seconds after the first failed attempt, doubling up to 8 seconds, with jitter.
+    ///
+    /// # Argument Notes
+    /// * `max_attempts` includes the first attempt. A value of 0 is treated as 1.
+    ///
+    /// # Return Value Notes
+    /// * The result is the receipt of the purchase, or the error of the last attempt.
+    /// * Every failed attempt before that is logged in [`RetriedPurchase::failed_attempts`].
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::economy::PurchaseArgs;
+    /// use roboat::ids::{ProductId, Uaid};
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let args = PurchaseArgs {
+    ///     product_id: ProductId(12345679),
+    ///     seller_id: 5656565656,
+    ///     uaid: Uaid(987654321),
+    ///     price: 5000,
+    /// };
+    ///
+    /// let purchase = client.purchase_limited_with_retries(args, 5).await;
+    ///
+    /// for attempt in &purchase.failed_attempts {
+    ///     println!("[{}] Failed: {}", attempt.attempted_at, attempt.error);
+    /// }
+    ///
+    /// let receipt = purchase.result?;
+    /// println!("Successfully Purchased for {} robux!", receipt.price);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn purchase_limited_with_retries(
+        &self,
+        args: PurchaseArgs,
+        max_attempts: usize,
+    ) -> RetriedPurchase {
+        let mut backoff = Backoff::new(PURCHASE_RETRY_INITIAL_DELAY)
+            .max_delay(PURCHASE_RETRY_MAX_DELAY)
+            .jitter(true);
+
+        let mut failed_attempts = Vec::new();
+        let mut attempt = 1;
+
+        loop {
+            let attempted_at = Utc::now();
+
+            let error = match self
+                .purchase_limited(args.product_id, args.seller_id, args.uaid, args.price)
+                .await
+            {
+                Ok(receipt) => {
+                    return RetriedPurchase {
+                        result: Ok(receipt),
+                        failed_attempts,
+                    }
+                }
+                Err(e) => e,
+            };
+
+            if attempt >= max_attempts || !Self::is_retryable_purchase_error(&error) {
+                return RetriedPurchase {
+                    result: Err(error),
+                    failed_attempts,
+                };
+            }
+
+            failed_attempts.push(PurchaseAttempt {
+                attempted_at,
+                error,
+            });
+
+            tokio::time::sleep(backoff.next_delay()).await;
+            self.stats.record_retry();
+            attempt += 1;
+        }
+    }
+
+    /// Whether a purchase that failed with `error` is worth attempting again.
+    fn is_retryable_purchase_error(error: &RoboatError) -> bool {
+        RetryPolicy::is_transient(error)
+            || matches!(
+                error,
+                RoboatError::PurchaseLimitedError(
+                    PurchaseLimitedError::PriceChanged
+                        | PurchaseLimitedError::UnknownRobloxErrorMsg(_)
+                )
+            )
+    }
+
+    /// Checks whether a copy with the given uaid is held by the current account.
+    async fn uaid_in_inventory(&self, uaid: Uaid) -> Result<bool, RoboatError> {
+        let user_id = self.user_id().await?;
+        let collectibles = self.all_user_collectibles(user_id).await?;
+
+        Ok(collectibles
+            .iter()
+            .any(|collectible| collectible.uaid == uaid))
+    }
+
+    /// Purchases a limited (including limited u) on behalf of a group using <https://economy.roblox.com/v1/purchases/products/{product_id}>.
+    /// The item will be held by the group instead of the current account.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * The current account must have permission to spend the group's funds.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    ///
+    /// # Return Value Notes
+    /// * Will return a [`PurchaseReceipt`] if the limited was successfully purchased.
+    ///
+    /// # Argument Notes
+    /// * `product_id` if the product id of the limited, NOT the item id.
+    /// * `seller_id` is the id of the reseller. This is a group id if the reseller's
+    ///   [`Reseller::seller_type`] is [`SellerType::Group`].
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    /// * [`RoboatError::PurchaseLimitedError`] - Nested inside this error, all variants of [`PurchaseLimitedError`] may be thrown.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ids::{ProductId, Uaid};
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let group_id = 1127093;
+    /// let product_id = ProductId(12345679);
+    /// let seller_id = 5656565656;
+    /// let uaid = Uaid(987654321);
+    /// let price = 5000;
+    ///
+    /// client
+    ///     .purchase_limited_for_group(group_id, product_id, seller_id, uaid, price)
+    ///     .await?;
+    /// println!("Successfully Purchased!");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn purchase_limited_for_group(
+        &self,
+        group_id: u64,
+        product_id: ProductId,
+        seller_id: u64,
+        uaid: Uaid,
+        price: u64,
+    ) -> Result<PurchaseReceipt, RoboatError> {
         match self
-            .put_limited_on_sale_internal(item_id, uaid, price)
+            .purchase_limited_internal(product_id, price, seller_id, uaid, Some(group_id))
             .await
         {
             Ok(x) => Ok(x),
@@ -371,7 +2290,82 @@ impl Client {
                 RoboatError::InvalidXcsrf(new_xcsrf) => {
                     self.set_xcsrf(new_xcsrf).await;
 
-                    self.put_limited_on_sale_internal(item_id, uaid, price)
+                    self.purchase_limited_internal(
+                        product_id,
+                        price,
+                        seller_id,
+                        uaid,
+                        Some(group_id),
+                    )
+                    .await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Purchases a non-limited product (such as a gamepass, a developer product, or a regular
+    /// catalog item) using <https://economy.roblox.com/v1/purchases/products/{product_id}>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    /// * Use [`Client::purchase_limited`] for limiteds, as they are bought from a specific reseller.
+    ///
+    /// # Return Value Notes
+    /// * Will return a [`ProductReceipt`] if the product was successfully purchased.
+    ///
+    /// # Argument Notes
+    /// * `product_id` is the product id of the item, NOT the item id.
+    /// * `expected_price` is the price the product is expected to cost. The purchase fails
+    ///   with [`PurchaseError::PriceChanged`] if it costs anything else.
+    /// * `expected_seller_id` is the id of the creator of the product. This is a group id if
+    ///   the product is owned by a group.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    /// * [`RoboatError::PurchaseError`] - Nested inside this error, all variants of [`PurchaseError`] may be thrown.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ids::ProductId;
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let product_id = ProductId(12345679);
+    /// let expected_price = 25;
+    /// let expected_seller_id = 1;
+    ///
+    /// let receipt = client
+    ///     .purchase_product(product_id, expected_price, expected_seller_id)
+    ///     .await?;
+    /// println!("Successfully Purchased for {} robux!", receipt.price);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn purchase_product(
+        &self,
+        product_id: ProductId,
+        expected_price: u64,
+        expected_seller_id: u64,
+    ) -> Result<ProductReceipt, RoboatError> {
+        match self
+            .purchase_product_internal(product_id, expected_price, expected_seller_id)
+            .await
+        {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.purchase_product_internal(product_id, expected_price, expected_seller_id)
                         .await
                 }
                 _ => Err(e),
@@ -379,17 +2373,36 @@ impl Client {
         }
     }
 
-    /// Takes a limited item off sale using the endpoint <https://economy.roblox.com/v1/assets/{item_id}/resellable-copies/{uaid}>.
+    /// Buys the cheapest copy of a limited item and immediately lists it again at `relist_price`.
+    /// This is the usual sequence for flipping an item.
+    ///
+    /// After the purchase, the inventory of the current account is checked until a copy of the
+    /// item that was not there before shows up, as Roblox can take a moment to show a new copy.
+    /// That copy is the one that gets listed.
     ///
     /// # Notes
     /// * Requires a valid roblosecurity.
     /// * Will repeat once if the x-csrf-token is invalid.
+    /// * The inventory is checked up to 10 times, 2 seconds apart.
+    /// * In dry run mode, the uaid of the bought listing is listed without checking the inventory.
     ///
     /// # Return Value Notes
-    /// * Will return `Ok(())` if the item was successfully taken off sale.
+    /// * Returns `Ok` whenever the purchase went through, even if the relist failed (for example,
+    ///   if Roblox put the copy on hold or it never showed up in the inventory). Check
+    ///   [`RelistOutcome::result`] to see whether it was listed.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    /// * [`RoboatError::PurchaseLimitedError`] - If the purchase fails, including
+    ///   [`PurchaseLimitedError::AboveMaxPrice`] if the cheapest listing costs more than `buy_max`
+    ///   and [`PurchaseLimitedError::ItemNotForSale`] if there are no listings.
     ///
     /// # Example
     /// ```no_run
+    /// use roboat::economy::ProductInfo;
+    /// use roboat::ids::{AssetId, ProductId};
     /// use roboat::ClientBuilder;
     ///
     /// const ROBLOSECURITY: &str = "roblosecurity";
@@ -398,51 +2411,95 @@ impl Client {
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
     ///
-    /// let item_id = 123456789;
-    /// let uaid = 987654321;
+    /// let product_info = ProductInfo {
+    ///     asset_id: AssetId(1365767),
+    ///     product_id: ProductId(21149340),
+    /// };
     ///
-    /// match client.take_limited_off_sale(item_id, uaid).await {
-    ///    Ok(_) => println!("Successfully took item off sale!"),
-    ///    Err(e) => println!("Error: {}", e),
+    /// let outcome = client.buy_and_relist(product_info, 5000, 6000).await?;
+    /// println!("Bought for {} robux.", outcome.receipt.price);
+    ///
+    /// match outcome.result {
+    ///     Ok(uaid) => println!("Listed uaid {}.", uaid),
+    ///     Err(e) => println!("Failed to list. Reason: {}", e),
     /// }
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn take_limited_off_sale(&self, item_id: u64, uaid: u64) -> Result<(), RoboatError> {
-        match self.take_limited_off_sale_internal(item_id, uaid).await {
-            Ok(x) => Ok(x),
-            Err(e) => match e {
-                RoboatError::InvalidXcsrf(new_xcsrf) => {
-                    self.set_xcsrf(new_xcsrf).await;
-
-                    self.take_limited_off_sale_internal(item_id, uaid).await
-                }
-                _ => Err(e),
-            },
+    pub async fn buy_and_relist(
+        &self,
+        product_info: ProductInfo,
+        buy_max: u64,
+        relist_price: u64,
+    ) -> Result<RelistOutcome, RoboatError> {
+        let (listings, _) = self
+            .resellers(product_info.asset_id, Limit::Ten, None)
+            .await?;
+
+        let listing = listings
+            .into_iter()
+            .min_by_key(|listing| listing.price)
+            .ok_or(RoboatError::PurchaseLimitedError(
+                PurchaseLimitedError::ItemNotForSale,
+            ))?;
+
+        if listing.price > buy_max {
+            return Err(RoboatError::PurchaseLimitedError(
+                PurchaseLimitedError::AboveMaxPrice(listing.price),
+            ));
         }
+
+        let owned_before = match self.dry_run {
+            true => Vec::new(),
+            false => self.owned_uaids(product_info.asset_id).await?,
+        };
+
+        let receipt = self
+            .purchase_limited(
+                product_info.product_id,
+                listing.reseller.user_id,
+                listing.uaid,
+                listing.price,
+            )
+            .await?;
+
+        let result = match self.dry_run {
+            true => Ok(listing.uaid),
+            false => {
+                self.wait_for_new_uaid(product_info.asset_id, &owned_before)
+                    .await
+            }
+        };
+
+        let result = match result {
+            Ok(uaid) => self
+                .put_limited_on_sale(product_info.asset_id, uaid, relist_price)
+                .await
+                .map(|_| uaid),
+            Err(e) => Err(e),
+        };
+
+        Ok(RelistOutcome { receipt, result })
     }
 
-    // todo: add manual xcsrf refreshing and talk about it here
-    /// Purchases a limited (including limited u) using  <https://economy.roblox.com/v1/purchases/products/{product_id}>.
+    /// Finds the cheapest way to buy an item by comparing its primary sale price with the
+    /// lowest reseller price. Uses [`Client::item_details`] and [`Client::resellers`].
     ///
     /// # Notes
     /// * Requires a valid roblosecurity.
-    /// * Will repeat once if the x-csrf-token is invalid.
-    ///
-    /// # Return Value Notes
-    /// * Will return `Ok(())` if the limited was successfully purchased.
-    ///
-    /// # Argument Notes
-    /// * `product_id` if the product id of the limited, NOT the item id.
+    /// * Resellers are only checked if the item is a limited.
+    /// * If both channels have the same price, the primary sale is returned.
     ///
     /// # Errors
     /// * All errors under [Standard Errors](#standard-errors).
     /// * All errors under [Auth Required Errors](#auth-required-errors).
-    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
-    /// * [`RoboatError::PurchaseLimitedError`] - Nested inside this error, all variants of [`PurchaseLimitedError`] may be thrown.
+    /// * [`RoboatError::PurchaseLimitedError`] - [`PurchaseLimitedError::ItemNotForSale`] if
+    ///   the item is neither on primary sale nor resold by anyone.
     ///
     /// # Example
     /// ```no_run
+    /// use roboat::economy::BestPrice;
+    /// use roboat::ids::AssetId;
     /// use roboat::ClientBuilder;
     ///
     /// const ROBLOSECURITY: &str = "roblosecurity";
@@ -451,53 +2508,510 @@ impl Client {
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
     ///
-    /// let product_id = 12345679;
-    /// let seller_id = 5656565656;
-    /// let uaid = 987654321;
-    /// let price = 5000;
-    ///
-    /// let _ = client.purchase_limited(product_id, seller_id, uaid, price).await?;
-    /// println!("Successfully Purchased!");
+    /// match client.best_price(AssetId(1365767)).await? {
+    ///     BestPrice::Primary { price, .. } => println!("Cheapest from primary sale: {}", price),
+    ///     BestPrice::Resale(args) => {
+    ///         println!("Cheapest from uaid {}: {}", args.uaid, args.price);
+    ///         let retried_purchase = client.purchase_limited_with_retries(args, 3).await;
+    ///         retried_purchase.result?;
+    ///     }
+    /// }
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn purchase_limited(
+    pub async fn best_price(&self, item_id: AssetId) -> Result<BestPrice, RoboatError> {
+        let item_args = ItemArgs {
+            item_type: ItemType::Asset,
+            id: item_id.0,
+        };
+
+        let item_details = self
+            .item_details(vec![item_args])
+            .await?
+            .pop()
+            .ok_or(RoboatError::MalformedResponse)?;
+
+        let product_id = item_details.product_id;
+
+        let is_limited = item_details.item_restrictions.iter().any(|restriction| {
+            matches!(
+                restriction,
+                ItemRestriction::Limited | ItemRestriction::LimitedUnique
+            )
+        });
+
+        // The price of a classic limited is the lowest reseller price, not a primary sale.
+        // Collectibles can still be on primary sale while copies are being resold.
+        let has_primary_sale = !matches!(
+            item_details.price_status,
+            Some(PriceStatus::Offsale) | Some(PriceStatus::NoResellers)
+        ) && (!is_limited || item_details.collectible_item_id.is_some());
+
+        let primary = match (has_primary_sale, item_details.price) {
+            (true, Some(price)) => Some(BestPrice::Primary {
+                product_id,
+                seller_id: item_details.creator_user_id,
+                price,
+            }),
+            (true, None) if item_details.price_status == Some(PriceStatus::Free) => {
+                Some(BestPrice::Primary {
+                    product_id,
+                    seller_id: item_details.creator_user_id,
+                    price: 0,
+                })
+            }
+            _ => None,
+        };
+
+        let resale = match is_limited {
+            true => self
+                .resellers(item_id, Limit::Ten, None)
+                .await?
+                .0
+                .into_iter()
+                .min_by_key(|listing| listing.price)
+                .map(|listing| {
+                    BestPrice::Resale(PurchaseArgs {
+                        product_id,
+                        seller_id: listing.reseller.user_id,
+                        uaid: listing.uaid,
+                        price: listing.price,
+                    })
+                }),
+            false => None,
+        };
+
+        match (primary, resale) {
+            (Some(primary), Some(resale)) if resale.price() < primary.price() => Ok(resale),
+            (Some(primary), _) => Ok(primary),
+            (None, Some(resale)) => Ok(resale),
+            (None, None) => Err(RoboatError::PurchaseLimitedError(
+                PurchaseLimitedError::ItemNotForSale,
+            )),
+        }
+    }
+
+    /// Grabs the raw details of an asset from <https://economy.roblox.com/v2/assets/{asset_id}/details>.
+    async fn asset_details(
+        &self,
+        asset_id: AssetId,
+    ) -> Result<request_types::AssetDetailsResponse, RoboatError> {
+        let formatted_url = format!(
+            "{}{}{}",
+            ASSET_DETAILS_API_PART_1, asset_id, ASSET_DETAILS_API_PART_2
+        );
+
+        let request = self.reqwest_client.get(formatted_url);
+
+        let response = self.send_request(request).await?;
+        Self::parse_to_raw::<request_types::AssetDetailsResponse>(response).await
+    }
+
+    /// Returns the uaids of every copy of an item held by the current account.
+    async fn owned_uaids(&self, asset_id: AssetId) -> Result<Vec<Uaid>, RoboatError> {
+        let user_id = self.user_id().await?;
+
+        Ok(self
+            .all_user_collectibles(user_id)
+            .await?
+            .into_iter()
+            .filter(|collectible| collectible.asset_id == asset_id)
+            .map(|collectible| collectible.uaid)
+            .collect())
+    }
+
+    /// Checks the inventory until a copy of the item shows up that is not in `owned_before`.
+    async fn wait_for_new_uaid(
+        &self,
+        asset_id: AssetId,
+        owned_before: &[Uaid],
+    ) -> Result<Uaid, RoboatError> {
+        for attempt in 0..RELIST_INVENTORY_ATTEMPTS {
+            if attempt > 0 {
+                tokio::time::sleep(RELIST_INVENTORY_DELAY).await;
+            }
+
+            let new_uaid = self
+                .owned_uaids(asset_id)
+                .await?
+                .into_iter()
+                .find(|uaid| !owned_before.contains(uaid));
+
+            if let Some(uaid) = new_uaid {
+                return Ok(uaid);
+            }
+        }
+
+        Err(RoboatError::PurchaseNotInInventory)
+    }
+}
+
+/// The endpoints of this module as a trait, implemented by [`Client`].
+///
+/// Code written against `impl EconomyApi` instead of [`Client`] can be given a mock
+/// implementation in tests. Every method behaves exactly like the [`Client`] method of the same name.
+///
+/// # Example
+/// ```no_run
+/// use roboat::economy::EconomyApi;
+/// use roboat::{ClientBuilder, RoboatError};
+///
+/// async fn can_afford(economy: &impl EconomyApi, price: u64) -> Result<bool, RoboatError> {
+///     Ok(economy.robux().await? >= price)
+/// }
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = ClientBuilder::new().roblosecurity("roblosecurity".to_string()).build();
+///
+/// println!("Can afford: {}", can_afford(&client, 5000).await?);
+/// # Ok(())
+/// # }
+/// ```
+#[allow(missing_docs)]
+pub trait EconomyApi {
+    fn robux(&self) -> impl Future<Output = Result<u64, RoboatError>> + Send;
+
+    fn resellers(
+        &self,
+        item_id: AssetId,
+        limit: Limit,
+        cursor: Option<String>,
+    ) -> impl Future<Output = Result<(Vec<Listing>, Option<String>), RoboatError>> + Send;
+
+    fn resale_data(
+        &self,
+        item_id: AssetId,
+    ) -> impl Future<Output = Result<ResaleData, RoboatError>> + Send;
+
+    fn sales_velocity(
+        &self,
+        item_id: AssetId,
+        window: Duration,
+    ) -> impl Future<Output = Result<SalesVelocity, RoboatError>> + Send;
+
+    fn asset_version_metadata(
+        &self,
+        asset_id: AssetId,
+    ) -> impl Future<Output = Result<AssetVersionMetadata, RoboatError>> + Send;
+
+    fn user_sales(
+        &self,
+        limit: Limit,
+        cursor: Option<String>,
+    ) -> impl Future<Output = Result<(Vec<UserSale>, Option<String>), RoboatError>> + Send;
+
+    fn commission_sales(
+        &self,
+        limit: Limit,
+        cursor: Option<String>,
+    ) -> impl Future<Output = Result<(Vec<CommissionSale>, Option<String>), RoboatError>> + Send;
+
+    fn transactions(
+        &self,
+        transaction_type: TransactionType,
+        limit: Limit,
+        cursor: Option<String>,
+    ) -> impl Future<Output = Result<(Vec<Transaction>, Option<String>), RoboatError>> + Send;
+
+    fn billing_history(
+        &self,
+        limit: Limit,
+        cursor: Option<String>,
+    ) -> impl Future<Output = Result<(Vec<TransactionRecord>, Option<String>), RoboatError>> + Send;
+
+    fn pending_robux(&self) -> impl Future<Output = Result<RobuxSummary, RoboatError>> + Send;
+
+    fn group_funds(
+        &self,
+        group_id: u64,
+    ) -> impl Future<Output = Result<GroupFunds, RoboatError>> + Send;
+
+    fn group_revenue_summary(
+        &self,
+        group_id: u64,
+        time_frame: RevenueTimeFrame,
+    ) -> impl Future<Output = Result<RevenueSummary, RoboatError>> + Send;
+
+    fn group_transactions(
+        &self,
+        group_id: u64,
+        transaction_type: TransactionType,
+        limit: Limit,
+        cursor: Option<String>,
+    ) -> impl Future<Output = Result<(Vec<Transaction>, Option<String>), RoboatError>> + Send;
+
+    fn put_limited_on_sale(
+        &self,
+        item_id: AssetId,
+        uaid: Uaid,
+        price: u64,
+    ) -> impl Future<Output = Result<(), RoboatError>> + Send;
+
+    fn validate_listing_price(
+        &self,
+        item_id: AssetId,
+        price: u64,
+    ) -> impl Future<Output = Result<(), RoboatError>> + Send;
+
+    fn take_limited_off_sale(
+        &self,
+        item_id: AssetId,
+        uaid: Uaid,
+    ) -> impl Future<Output = Result<(), RoboatError>> + Send;
+
+    fn purchase_limited(
         &self,
-        product_id: u64,
+        product_id: ProductId,
         seller_id: u64,
-        uaid: u64,
+        uaid: Uaid,
         price: u64,
-    ) -> Result<(), RoboatError> {
-        match self
-            .purchase_limited_internal(product_id, price, seller_id, uaid)
-            .await
-        {
-            Ok(x) => Ok(x),
-            Err(e) => match e {
-                RoboatError::InvalidXcsrf(new_xcsrf) => {
-                    self.set_xcsrf(new_xcsrf).await;
+    ) -> impl Future<Output = Result<PurchaseReceipt, RoboatError>> + Send;
 
-                    self.purchase_limited_internal(product_id, price, seller_id, uaid)
-                        .await
-                }
-                _ => Err(e),
-            },
-        }
+    fn purchase_limited_for_group(
+        &self,
+        group_id: u64,
+        product_id: ProductId,
+        seller_id: u64,
+        uaid: Uaid,
+        price: u64,
+    ) -> impl Future<Output = Result<PurchaseReceipt, RoboatError>> + Send;
+
+    fn purchase_limited_with_retries(
+        &self,
+        args: PurchaseArgs,
+        max_attempts: usize,
+    ) -> impl Future<Output = RetriedPurchase> + Send;
+
+    fn purchase_product(
+        &self,
+        product_id: ProductId,
+        expected_price: u64,
+        expected_seller_id: u64,
+    ) -> impl Future<Output = Result<ProductReceipt, RoboatError>> + Send;
+
+    fn buy_and_relist(
+        &self,
+        product_info: ProductInfo,
+        buy_max: u64,
+        relist_price: u64,
+    ) -> impl Future<Output = Result<RelistOutcome, RoboatError>> + Send;
+
+    fn best_price(
+        &self,
+        item_id: AssetId,
+    ) -> impl Future<Output = Result<BestPrice, RoboatError>> + Send;
+
+    fn watch_remaining(
+        &self,
+        asset_id: AssetId,
+        interval: impl Into<PollInterval>,
+    ) -> BoxStream<'_, Result<RemainingStock, RoboatError>>;
+}
+
+impl EconomyApi for Client {
+    fn robux(&self) -> impl Future<Output = Result<u64, RoboatError>> + Send {
+        Client::robux(self)
+    }
+
+    fn resellers(
+        &self,
+        item_id: AssetId,
+        limit: Limit,
+        cursor: Option<String>,
+    ) -> impl Future<Output = Result<(Vec<Listing>, Option<String>), RoboatError>> + Send {
+        Client::resellers(self, item_id, limit, cursor)
+    }
+
+    fn resale_data(
+        &self,
+        item_id: AssetId,
+    ) -> impl Future<Output = Result<ResaleData, RoboatError>> + Send {
+        Client::resale_data(self, item_id)
+    }
+
+    fn sales_velocity(
+        &self,
+        item_id: AssetId,
+        window: Duration,
+    ) -> impl Future<Output = Result<SalesVelocity, RoboatError>> + Send {
+        Client::sales_velocity(self, item_id, window)
+    }
+
+    fn asset_version_metadata(
+        &self,
+        asset_id: AssetId,
+    ) -> impl Future<Output = Result<AssetVersionMetadata, RoboatError>> + Send {
+        Client::asset_version_metadata(self, asset_id)
+    }
+
+    fn user_sales(
+        &self,
+        limit: Limit,
+        cursor: Option<String>,
+    ) -> impl Future<Output = Result<(Vec<UserSale>, Option<String>), RoboatError>> + Send {
+        Client::user_sales(self, limit, cursor)
+    }
+
+    fn commission_sales(
+        &self,
+        limit: Limit,
+        cursor: Option<String>,
+    ) -> impl Future<Output = Result<(Vec<CommissionSale>, Option<String>), RoboatError>> + Send
+    {
+        Client::commission_sales(self, limit, cursor)
+    }
+
+    fn transactions(
+        &self,
+        transaction_type: TransactionType,
+        limit: Limit,
+        cursor: Option<String>,
+    ) -> impl Future<Output = Result<(Vec<Transaction>, Option<String>), RoboatError>> + Send {
+        Client::transactions(self, transaction_type, limit, cursor)
+    }
+
+    fn billing_history(
+        &self,
+        limit: Limit,
+        cursor: Option<String>,
+    ) -> impl Future<Output = Result<(Vec<TransactionRecord>, Option<String>), RoboatError>> + Send
+    {
+        Client::billing_history(self, limit, cursor)
+    }
+
+    fn pending_robux(&self) -> impl Future<Output = Result<RobuxSummary, RoboatError>> + Send {
+        Client::pending_robux(self)
+    }
+
+    fn group_funds(
+        &self,
+        group_id: u64,
+    ) -> impl Future<Output = Result<GroupFunds, RoboatError>> + Send {
+        Client::group_funds(self, group_id)
+    }
+
+    fn group_revenue_summary(
+        &self,
+        group_id: u64,
+        time_frame: RevenueTimeFrame,
+    ) -> impl Future<Output = Result<RevenueSummary, RoboatError>> + Send {
+        Client::group_revenue_summary(self, group_id, time_frame)
+    }
+
+    fn group_transactions(
+        &self,
+        group_id: u64,
+        transaction_type: TransactionType,
+        limit: Limit,
+        cursor: Option<String>,
+    ) -> impl Future<Output = Result<(Vec<Transaction>, Option<String>), RoboatError>> + Send {
+        Client::group_transactions(self, group_id, transaction_type, limit, cursor)
+    }
+
+    fn put_limited_on_sale(
+        &self,
+        item_id: AssetId,
+        uaid: Uaid,
+        price: u64,
+    ) -> impl Future<Output = Result<(), RoboatError>> + Send {
+        Client::put_limited_on_sale(self, item_id, uaid, price)
+    }
+
+    fn validate_listing_price(
+        &self,
+        item_id: AssetId,
+        price: u64,
+    ) -> impl Future<Output = Result<(), RoboatError>> + Send {
+        Client::validate_listing_price(self, item_id, price)
+    }
+
+    fn take_limited_off_sale(
+        &self,
+        item_id: AssetId,
+        uaid: Uaid,
+    ) -> impl Future<Output = Result<(), RoboatError>> + Send {
+        Client::take_limited_off_sale(self, item_id, uaid)
+    }
+
+    fn purchase_limited(
+        &self,
+        product_id: ProductId,
+        seller_id: u64,
+        uaid: Uaid,
+        price: u64,
+    ) -> impl Future<Output = Result<PurchaseReceipt, RoboatError>> + Send {
+        Client::purchase_limited(self, product_id, seller_id, uaid, price)
+    }
+
+    fn purchase_limited_for_group(
+        &self,
+        group_id: u64,
+        product_id: ProductId,
+        seller_id: u64,
+        uaid: Uaid,
+        price: u64,
+    ) -> impl Future<Output = Result<PurchaseReceipt, RoboatError>> + Send {
+        Client::purchase_limited_for_group(self, group_id, product_id, seller_id, uaid, price)
+    }
+
+    fn purchase_limited_with_retries(
+        &self,
+        args: PurchaseArgs,
+        max_attempts: usize,
+    ) -> impl Future<Output = RetriedPurchase> + Send {
+        Client::purchase_limited_with_retries(self, args, max_attempts)
+    }
+
+    fn purchase_product(
+        &self,
+        product_id: ProductId,
+        expected_price: u64,
+        expected_seller_id: u64,
+    ) -> impl Future<Output = Result<ProductReceipt, RoboatError>> + Send {
+        Client::purchase_product(self, product_id, expected_price, expected_seller_id)
+    }
+
+    fn buy_and_relist(
+        &self,
+        product_info: ProductInfo,
+        buy_max: u64,
+        relist_price: u64,
+    ) -> impl Future<Output = Result<RelistOutcome, RoboatError>> + Send {
+        Client::buy_and_relist(self, product_info, buy_max, relist_price)
+    }
+
+    fn best_price(
+        &self,
+        item_id: AssetId,
+    ) -> impl Future<Output = Result<BestPrice, RoboatError>> + Send {
+        Client::best_price(self, item_id)
+    }
+
+    fn watch_remaining(
+        &self,
+        asset_id: AssetId,
+        interval: impl Into<PollInterval>,
+    ) -> BoxStream<'_, Result<RemainingStock, RoboatError>> {
+        Client::watch_remaining(self, asset_id, interval)
     }
 }
 
 mod internal {
     use super::{
-        request_types, PurchaseLimitedError, TOGGLE_SALE_API_PART_1, TOGGLE_SALE_API_PART_2,
+        request_types, ProductReceipt, PurchaseError, PurchaseLimitedError, PurchaseReceipt,
+        SellerType, TOGGLE_SALE_API_PART_1, TOGGLE_SALE_API_PART_2,
     };
+    use crate::ids::{AssetId, ProductId, Uaid};
     use crate::{Client, RoboatError, CONTENT_TYPE, USER_AGENT, XCSRF_HEADER};
+    use chrono::Utc;
     use reqwest::header;
 
     impl Client {
         pub(super) async fn put_limited_on_sale_internal(
             &self,
-            item_id: u64,
-            uaid: u64,
+            item_id: AssetId,
+            uaid: Uaid,
             price: u64,
         ) -> Result<(), RoboatError> {
             let formatted_url = format!(
@@ -511,16 +3025,16 @@ mod internal {
                 "price": price,
             });
 
-            let request_result = self
+            let request = self
                 .reqwest_client
                 .patch(formatted_url)
                 .header(header::COOKIE, cookie)
                 .header(XCSRF_HEADER, self.xcsrf().await)
-                .json(&json)
-                .send()
-                .await;
+                .json(&json);
 
-            let _ = Self::validate_request_result(request_result).await?;
+            let _ = self
+                .send_mutating_request(request, serde_json::json!({}))
+                .await?;
 
             // We don't need to do anything, we just need a 200 status code.
 
@@ -529,8 +3043,8 @@ mod internal {
 
         pub(super) async fn take_limited_off_sale_internal(
             &self,
-            item_id: u64,
-            uaid: u64,
+            item_id: AssetId,
+            uaid: Uaid,
         ) -> Result<(), RoboatError> {
             let formatted_url = format!(
                 "{}{}{}{}",
@@ -541,16 +3055,16 @@ mod internal {
 
             let json = serde_json::json!({});
 
-            let request_result = self
+            let request = self
                 .reqwest_client
                 .patch(formatted_url)
                 .header(header::COOKIE, cookie)
                 .header(XCSRF_HEADER, self.xcsrf().await)
-                .json(&json)
-                .send()
-                .await;
+                .json(&json);
 
-            let _ = Self::validate_request_result(request_result).await?;
+            let _ = self
+                .send_mutating_request(request, serde_json::json!({}))
+                .await?;
 
             // We don't need to do anything, we just need a 200 status code.
 
@@ -559,11 +3073,12 @@ mod internal {
 
         pub(super) async fn purchase_limited_internal(
             &self,
-            product_id: u64,
+            product_id: ProductId,
             price: u64,
             seller_id: u64,
-            uaid: u64,
-        ) -> Result<(), RoboatError> {
+            uaid: Uaid,
+            purchasing_group_id: Option<u64>,
+        ) -> Result<PurchaseReceipt, RoboatError> {
             let formatted_url = format!(
                 "https://economy.roblox.com/v1/purchases/products/{}",
                 product_id
@@ -571,34 +3086,51 @@ mod internal {
 
             let cookie = self.cookie_string()?;
 
-            let json = serde_json::json!({
+            let mut json = serde_json::json!({
                 "expectedCurrency": 1,
                 "expectedPrice": price,
                 "expectedSellerId": seller_id,
                 "userAssetId": uaid,
             });
 
-            let request_result = self
+            // Only sent when buying on behalf of a group, otherwise the item goes to the user.
+            if let Some(group_id) = purchasing_group_id {
+                json["expectedPurchaserType"] = SellerType::Group.as_str().into();
+                json["expectedPurchaserId"] = group_id.into();
+            }
+
+            let request = self
                 .reqwest_client
                 .post(formatted_url)
                 .header(header::COOKIE, cookie)
                 .header(XCSRF_HEADER, self.xcsrf().await)
                 .header(header::USER_AGENT, USER_AGENT)
                 .header(header::CONTENT_TYPE, CONTENT_TYPE)
-                .json(&json)
-                .send()
-                .await;
+                .json(&json);
 
-            let response = Self::validate_request_result(request_result).await?;
+            let dry_run_body = serde_json::json!({
+                "purchased": true,
+                "errorMsg": "",
+            });
+
+            let response = self.send_mutating_request(request, dry_run_body).await?;
 
             let raw =
                 Self::parse_to_raw::<request_types::PurchaseLimitedResponse>(response).await?;
 
             match raw.purchased {
-                true => Ok(()),
+                true => Ok(PurchaseReceipt {
+                    product_id,
+                    asset_id: raw.asset_id.map(AssetId),
+                    uaid,
+                    price: raw.price.unwrap_or(price),
+                    seller_id,
+                    purchased_at: Utc::now(),
+                    in_inventory: None,
+                }),
                 false => match raw.error_msg.as_str() {
                     "You have a pending transaction. Please wait 1 minute and try again." => Err(
-                        RoboatError::PurchaseLimitedError(PurchaseLimitedError::CannotBuyOwnItem),
+                        RoboatError::PurchaseLimitedError(PurchaseLimitedError::PendingTransaction),
                     ),
                     "You already own this item." => Err(RoboatError::PurchaseLimitedError(
                         PurchaseLimitedError::CannotBuyOwnItem,
@@ -620,5 +3152,79 @@ mod internal {
                 },
             }
         }
+
+        pub(super) async fn purchase_product_internal(
+            &self,
+            product_id: ProductId,
+            expected_price: u64,
+            expected_seller_id: u64,
+        ) -> Result<ProductReceipt, RoboatError> {
+            let formatted_url = format!(
+                "https://economy.roblox.com/v1/purchases/products/{}",
+                product_id
+            );
+
+            let cookie = self.cookie_string()?;
+
+            let json = serde_json::json!({
+                "expectedCurrency": 1,
+                "expectedPrice": expected_price,
+                "expectedSellerId": expected_seller_id,
+            });
+
+            let request = self
+                .reqwest_client
+                .post(formatted_url)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, self.xcsrf().await)
+                .header(header::USER_AGENT, USER_AGENT)
+                .header(header::CONTENT_TYPE, CONTENT_TYPE)
+                .json(&json);
+
+            let dry_run_body = serde_json::json!({
+                "purchased": true,
+                "errorMsg": "",
+            });
+
+            let response = self.send_mutating_request(request, dry_run_body).await?;
+
+            let raw =
+                Self::parse_to_raw::<request_types::PurchaseProductResponse>(response).await?;
+
+            if raw.purchased {
+                return Ok(ProductReceipt {
+                    product_id,
+                    asset_id: raw.asset_id.map(AssetId),
+                    price: raw.price.unwrap_or(expected_price),
+                    seller_id: expected_seller_id,
+                    purchased_at: Utc::now(),
+                });
+            }
+
+            // The reason is more stable than the message, so it is checked first.
+            let error = match (raw.reason.as_deref(), raw.error_msg.as_str()) {
+                (Some("AlreadyOwned"), _) | (_, "You already own this item.") => {
+                    PurchaseError::AlreadyOwned
+                }
+                (Some("NotForSale"), _) | (_, "This item is not for sale.") => {
+                    PurchaseError::NotForSale
+                }
+                (Some("InsufficientFunds"), _)
+                | (_, "You do not have enough Robux to purchase this item.") => {
+                    PurchaseError::NotEnoughRobux
+                }
+                (Some("PriceChanged"), _)
+                | (_, "This item has changed price. Please try again.") => {
+                    PurchaseError::PriceChanged
+                }
+                (Some("PendingTransaction"), _)
+                | (_, "You have a pending transaction. Please wait 1 minute and try again.") => {
+                    PurchaseError::PendingTransaction
+                }
+                _ => PurchaseError::UnknownRobloxErrorMsg(raw.error_msg),
+            };
+
+            Err(RoboatError::PurchaseError(error))
+        }
     }
 }
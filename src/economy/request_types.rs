@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -5,6 +6,24 @@ pub(super) struct CurrencyResponse {
     pub robux: u64,
 }
 
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct GroupRevenueSummaryResponse {
+    #[serde(default)]
+    pub item_sale_robux: i64,
+    #[serde(default)]
+    pub commission_robux: i64,
+    #[serde(default)]
+    pub premium_payouts: i64,
+    #[serde(default)]
+    pub group_premium_payouts: i64,
+    #[serde(default)]
+    pub individual_to_group_robux: i64,
+    #[serde(default)]
+    pub group_payout_robux: i64,
+    pub pending_robux: u64,
+}
+
 #[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub(super) struct ResellersResponse {
@@ -28,46 +47,64 @@ pub(super) struct ResellerRaw {
     pub has_verified_badge: bool,
     pub id: u64,
     #[serde(rename = "type")]
-    pub seller_type: Option<serde_json::Value>,
+    pub seller_type: Option<String>,
     pub name: String,
 }
 
 #[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub(super) struct UserSalesResponse {
+pub(super) struct TransactionsResponse {
     pub previous_page_cursor: Option<String>,
     pub next_page_cursor: Option<String>,
-    pub data: Vec<SaleRaw>,
+    pub data: Vec<TransactionRaw>,
 }
 
 #[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub(super) struct SaleRaw {
+pub(super) struct TransactionRaw {
     pub id: u64,
+    pub created: Option<DateTime<Utc>>,
+    #[serde(default)]
     pub is_pending: bool,
-    pub agent: UserRaw,
-    pub details: DetailsRaw,
+    /// Does not exist for some transaction types, such as premium stipends.
+    pub agent: Option<AgentRaw>,
+    /// Does not exist for some transaction types, such as premium stipends.
+    pub details: Option<DetailsRaw>,
     pub currency: CurrencyRaw,
 }
 
-// This is what they call the user that bought the item for some reason.
+// This is what they call the other side of the transaction, such as the user that
+// bought the item for a sale.
 #[derive(Serialize, Deserialize)]
-pub(super) struct UserRaw {
+pub(super) struct AgentRaw {
     pub id: u64,
-    /// This is the user's display name.
+    /// This is the user's display name, or the name of the group.
     pub name: String,
+    #[serde(rename = "type")]
+    pub agent_type: Option<String>,
 }
 
 #[derive(Serialize, Deserialize)]
 pub(super) struct DetailsRaw {
-    pub id: u64,
+    pub id: Option<u64>,
     /// The name of the item.
+    pub name: Option<String>,
+    /// The experience the item was sold in. Only exists for commission (affiliate) sales.
+    pub place: Option<PlaceRaw>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct PlaceRaw {
+    pub place_id: u64,
+    pub universe_id: u64,
     pub name: String,
 }
 
 #[derive(Serialize, Deserialize)]
 pub(super) struct CurrencyRaw {
-    pub amount: u64,
+    /// Negative for transactions that spend robux, such as purchases.
+    pub amount: i64,
     #[serde(rename = "type")]
     pub currency_type: CurrencyTypeRaw,
 }
@@ -78,13 +115,56 @@ pub(super) enum CurrencyTypeRaw {
 }
 
 #[derive(Serialize, Deserialize)]
-pub(super) enum TransactionTypeRaw {
-    Sale,
+#[serde(rename_all = "camelCase")]
+pub struct PurchaseLimitedResponse {
+    pub purchased: bool,
+    pub error_msg: String,
+    /// Only exists if the purchase went through.
+    pub asset_id: Option<u64>,
+    /// Only exists if the purchase went through.
+    pub price: Option<u64>,
 }
 
 #[derive(Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct PurchaseLimitedResponse {
+pub(super) struct PurchaseProductResponse {
     pub purchased: bool,
+    #[serde(default)]
     pub error_msg: String,
+    /// A short code for why the purchase failed, such as `"AlreadyOwned"`.
+    pub reason: Option<String>,
+    /// Only exists if the purchase went through.
+    pub asset_id: Option<u64>,
+    /// Only exists if the purchase went through.
+    pub price: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct ResaleDataResponse {
+    pub asset_stock: Option<u64>,
+    pub sales: u64,
+    pub number_remaining: Option<u64>,
+    pub recent_average_price: u64,
+    pub original_price: Option<u64>,
+    pub price_data_points: Vec<DataPointRaw>,
+    pub volume_data_points: Vec<DataPointRaw>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(super) struct DataPointRaw {
+    pub value: u64,
+    pub date: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub(super) struct AssetDetailsResponse {
+    pub asset_id: u64,
+    pub name: String,
+    pub description: Option<String>,
+    pub created: DateTime<Utc>,
+    pub updated: DateTime<Utc>,
+    /// Only exists for Limited U items.
+    pub remaining: Option<u64>,
 }
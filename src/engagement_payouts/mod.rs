@@ -0,0 +1,101 @@
+use crate::ids::UniverseId;
+use crate::{Client, RoboatError};
+use reqwest::header;
+use serde::{Deserialize, Serialize};
+
+mod request_types;
+
+const PAYOUT_HISTORY_API: &str = "https://engagementpayouts.roblox.com/v1/universe-payout-history";
+
+/// A single day of Premium (engagement-based) payouts for a universe. Retrieved from
+/// <https://engagementpayouts.roblox.com/v1/universe-payout-history>.
+#[derive(Clone, Debug, PartialEq, PartialOrd, Default, Serialize, Deserialize)]
+pub struct PremiumPayout {
+    /// The day the payout is for, in the format `YYYY-MM-DD`.
+    pub date: String,
+    /// The engagement score of the universe for the day.
+    pub engagement_score: f64,
+    /// The amount of robux paid out for the day.
+    pub payout_in_robux: u64,
+    /// The type of the payout (usually `Engagement`).
+    pub payout_type: String,
+    /// Whether the universe was eligible for payouts on that day (usually `Eligible`).
+    pub eligibility_type: String,
+}
+
+impl Client {
+    /// Grabs the daily Premium payouts of a universe from
+    /// <https://engagementpayouts.roblox.com/v1/universe-payout-history?universeId={universe_id}&startDate={start}&endDate={end}>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * The user must have permission to view the analytics of the universe.
+    ///
+    /// # Argument Notes
+    /// * `start` and `end` are inclusive dates in the format `YYYY-MM-DD`.
+    ///
+    /// # Return Value Notes
+    /// * The payouts are sorted by date, oldest first.
+    /// * Days without a payout are not included.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ids::UniverseId;
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let universe_id = UniverseId(1234567890);
+    /// let start = "2023-05-01".to_string();
+    /// let end = "2023-05-31".to_string();
+    ///
+    /// let payouts = client.premium_payouts(universe_id, start, end).await?;
+    ///
+    /// for payout in payouts {
+    ///     println!("{}: {} robux", payout.date, payout.payout_in_robux);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn premium_payouts(
+        &self,
+        universe_id: UniverseId,
+        start: String,
+        end: String,
+    ) -> Result<Vec<PremiumPayout>, RoboatError> {
+        let formatted_url = format!(
+            "{}?universeId={}&startDate={}&endDate={}",
+            PAYOUT_HISTORY_API, universe_id, start, end
+        );
+
+        let cookie = self.cookie_string()?;
+
+        let request = self
+            .reqwest_client
+            .get(formatted_url)
+            .header(header::COOKIE, cookie);
+
+        let response = self.send_request(request).await?;
+        let raw = Self::parse_to_raw::<request_types::PayoutHistoryResponse>(response).await?;
+
+        let mut payouts = raw
+            .into_iter()
+            .map(|(date, payout)| PremiumPayout {
+                date,
+                engagement_score: payout.engagement_score,
+                payout_in_robux: payout.payout_in_robux,
+                payout_type: payout.payout_type,
+                eligibility_type: payout.eligibility_type,
+            })
+            .collect::<Vec<_>>();
+
+        // Dates are in the format YYYY-MM-DD so they sort chronologically as strings.
+        payouts.sort_by(|a, b| a.date.cmp(&b.date));
+
+        Ok(payouts)
+    }
+}
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// The response is a map of dates (`YYYY-MM-DD`) to the payout for that day.
+pub(super) type PayoutHistoryResponse = HashMap<String, PayoutRaw>;
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct PayoutRaw {
+    pub engagement_score: f64,
+    pub payout_in_robux: u64,
+    pub payout_type: String,
+    pub eligibility_type: String,
+}
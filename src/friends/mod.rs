@@ -0,0 +1,937 @@
+use crate::ids::{AssetId, PlaceId, UniverseId, UserId};
+use crate::{BatchResult, Client, Limit, RoboatError};
+use chrono::{DateTime, Utc};
+use reqwest::header::{self, HeaderValue};
+use serde::{Deserialize, Serialize};
+
+mod request_types;
+
+const FRIENDS_API: &str = "https://friends.roblox.com/v1/users/";
+
+const FRIEND_REQUESTS_API: &str = "https://friends.roblox.com/v1/my/friends/requests";
+
+const ACCEPT_FRIEND_REQUEST_API_PART_2: &str = "/accept-friend-request";
+const DECLINE_FRIEND_REQUEST_API_PART_2: &str = "/decline-friend-request";
+const SEND_FRIEND_REQUEST_API_PART_2: &str = "/request-friendship";
+const UNFRIEND_API_PART_2: &str = "/unfriend";
+const FOLLOW_API_PART_2: &str = "/follow";
+const UNFOLLOW_API_PART_2: &str = "/unfollow";
+
+/// For follower and following lists, we use Descending as the sort order so that the
+/// newest followers come first.
+const FOLLOW_SORT_ORDER: &str = "Desc";
+
+/// The amount of friend requests grabbed per page by [`Client::friend_requests`].
+const FRIEND_REQUESTS_LIMIT: u64 = 100;
+
+/// A friend of a user. Retrieved from <https://friends.roblox.com/v1/users/{user_id}/friends>.
+#[allow(missing_docs)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct Friend {
+    pub user_id: UserId,
+    pub username: String,
+    pub display_name: String,
+    pub has_verified_badge: bool,
+}
+
+/// The type of presence of an online user (Online, InGame, InStudio).
+#[derive(
+    Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize, Copy,
+)]
+pub enum PresenceType {
+    /// The user is on the website or in the app, but not in an experience.
+    #[default]
+    Online,
+    /// The user is in an experience.
+    InGame,
+    /// The user is in Roblox Studio.
+    InStudio,
+}
+
+/// A friend that is currently online. Retrieved from <https://friends.roblox.com/v1/users/{user_id}/friends/online>.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct OnlineFriend {
+    /// The user id of the friend.
+    pub user_id: UserId,
+    /// The username of the friend.
+    pub username: String,
+    /// The display name of the friend.
+    pub display_name: String,
+    /// Whether the friend is on the website, in an experience, or in studio.
+    pub presence_type: PresenceType,
+    /// A description of where the friend is (such as "Website" or the name of an experience).
+    pub last_location: Option<String>,
+    /// The place the friend is in. Only exists if the friend is in an experience
+    /// that they allow the current account to join or see.
+    pub place_id: Option<PlaceId>,
+    /// The root place of the experience the friend is in.
+    pub root_place_id: Option<PlaceId>,
+    /// The universe (experience) the friend is in.
+    pub universe_id: Option<UniverseId>,
+}
+
+/// A pending friend request sent to the current account. Retrieved from
+/// <https://friends.roblox.com/v1/my/friends/requests>.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct FriendRequest {
+    /// The user id of the sender. Used for accepting or declining the request.
+    pub user_id: UserId,
+    /// The username of the sender.
+    pub username: String,
+    /// The display name of the sender.
+    pub display_name: String,
+    /// When the request was sent.
+    pub sent_at: DateTime<Utc>,
+    /// The usernames of friends the sender and the current account have in common.
+    pub mutual_friends: Vec<String>,
+}
+
+/// Why a user was recommended as a friend by [`Client::friend_recommendations`].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub enum RecommendationSource {
+    /// The user has friends in common with the current account.
+    MutualFriends,
+    /// The user is in the contacts of the current account.
+    Contacts,
+    /// The user frequently plays experiences with the current account.
+    Frequents,
+    /// Roblox did not give a reason for the recommendation.
+    #[default]
+    None,
+    /// A value not yet known to this library.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// A user recommended as a friend for the current account. Retrieved from
+/// <https://friends.roblox.com/v1/users/{user_id}/friends/recommendations>.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct FriendRecommendation {
+    /// The user id of the recommended user.
+    pub user_id: UserId,
+    /// The username of the recommended user.
+    pub username: String,
+    /// The display name of the recommended user.
+    pub display_name: String,
+    /// Whether the recommended user has a verified badge.
+    pub has_verified_badge: bool,
+    /// Why the user was recommended.
+    pub source: RecommendationSource,
+}
+
+/// A user in a follower or following list. Retrieved from
+/// <https://friends.roblox.com/v1/users/{user_id}/followers> and
+/// <https://friends.roblox.com/v1/users/{user_id}/followings>.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct FollowerEntry {
+    /// The user id of the user.
+    pub user_id: UserId,
+    /// The username of the user.
+    pub username: String,
+    /// The display name of the user.
+    pub display_name: String,
+    /// Whether the user has a verified badge.
+    pub has_verified_badge: bool,
+}
+
+impl Client {
+    /// Grabs the friends of a user from <https://friends.roblox.com/v1/users/{user_id}/friends>.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    /// * This endpoint is not paginated and returns all friends at once.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ids::UserId;
+    /// use roboat::ClientBuilder;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let user_id = UserId(2207291);
+    /// let friends = client.friends_list(user_id).await?;
+    ///
+    /// println!("Found {} friends.", friends.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn friends_list(&self, user_id: UserId) -> Result<Vec<Friend>, RoboatError> {
+        let formatted_url = format!("{}{}/friends", FRIENDS_API, user_id);
+
+        let cookie_string = self.cookie_string().unwrap_or(HeaderValue::from_static(""));
+
+        let request = self
+            .reqwest_client
+            .get(formatted_url)
+            .header(header::COOKIE, cookie_string);
+
+        let response = self.send_request(request).await?;
+        let raw = Self::parse_to_raw::<request_types::FriendsListResponse>(response).await?;
+
+        let friends = raw
+            .data
+            .into_iter()
+            .map(|friend| Friend {
+                user_id: friend.id,
+                username: friend.name,
+                display_name: friend.display_name,
+                has_verified_badge: friend.has_verified_badge,
+            })
+            .collect();
+
+        Ok(friends)
+    }
+
+    /// Grabs the friends of the current account that are online, along with their presence,
+    /// from <https://friends.roblox.com/v1/users/{user_id}/friends/online>.
+    ///
+    /// This is a single request, unlike combining [`Client::friends_list`] with a presence lookup.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let online_friends = client.friends_online().await?;
+    ///
+    /// for friend in online_friends {
+    ///     println!("{} is {:?}", friend.username, friend.presence_type);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn friends_online(&self) -> Result<Vec<OnlineFriend>, RoboatError> {
+        let user_id = self.user_id().await?;
+        let formatted_url = format!("{}{}/friends/online", FRIENDS_API, user_id);
+
+        let cookie_string = self.cookie_string()?;
+
+        let request = self
+            .reqwest_client
+            .get(formatted_url)
+            .header(header::COOKIE, cookie_string);
+
+        let response = self.send_request(request).await?;
+        let raw = Self::parse_to_raw::<request_types::OnlineFriendsResponse>(response).await?;
+
+        let online_friends = raw
+            .data
+            .into_iter()
+            .map(|friend| OnlineFriend {
+                user_id: friend.id,
+                username: friend.name,
+                display_name: friend.display_name,
+                presence_type: friend.user_presence.user_presence_type,
+                last_location: friend.user_presence.last_location,
+                place_id: friend.user_presence.place_id,
+                root_place_id: friend.user_presence.root_place_id,
+                universe_id: friend.user_presence.universe_id,
+            })
+            .collect();
+
+        Ok(online_friends)
+    }
+
+    /// Finds which friends of the current account own an asset. This is done by grabbing the
+    /// friends of the account using [`Client::friends_list`], and then checking whether each
+    /// friend owns the asset using [`Client::users_own_asset`].
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Up to 10 ownership checks are made at once.
+    ///
+    /// # Return Value Notes
+    /// * The successes are the friends that own the asset.
+    /// * A failed ownership check does not discard the other checks. The index of each
+    ///   success and failure is the index of the friend in [`Client::friends_list`].
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ids::AssetId;
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let asset_id = AssetId(1365767);
+    /// let result = client.friends_owning_asset(asset_id).await?;
+    ///
+    /// for (_, owner) in result.successes {
+    ///     println!("{} owns this item.", owner.username);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn friends_owning_asset(
+        &self,
+        asset_id: AssetId,
+    ) -> Result<BatchResult<Friend>, RoboatError> {
+        let user_id = self.user_id().await?;
+        let friends = self.friends_list(user_id).await?;
+
+        let user_ids = friends.iter().map(|friend| friend.user_id).collect();
+        let ownership_result = self.users_own_asset(user_ids, asset_id).await;
+
+        let successes = ownership_result
+            .successes
+            .into_iter()
+            .filter(|(_, owns_asset)| *owns_asset)
+            .map(|(i, _)| (i, friends[i].clone()))
+            .collect();
+
+        let owners = BatchResult {
+            successes,
+            failures: ownership_result.failures,
+        };
+
+        Ok(owners)
+    }
+
+    /// Grabs the users recommended as friends for the current account, along with why
+    /// each one was recommended, from
+    /// <https://friends.roblox.com/v1/users/{user_id}/friends/recommendations>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * This endpoint is not paginated and returns all recommendations at once.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::friends::RecommendationSource;
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let recommendations = client.friend_recommendations().await?;
+    ///
+    /// for recommendation in recommendations {
+    ///     if recommendation.source == RecommendationSource::MutualFriends {
+    ///         println!("{} has mutual friends.", recommendation.username);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn friend_recommendations(&self) -> Result<Vec<FriendRecommendation>, RoboatError> {
+        let user_id = self.user_id().await?;
+        let formatted_url = format!("{}{}/friends/recommendations", FRIENDS_API, user_id);
+
+        let cookie_string = self.cookie_string()?;
+
+        let request = self
+            .reqwest_client
+            .get(formatted_url)
+            .header(header::COOKIE, cookie_string);
+
+        let response = self.send_request(request).await?;
+        let raw =
+            Self::parse_to_raw::<request_types::FriendRecommendationsResponse>(response).await?;
+
+        let recommendations = raw
+            .data
+            .into_iter()
+            .map(|recommendation| FriendRecommendation {
+                user_id: recommendation.id,
+                username: recommendation.name,
+                display_name: recommendation.display_name,
+                has_verified_badge: recommendation.has_verified_badge,
+                source: recommendation.context_type,
+            })
+            .collect();
+
+        Ok(recommendations)
+    }
+
+    /// Grabs a page of pending friend requests sent to the current account from
+    /// <https://friends.roblox.com/v1/my/friends/requests>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Returns up to 100 requests per page, newest first.
+    ///
+    /// # Return Value Notes
+    /// * The first value is a vector of friend requests.
+    /// * The second value is the cursor for the next page of results. If there are no more pages, this will be `None`.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let (requests, next_cursor) = client.friend_requests(None).await?;
+    ///
+    /// for request in requests {
+    ///     println!("Friend request from {}", request.username);
+    /// }
+    ///
+    /// println!("Next Cursor: {}", next_cursor.unwrap_or_default());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn friend_requests(
+        &self,
+        cursor: Option<String>,
+    ) -> Result<(Vec<FriendRequest>, Option<String>), RoboatError> {
+        let formatted_url = format!(
+            "{}?limit={}&cursor={}",
+            FRIEND_REQUESTS_API,
+            FRIEND_REQUESTS_LIMIT,
+            cursor.unwrap_or_default()
+        );
+
+        let cookie_string = self.cookie_string()?;
+
+        let request = self
+            .reqwest_client
+            .get(formatted_url)
+            .header(header::COOKIE, cookie_string);
+
+        let response = self.send_request(request).await?;
+        let raw = Self::parse_to_raw::<request_types::FriendRequestsResponse>(response).await?;
+
+        let friend_requests = raw
+            .data
+            .into_iter()
+            .map(|request| FriendRequest {
+                user_id: request.id,
+                username: request.name,
+                display_name: request.display_name,
+                sent_at: request.friend_request.sent_at,
+                mutual_friends: request.mutual_friends_list,
+            })
+            .collect();
+
+        Ok((friend_requests, raw.next_page_cursor))
+    }
+
+    /// Accepts a friend request from a user using the endpoint
+    /// <https://friends.roblox.com/v1/users/{user_id}/accept-friend-request>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let (requests, _) = client.friend_requests(None).await?;
+    ///
+    /// for request in requests {
+    ///     client.accept_friend_request(request.user_id).await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn accept_friend_request(&self, user_id: UserId) -> Result<(), RoboatError> {
+        match self
+            .friend_action_internal(user_id, ACCEPT_FRIEND_REQUEST_API_PART_2)
+            .await
+        {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.friend_action_internal(user_id, ACCEPT_FRIEND_REQUEST_API_PART_2)
+                        .await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Declines a friend request from a user using the endpoint
+    /// <https://friends.roblox.com/v1/users/{user_id}/decline-friend-request>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ids::UserId;
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let user_id = UserId(2207291);
+    /// client.decline_friend_request(user_id).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn decline_friend_request(&self, user_id: UserId) -> Result<(), RoboatError> {
+        match self
+            .friend_action_internal(user_id, DECLINE_FRIEND_REQUEST_API_PART_2)
+            .await
+        {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.friend_action_internal(user_id, DECLINE_FRIEND_REQUEST_API_PART_2)
+                        .await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Sends a friend request to a user using the endpoint
+    /// <https://friends.roblox.com/v1/users/{user_id}/request-friendship>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    /// * [`RoboatError::ChallengeRequired`] - If Roblox wants a captcha to be completed first.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ids::UserId;
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let user_id = UserId(2207291);
+    /// client.send_friend_request(user_id).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn send_friend_request(&self, user_id: UserId) -> Result<(), RoboatError> {
+        match self.send_friend_request_internal(user_id).await {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.send_friend_request_internal(user_id).await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Removes a user from the friends of the current account using the endpoint
+    /// <https://friends.roblox.com/v1/users/{user_id}/unfriend>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ids::UserId;
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let user_id = UserId(2207291);
+    /// client.unfriend(user_id).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn unfriend(&self, user_id: UserId) -> Result<(), RoboatError> {
+        match self
+            .friend_action_internal(user_id, UNFRIEND_API_PART_2)
+            .await
+        {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.friend_action_internal(user_id, UNFRIEND_API_PART_2)
+                        .await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Grabs a page of the users following a user from
+    /// <https://friends.roblox.com/v1/users/{user_id}/followers>.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    /// * Followers are ordered newest to oldest.
+    ///
+    /// # Return Value Notes
+    /// * The first value is a vector of followers.
+    /// * The second value is the cursor for the next page of results. If there are no more pages, this will be `None`.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ids::UserId;
+    /// use roboat::ClientBuilder;
+    /// use roboat::Limit;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let user_id = UserId(2207291);
+    /// let (followers, next_cursor) = client.followers(user_id, Limit::Hundred, None).await?;
+    ///
+    /// for follower in followers {
+    ///     println!("{} follows this user.", follower.username);
+    /// }
+    ///
+    /// println!("Next Cursor: {}", next_cursor.unwrap_or_default());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn followers(
+        &self,
+        user_id: UserId,
+        limit: Limit,
+        cursor: Option<String>,
+    ) -> Result<(Vec<FollowerEntry>, Option<String>), RoboatError> {
+        self.follow_list(user_id, "followers", limit, cursor).await
+    }
+
+    /// Grabs a page of the users a user is following from
+    /// <https://friends.roblox.com/v1/users/{user_id}/followings>.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    /// * Followings are ordered newest to oldest.
+    ///
+    /// # Return Value Notes
+    /// * The first value is a vector of followed users.
+    /// * The second value is the cursor for the next page of results. If there are no more pages, this will be `None`.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ids::UserId;
+    /// use roboat::ClientBuilder;
+    /// use roboat::Limit;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let user_id = UserId(2207291);
+    /// let (followings, next_cursor) = client.followings(user_id, Limit::Hundred, None).await?;
+    ///
+    /// for following in followings {
+    ///     println!("This user follows {}.", following.username);
+    /// }
+    ///
+    /// println!("Next Cursor: {}", next_cursor.unwrap_or_default());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn followings(
+        &self,
+        user_id: UserId,
+        limit: Limit,
+        cursor: Option<String>,
+    ) -> Result<(Vec<FollowerEntry>, Option<String>), RoboatError> {
+        self.follow_list(user_id, "followings", limit, cursor).await
+    }
+
+    /// Follows a user using the endpoint <https://friends.roblox.com/v1/users/{user_id}/follow>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    /// * [`RoboatError::ChallengeRequired`] - If Roblox wants a captcha to be completed first.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ids::UserId;
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let user_id = UserId(2207291);
+    /// client.follow(user_id).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn follow(&self, user_id: UserId) -> Result<(), RoboatError> {
+        match self.follow_internal(user_id).await {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.follow_internal(user_id).await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Unfollows a user using the endpoint <https://friends.roblox.com/v1/users/{user_id}/unfollow>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ids::UserId;
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let user_id = UserId(2207291);
+    /// client.unfollow(user_id).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn unfollow(&self, user_id: UserId) -> Result<(), RoboatError> {
+        match self
+            .friend_action_internal(user_id, UNFOLLOW_API_PART_2)
+            .await
+        {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.friend_action_internal(user_id, UNFOLLOW_API_PART_2)
+                        .await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Used by [`Client::followers`] and [`Client::followings`], which only differ in `list_name`.
+    async fn follow_list(
+        &self,
+        user_id: UserId,
+        list_name: &str,
+        limit: Limit,
+        cursor: Option<String>,
+    ) -> Result<(Vec<FollowerEntry>, Option<String>), RoboatError> {
+        let formatted_url = format!(
+            "{}{}/{}?sortOrder={}&limit={}&cursor={}",
+            FRIENDS_API,
+            user_id,
+            list_name,
+            FOLLOW_SORT_ORDER,
+            limit.to_u64(),
+            cursor.unwrap_or_default()
+        );
+
+        let cookie_string = self.cookie_string().unwrap_or(HeaderValue::from_static(""));
+
+        let request = self
+            .reqwest_client
+            .get(formatted_url)
+            .header(header::COOKIE, cookie_string);
+
+        let response = self.send_request(request).await?;
+        let raw = Self::parse_to_raw::<request_types::FollowListResponse>(response).await?;
+
+        let entries = raw
+            .data
+            .into_iter()
+            .map(|user| FollowerEntry {
+                user_id: user.id,
+                username: user.name,
+                display_name: user.display_name,
+                has_verified_badge: user.has_verified_badge,
+            })
+            .collect();
+
+        Ok((entries, raw.next_page_cursor))
+    }
+}
+
+mod internal {
+    use super::{request_types, FOLLOW_API_PART_2, FRIENDS_API, SEND_FRIEND_REQUEST_API_PART_2};
+    use crate::ids::UserId;
+    use crate::{Client, RoboatError, XCSRF_HEADER};
+    use reqwest::{header, RequestBuilder};
+
+    /// The challenge type used when Roblox wants a captcha before a friend request is sent.
+    const CAPTCHA_CHALLENGE: &str = "captcha";
+
+    impl Client {
+        /// Sends a request to an endpoint that acts on another user and has no body,
+        /// such as <https://friends.roblox.com/v1/users/{user_id}/unfriend>.
+        pub(super) async fn friend_action_internal(
+            &self,
+            user_id: UserId,
+            api_part_2: &str,
+        ) -> Result<(), RoboatError> {
+            let formatted_url = format!("{}{}{}", FRIENDS_API, user_id, api_part_2);
+
+            let cookie = self.cookie_string()?;
+
+            let request = self
+                .reqwest_client
+                .post(formatted_url)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, self.xcsrf().await);
+
+            let _ = self
+                .send_mutating_request(request, serde_json::json!({}))
+                .await?;
+
+            // We don't need to do anything, we just need a 200 status code.
+
+            Ok(())
+        }
+
+        pub(super) async fn send_friend_request_internal(
+            &self,
+            user_id: UserId,
+        ) -> Result<(), RoboatError> {
+            let formatted_url = format!(
+                "{}{}{}",
+                FRIENDS_API, user_id, SEND_FRIEND_REQUEST_API_PART_2
+            );
+
+            let cookie = self.cookie_string()?;
+
+            let body = request_types::SendFriendRequestReqBody {
+                friendship_origin_source_type: "Unknown".to_string(),
+            };
+
+            let request = self
+                .reqwest_client
+                .post(formatted_url)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, self.xcsrf().await)
+                .json(&body);
+
+            self.send_captcha_checked_request(request).await
+        }
+
+        pub(super) async fn follow_internal(&self, user_id: UserId) -> Result<(), RoboatError> {
+            let formatted_url = format!("{}{}{}", FRIENDS_API, user_id, FOLLOW_API_PART_2);
+
+            let cookie = self.cookie_string()?;
+
+            let request = self
+                .reqwest_client
+                .post(formatted_url)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, self.xcsrf().await);
+
+            self.send_captcha_checked_request(request).await
+        }
+
+        /// Sends a request to an endpoint that reports whether a captcha is required
+        /// (such as sending a friend request or following a user) instead of failing.
+        async fn send_captcha_checked_request(
+            &self,
+            request: RequestBuilder,
+        ) -> Result<(), RoboatError> {
+            let response = self
+                .send_mutating_request(
+                    request,
+                    serde_json::json!({ "success": true, "isCaptchaRequired": false }),
+                )
+                .await?;
+
+            let raw = Self::parse_to_raw::<request_types::CaptchaCheckedResponse>(response).await?;
+
+            if raw.is_captcha_required {
+                return Err(RoboatError::ChallengeRequired(
+                    CAPTCHA_CHALLENGE.to_string(),
+                ));
+            }
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use super::{PresenceType, RecommendationSource};
+use crate::ids::{PlaceId, UniverseId, UserId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+pub(super) struct FriendsListResponse {
+    pub data: Vec<FriendRaw>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct FriendRaw {
+    pub id: UserId,
+    pub name: String,
+    pub display_name: String,
+    pub has_verified_badge: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(super) struct OnlineFriendsResponse {
+    pub data: Vec<OnlineFriendRaw>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct OnlineFriendRaw {
+    pub id: UserId,
+    pub name: String,
+    pub display_name: String,
+    pub user_presence: UserPresenceRaw,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct UserPresenceRaw {
+    #[serde(rename = "UserPresenceType")]
+    pub user_presence_type: PresenceType,
+    pub last_location: Option<String>,
+    pub place_id: Option<PlaceId>,
+    pub root_place_id: Option<PlaceId>,
+    pub universe_id: Option<UniverseId>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct FriendRequestsResponse {
+    pub previous_page_cursor: Option<String>,
+    pub next_page_cursor: Option<String>,
+    pub data: Vec<FriendRequestRaw>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct FriendRequestRaw {
+    pub id: UserId,
+    pub name: String,
+    pub display_name: String,
+    pub friend_request: FriendRequestInfoRaw,
+    #[serde(default)]
+    pub mutual_friends_list: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct FriendRequestInfoRaw {
+    pub sent_at: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct SendFriendRequestReqBody {
+    pub friendship_origin_source_type: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct CaptchaCheckedResponse {
+    pub success: bool,
+    pub is_captcha_required: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct FollowListResponse {
+    pub previous_page_cursor: Option<String>,
+    pub next_page_cursor: Option<String>,
+    pub data: Vec<FriendRaw>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(super) struct FriendRecommendationsResponse {
+    pub data: Vec<FriendRecommendationRaw>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct FriendRecommendationRaw {
+    pub id: UserId,
+    pub name: String,
+    pub display_name: String,
+    #[serde(default)]
+    pub has_verified_badge: bool,
+    #[serde(default)]
+    pub context_type: RecommendationSource,
+}
@@ -0,0 +1,96 @@
+use crate::ids::{PlaceId, UniverseId};
+use crate::{Client, RoboatError};
+use reqwest::header::{self, HeaderValue};
+use serde::{Deserialize, Serialize};
+
+mod request_types;
+
+const GAMES_API: &str = "https://games.roblox.com/v1/games";
+
+/// The current stats of a universe (game). Retrieved from <https://games.roblox.com/v1/games?universeIds={universe_ids}>.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct UniverseStats {
+    /// The id of the universe.
+    pub universe_id: UniverseId,
+    /// The id of the start place of the universe.
+    pub root_place_id: PlaceId,
+    /// The name of the universe.
+    pub name: String,
+    /// The amount of players currently in the universe (the CCU).
+    pub playing: u64,
+    /// The total amount of visits the universe has.
+    pub visits: u64,
+    /// The maximum amount of players in a server.
+    pub max_players: u64,
+    /// The amount of users that have favorited the universe.
+    pub favorited_count: u64,
+}
+
+impl Client {
+    /// Grabs the current stats (player count, visits, favorites) of one or more universes
+    /// from <https://games.roblox.com/v1/games?universeIds={universe_ids}>.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    /// * This endpoint will accept up to 50 universe ids at a time.
+    ///
+    /// # Return Value Notes
+    /// * Universes that do not exist are left out of the returned list.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ids::UniverseId;
+    /// use roboat::ClientBuilder;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let universe_ids = vec![UniverseId(1686885941)];
+    /// let stats = client.universe_stats(universe_ids).await?;
+    ///
+    /// for universe in stats {
+    ///     println!("{} has {} players.", universe.name, universe.playing);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn universe_stats(
+        &self,
+        universe_ids: Vec<UniverseId>,
+    ) -> Result<Vec<UniverseStats>, RoboatError> {
+        let formatted_universe_ids = universe_ids
+            .iter()
+            .map(|id| id.to_string())
+            .collect::<Vec<String>>()
+            .join(",");
+
+        let formatted_url = format!("{}?universeIds={}", GAMES_API, formatted_universe_ids);
+
+        let cookie_string = self.cookie_string().unwrap_or(HeaderValue::from_static(""));
+
+        let request = self
+            .reqwest_client
+            .get(formatted_url)
+            .header(header::COOKIE, cookie_string);
+
+        let response = self.send_request(request).await?;
+        let raw = Self::parse_to_raw::<request_types::GamesResponse>(response).await?;
+
+        let stats = raw
+            .data
+            .into_iter()
+            .map(|game| UniverseStats {
+                universe_id: game.id,
+                root_place_id: game.root_place_id,
+                name: game.name,
+                playing: game.playing.unwrap_or_default(),
+                visits: game.visits,
+                max_players: game.max_players,
+                favorited_count: game.favorited_count,
+            })
+            .collect();
+
+        Ok(stats)
+    }
+}
@@ -0,0 +1,19 @@
+use crate::ids::{PlaceId, UniverseId};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+pub(super) struct GamesResponse {
+    pub data: Vec<GameRaw>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct GameRaw {
+    pub id: UniverseId,
+    pub root_place_id: PlaceId,
+    pub name: String,
+    pub playing: Option<u64>,
+    pub visits: u64,
+    pub max_players: u64,
+    pub favorited_count: u64,
+}
@@ -0,0 +1,2142 @@
+use crate::crash::{guarded_watcher, WatcherState};
+use crate::ids::UserId;
+use crate::polling::{PollInterval, PollOutcome};
+use crate::thumbnails::{
+    ThumbnailArgs, ThumbnailFormat, ThumbnailSize, ThumbnailState, ThumbnailType,
+};
+use crate::{Client, Limit, RoboatError};
+use chrono::{DateTime, Utc};
+use futures_util::stream::BoxStream;
+use reqwest::header::{self, HeaderValue};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+mod request_types;
+
+const GROUPS_API: &str = "https://groups.roblox.com/v1/groups/";
+
+/// The amount of join requests grabbed per page by [`Client::join_requests`].
+const JOIN_REQUESTS_LIMIT: u64 = 100;
+
+/// The maximum amount of member pages [`Client::recent_group_joins`] will go through
+/// before giving up on finding the cutoff member.
+const MAX_RECENT_JOIN_PAGES: usize = 10;
+
+/// The type of relationship between two groups (Allies or Enemies).
+#[derive(
+    Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize, Copy,
+)]
+#[allow(missing_docs)]
+pub enum GroupRelationshipType {
+    #[default]
+    Allies,
+    Enemies,
+}
+
+impl GroupRelationshipType {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Allies => "allies",
+            Self::Enemies => "enemies",
+        }
+    }
+}
+
+/// Custom Roblox errors that occur when paying out group funds using [`Client::group_payout`].
+#[derive(
+    thiserror::Error,
+    Debug,
+    Default,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize,
+)]
+pub enum GroupPayoutError {
+    /// Thrown when the group does not have enough available robux for the payout.
+    /// Pending robux cannot be paid out (see [`Client::group_funds`]).
+    #[default]
+    #[error("Insufficient Funds")]
+    InsufficientFunds,
+    /// Thrown when a recipient cannot receive a payout, such as when they are not a member
+    /// of the group or have not been a member for long enough.
+    #[error("Recipient Not Eligible")]
+    RecipientNotEligible,
+}
+
+/// The owner of a group.
+#[allow(missing_docs)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct GroupOwner {
+    pub user_id: UserId,
+    pub username: String,
+    pub display_name: String,
+    pub has_verified_badge: bool,
+}
+
+/// The details of a group. Retrieved from <https://groups.roblox.com/v1/groups/{group_id}>.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct Group {
+    /// The id of the group.
+    pub group_id: u64,
+    /// The name of the group.
+    pub name: String,
+    /// The description of the group.
+    pub description: String,
+    /// The owner of the group. This is `None` if the group has no owner.
+    pub owner: Option<GroupOwner>,
+    /// The current shout of the group. This is `None` if the group has no shout, or if
+    /// the current account cannot see it.
+    pub shout: Option<GroupShout>,
+    /// The amount of members in the group.
+    pub member_count: u64,
+    /// Whether anyone can join the group without a join request.
+    pub public_entry_allowed: bool,
+    /// Whether the group has been locked by Roblox.
+    pub is_locked: bool,
+    /// Whether the group is verified by Roblox.
+    pub has_verified_badge: bool,
+}
+
+/// The shout of a group, shown at the top of the group page.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct GroupShout {
+    /// The text of the shout.
+    pub body: String,
+    /// The member that posted the shout. Roblox describes them with the same fields as a
+    /// [`GroupOwner`]. This is `None` if the poster's account no longer exists.
+    pub poster: Option<GroupOwner>,
+    /// When the shout was first posted.
+    pub created: DateTime<Utc>,
+    /// When the shout was last changed.
+    pub updated: DateTime<Utc>,
+}
+
+/// A group that is an ally or enemy of another group. Retrieved from
+/// <https://groups.roblox.com/v1/groups/{group_id}/relationships/{relationship_type}>.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct RelatedGroup {
+    /// The id of the related group.
+    pub group_id: u64,
+    /// The name of the related group.
+    pub name: String,
+    /// The description of the related group.
+    pub description: String,
+    /// The owner of the related group. This is `None` if the group has no owner.
+    pub owner: Option<GroupOwner>,
+    /// The amount of members in the related group.
+    pub member_count: u64,
+    /// Whether anyone can join the related group without a join request.
+    pub public_entry_allowed: bool,
+    /// Whether the related group is verified by Roblox.
+    pub has_verified_badge: bool,
+}
+
+/// A role in a group. Retrieved from <https://groups.roblox.com/v1/groups/{group_id}/roles>.
+///
+/// Roles are ordered by their rank first, meaning that sorting a `Vec<GroupRole>`
+/// will put the lowest ranked role first, and comparisons such as `role_a > role_b`
+/// can be used to check which role is higher up in the group.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct GroupRole {
+    /// The rank of the role, from 0 to 255. The owner role always has a rank of 255,
+    /// and the guest role always has a rank of 0.
+    pub rank: u8,
+    /// The id of the role. This is what is used when changing the role of a member.
+    pub role_id: u64,
+    /// The name of the role.
+    pub name: String,
+    /// The amount of members with the role. This is `None` if Roblox did not
+    /// include it in the response (such as for the guest role).
+    pub member_count: Option<u64>,
+}
+
+impl GroupRole {
+    /// Returns whether this role is ranked above `other`.
+    ///
+    /// # Example
+    /// ```
+    /// use roboat::groups::GroupRole;
+    ///
+    /// let member = GroupRole {
+    ///     rank: 1,
+    ///     role_id: 1234,
+    ///     name: "Member".to_string(),
+    ///     member_count: Some(100),
+    /// };
+    ///
+    /// let owner = GroupRole {
+    ///     rank: 255,
+    ///     role_id: 5678,
+    ///     name: "Owner".to_string(),
+    ///     member_count: Some(1),
+    /// };
+    ///
+    /// assert!(owner.is_above(&member));
+    /// assert!(!member.is_above(&owner));
+    /// ```
+    pub fn is_above(&self, other: &GroupRole) -> bool {
+        self.rank > other.rank
+    }
+
+    /// Returns whether this role is the owner role of the group.
+    pub fn is_owner(&self) -> bool {
+        self.rank == u8::MAX
+    }
+
+    /// Returns whether this role is the guest role of the group (used for non-members).
+    pub fn is_guest(&self) -> bool {
+        self.rank == 0
+    }
+}
+
+/// A member of a group. Retrieved from <https://groups.roblox.com/v1/groups/{group_id}/users>.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct GroupMember {
+    /// The id of the user.
+    pub user_id: UserId,
+    /// The username of the user.
+    pub username: String,
+    /// The display name of the user.
+    pub display_name: String,
+    /// Whether the user is verified by Roblox.
+    pub has_verified_badge: bool,
+    /// The role of the user in the group. The member count of the role is always `None`.
+    pub role: GroupRole,
+}
+
+/// A pending request to join a group. Retrieved from
+/// <https://groups.roblox.com/v1/groups/{group_id}/join-requests>.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct JoinRequest {
+    /// The id of the user that wants to join. Used for accepting or declining the request.
+    pub user_id: UserId,
+    /// The username of the user.
+    pub username: String,
+    /// The display name of the user.
+    pub display_name: String,
+    /// Whether the user is verified by Roblox.
+    pub has_verified_badge: bool,
+    /// When the request was sent.
+    pub created: DateTime<Utc>,
+}
+
+/// A user banned from a group. Retrieved from
+/// <https://groups.roblox.com/v1/groups/{group_id}/bans>.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct GroupBan {
+    /// The id of the banned user. Used for unbanning the user.
+    pub user_id: UserId,
+    /// The username of the banned user.
+    pub username: String,
+    /// The display name of the banned user.
+    pub display_name: String,
+    /// The id of the member that banned the user, if Roblox includes it.
+    pub banned_by_user_id: Option<UserId>,
+    /// The username of the member that banned the user, if Roblox includes it.
+    pub banned_by_username: Option<String>,
+    /// When the user was banned.
+    pub created: DateTime<Utc>,
+}
+
+/// The type of an action in the audit log of a group. Used in [`AuditLogEntry::action_type`]
+/// and to filter [`Client::group_audit_log`].
+///
+/// Roblox sends these with spaces (such as "Change Rank"), while the filter uses the
+/// name without spaces (such as "ChangeRank"). Both are accepted when deserializing.
+///
+/// # Example
+/// ```
+/// use roboat::groups::AuditAction;
+///
+/// let action: AuditAction = serde_json::from_str("\"Change Rank\"").unwrap();
+///
+/// assert_eq!(action, AuditAction::ChangeRank);
+/// assert_eq!(action.as_str(), "ChangeRank");
+/// ```
+#[allow(missing_docs)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub enum AuditAction {
+    #[default]
+    #[serde(alias = "Delete Post")]
+    DeletePost,
+    #[serde(alias = "Remove Member")]
+    RemoveMember,
+    #[serde(alias = "Accept Join Request")]
+    AcceptJoinRequest,
+    #[serde(alias = "Decline Join Request")]
+    DeclineJoinRequest,
+    #[serde(alias = "Post Status")]
+    PostStatus,
+    #[serde(alias = "Change Rank")]
+    ChangeRank,
+    #[serde(alias = "Buy Ad")]
+    BuyAd,
+    #[serde(alias = "Send Ally Request")]
+    SendAllyRequest,
+    #[serde(alias = "Create Enemy")]
+    CreateEnemy,
+    #[serde(alias = "Accept Ally Request")]
+    AcceptAllyRequest,
+    #[serde(alias = "Decline Ally Request")]
+    DeclineAllyRequest,
+    #[serde(alias = "Delete Ally")]
+    DeleteAlly,
+    #[serde(alias = "Delete Enemy")]
+    DeleteEnemy,
+    #[serde(alias = "Add Group Place")]
+    AddGroupPlace,
+    #[serde(alias = "Remove Group Place")]
+    RemoveGroupPlace,
+    #[serde(alias = "Create Items")]
+    CreateItems,
+    #[serde(alias = "Configure Items")]
+    ConfigureItems,
+    #[serde(alias = "Spend Group Funds")]
+    SpendGroupFunds,
+    #[serde(alias = "Change Owner")]
+    ChangeOwner,
+    Delete,
+    #[serde(alias = "Adjust Currency Amounts")]
+    AdjustCurrencyAmounts,
+    Abandon,
+    Claim,
+    Rename,
+    #[serde(alias = "Change Description")]
+    ChangeDescription,
+    #[serde(alias = "Create Group Asset")]
+    CreateGroupAsset,
+    #[serde(alias = "Update Group Asset")]
+    UpdateGroupAsset,
+    #[serde(alias = "Configure Group Asset")]
+    ConfigureGroupAsset,
+    #[serde(alias = "Revert Group Asset")]
+    RevertGroupAsset,
+    #[serde(alias = "Create Group Developer Product")]
+    CreateGroupDeveloperProduct,
+    #[serde(alias = "Configure Group Game")]
+    ConfigureGroupGame,
+    Lock,
+    Unlock,
+    #[serde(alias = "Create Game Pass")]
+    CreateGamePass,
+    #[serde(alias = "Create Badge")]
+    CreateBadge,
+    #[serde(alias = "Configure Badge")]
+    ConfigureBadge,
+    #[serde(alias = "Save Place")]
+    SavePlace,
+    #[serde(alias = "Publish Place")]
+    PublishPlace,
+    #[serde(alias = "Update Roleset Rank")]
+    UpdateRolesetRank,
+    #[serde(alias = "Update Roleset Data")]
+    UpdateRolesetData,
+    /// A value not yet known to this library.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl AuditAction {
+    /// Returns the name of the action as used by the `actionType` filter of the audit log.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::DeletePost => "DeletePost",
+            Self::RemoveMember => "RemoveMember",
+            Self::AcceptJoinRequest => "AcceptJoinRequest",
+            Self::DeclineJoinRequest => "DeclineJoinRequest",
+            Self::PostStatus => "PostStatus",
+            Self::ChangeRank => "ChangeRank",
+            Self::BuyAd => "BuyAd",
+            Self::SendAllyRequest => "SendAllyRequest",
+            Self::CreateEnemy => "CreateEnemy",
+            Self::AcceptAllyRequest => "AcceptAllyRequest",
+            Self::DeclineAllyRequest => "DeclineAllyRequest",
+            Self::DeleteAlly => "DeleteAlly",
+            Self::DeleteEnemy => "DeleteEnemy",
+            Self::AddGroupPlace => "AddGroupPlace",
+            Self::RemoveGroupPlace => "RemoveGroupPlace",
+            Self::CreateItems => "CreateItems",
+            Self::ConfigureItems => "ConfigureItems",
+            Self::SpendGroupFunds => "SpendGroupFunds",
+            Self::ChangeOwner => "ChangeOwner",
+            Self::Delete => "Delete",
+            Self::AdjustCurrencyAmounts => "AdjustCurrencyAmounts",
+            Self::Abandon => "Abandon",
+            Self::Claim => "Claim",
+            Self::Rename => "Rename",
+            Self::ChangeDescription => "ChangeDescription",
+            Self::CreateGroupAsset => "CreateGroupAsset",
+            Self::UpdateGroupAsset => "UpdateGroupAsset",
+            Self::ConfigureGroupAsset => "ConfigureGroupAsset",
+            Self::RevertGroupAsset => "RevertGroupAsset",
+            Self::CreateGroupDeveloperProduct => "CreateGroupDeveloperProduct",
+            Self::ConfigureGroupGame => "ConfigureGroupGame",
+            Self::Lock => "Lock",
+            Self::Unlock => "Unlock",
+            Self::CreateGamePass => "CreateGamePass",
+            Self::CreateBadge => "CreateBadge",
+            Self::ConfigureBadge => "ConfigureBadge",
+            Self::SavePlace => "SavePlace",
+            Self::PublishPlace => "PublishPlace",
+            Self::UpdateRolesetRank => "UpdateRolesetRank",
+            Self::UpdateRolesetData => "UpdateRolesetData",
+            Self::Unknown(action) => action,
+        }
+    }
+}
+
+impl std::fmt::Display for AuditAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// An action taken in a group, such as changing the rank of a member or paying out robux.
+/// Retrieved from <https://groups.roblox.com/v1/groups/{group_id}/audit-log>.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct AuditLogEntry {
+    /// The member that took the action. The member count of their role is always `None`.
+    pub actor: GroupMember,
+    /// The type of action (such as [`AuditAction::ChangeRank`] or [`AuditAction::SpendGroupFunds`]).
+    pub action_type: AuditAction,
+    /// The details of the action as a JSON string. The fields depend on the action type
+    /// (for example, a rank change includes the target user and the old and new roles).
+    pub description: String,
+    /// When the action was taken.
+    pub created: DateTime<Utc>,
+}
+
+/// The member count of a group at a point in time, and how it changed since the previous
+/// poll. Created by [`Client::watch_group_growth`].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct GroupGrowth {
+    /// The amount of members in the group.
+    pub member_count: u64,
+    /// How many members were gained (or lost, if negative) since the previous poll.
+    pub change: i64,
+    /// When the member count was polled.
+    pub timestamp: DateTime<Utc>,
+}
+
+/// The state kept between polls by [`Client::watch_group_growth`].
+struct GroupGrowthWatcher<'a> {
+    client: &'a Client,
+    group_id: u64,
+    interval: PollInterval,
+    /// Whether the first poll has happened. The first poll happens immediately.
+    polled: bool,
+    /// The member count from the latest successful poll. `None` until the first poll has finished.
+    last_member_count: Option<u64>,
+}
+
+/// The state kept between polls by [`Client::watch_group_audit_log`].
+struct AuditLogWatcher<'a> {
+    client: &'a Client,
+    group_id: u64,
+    interval: PollInterval,
+    /// Whether the first poll has happened. The first poll happens immediately.
+    polled: bool,
+    /// The entries from the latest poll that have not been yielded yet, oldest first.
+    pending: VecDeque<AuditLogEntry>,
+    /// The newest entries seen so far. `None` until the first poll has finished.
+    newest_seen: Option<Vec<AuditLogEntry>>,
+}
+
+impl WatcherState for GroupGrowthWatcher<'_> {
+    const NAME: &'static str = "watch_group_growth";
+
+    fn snapshot(&self) -> serde_json::Value {
+        serde_json::json!({
+            "group_id": self.group_id,
+            "last_member_count": self.last_member_count,
+        })
+    }
+}
+
+impl WatcherState for AuditLogWatcher<'_> {
+    const NAME: &'static str = "watch_group_audit_log";
+
+    fn snapshot(&self) -> serde_json::Value {
+        serde_json::json!({
+            "group_id": self.group_id,
+            "pending": self.pending,
+            "newest_seen": self.newest_seen,
+        })
+    }
+}
+
+impl Client {
+    /// Grabs the details of a group (such as its owner, shout, and member count) from
+    /// <https://groups.roblox.com/v1/groups/{group_id}>.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let group_id = 1200769;
+    /// let group = client.group_details(group_id).await?;
+    ///
+    /// println!("{} has {} members.", group.name, group.member_count);
+    ///
+    /// if let Some(shout) = group.shout {
+    ///     println!("Shout: {}", shout.body);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn group_details(&self, group_id: u64) -> Result<Group, RoboatError> {
+        let formatted_url = format!("{}{}", GROUPS_API, group_id);
+
+        let cookie_string = self.cookie_string().unwrap_or(HeaderValue::from_static(""));
+
+        let request = self
+            .reqwest_client
+            .get(formatted_url)
+            .header(header::COOKIE, cookie_string);
+
+        let response = self.send_request(request).await?;
+        let raw = Self::parse_to_raw::<request_types::GroupInfoResponse>(response).await?;
+
+        let shout = raw.shout.map(|shout| GroupShout {
+            body: shout.body,
+            poster: shout.poster.map(group_owner),
+            created: shout.created,
+            updated: shout.updated,
+        });
+
+        Ok(Group {
+            group_id: raw.id,
+            name: raw.name,
+            description: raw.description,
+            owner: raw.owner.map(group_owner),
+            shout,
+            member_count: raw.member_count,
+            public_entry_allowed: raw.public_entry_allowed,
+            is_locked: raw.is_locked,
+            has_verified_badge: raw.has_verified_badge,
+        })
+    }
+
+    /// Grabs the roles of a group from <https://groups.roblox.com/v1/groups/{group_id}/roles>.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    ///
+    /// # Return Value Notes
+    /// * Roles are sorted from the lowest rank to the highest rank.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let group_id = 1200769;
+    /// let roles = client.group_roles(group_id).await?;
+    ///
+    /// for role in roles {
+    ///     println!("{} (Rank {}): {:?} members", role.name, role.rank, role.member_count);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn group_roles(&self, group_id: u64) -> Result<Vec<GroupRole>, RoboatError> {
+        let formatted_url = format!("{}{}/roles", GROUPS_API, group_id);
+
+        let cookie_string = self.cookie_string().unwrap_or(HeaderValue::from_static(""));
+
+        let request = self
+            .reqwest_client
+            .get(formatted_url)
+            .header(header::COOKIE, cookie_string);
+
+        let response = self.send_request(request).await?;
+        let raw = Self::parse_to_raw::<request_types::GroupRolesResponse>(response).await?;
+
+        let mut roles = raw
+            .roles
+            .into_iter()
+            .map(|role| GroupRole {
+                rank: role.rank,
+                role_id: role.id,
+                name: role.name,
+                member_count: role.member_count,
+            })
+            .collect::<Vec<_>>();
+
+        roles.sort();
+
+        Ok(roles)
+    }
+
+    /// Grabs the allies of a group from
+    /// <https://groups.roblox.com/v1/groups/{group_id}/relationships/allies>.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    ///
+    /// # Argument Notes
+    /// * This endpoint uses a row index instead of a string cursor. If you want the
+    ///   starting page, use `None`.
+    ///
+    /// # Return Value Notes
+    /// * The first value is a vector of allied groups.
+    /// * The second value is the row index of the next page of results. If there are no more pages, this will be `None`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    /// use roboat::Limit;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let group_id = 1200769;
+    /// let limit = Limit::Ten;
+    /// let cursor = None;
+    ///
+    /// let (allies, next_cursor) = client.group_allies(group_id, limit, cursor).await?;
+    ///
+    /// for ally in allies {
+    ///     println!("Ally: {} ({})", ally.name, ally.group_id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn group_allies(
+        &self,
+        group_id: u64,
+        limit: Limit,
+        cursor: Option<u64>,
+    ) -> Result<(Vec<RelatedGroup>, Option<u64>), RoboatError> {
+        self.group_relationships(group_id, GroupRelationshipType::Allies, limit, cursor)
+            .await
+    }
+
+    /// Grabs the enemies of a group from
+    /// <https://groups.roblox.com/v1/groups/{group_id}/relationships/enemies>.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    ///
+    /// # Argument Notes
+    /// * This endpoint uses a row index instead of a string cursor. If you want the
+    ///   starting page, use `None`.
+    ///
+    /// # Return Value Notes
+    /// * The first value is a vector of enemy groups.
+    /// * The second value is the row index of the next page of results. If there are no more pages, this will be `None`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    /// use roboat::Limit;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let group_id = 1200769;
+    /// let limit = Limit::Ten;
+    /// let cursor = None;
+    ///
+    /// let (enemies, next_cursor) = client.group_enemies(group_id, limit, cursor).await?;
+    ///
+    /// for enemy in enemies {
+    ///     println!("Enemy: {} ({})", enemy.name, enemy.group_id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn group_enemies(
+        &self,
+        group_id: u64,
+        limit: Limit,
+        cursor: Option<u64>,
+    ) -> Result<(Vec<RelatedGroup>, Option<u64>), RoboatError> {
+        self.group_relationships(group_id, GroupRelationshipType::Enemies, limit, cursor)
+            .await
+    }
+
+    /// Sends an ally request to, or declares an enemy of, another group using
+    /// <https://groups.roblox.com/v1/groups/{group_id}/relationships/{relationship_type}/{related_group_id}>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Requires the account to have permission to manage relationships of the group.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    ///
+    /// # Return Value Notes
+    /// * Will return `Ok(())` if the relationship was successfully requested.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    /// use roboat::groups::GroupRelationshipType;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let group_id = 1200769;
+    /// let related_group_id = 7;
+    ///
+    /// client
+    ///     .add_group_relationship(group_id, GroupRelationshipType::Allies, related_group_id)
+    ///     .await?;
+    ///
+    /// println!("Sent ally request!");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn add_group_relationship(
+        &self,
+        group_id: u64,
+        relationship_type: GroupRelationshipType,
+        related_group_id: u64,
+    ) -> Result<(), RoboatError> {
+        match self
+            .add_group_relationship_internal(group_id, relationship_type, related_group_id)
+            .await
+        {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.add_group_relationship_internal(
+                        group_id,
+                        relationship_type,
+                        related_group_id,
+                    )
+                    .await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Removes an ally or enemy of a group using
+    /// <https://groups.roblox.com/v1/groups/{group_id}/relationships/{relationship_type}/{related_group_id}>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Requires the account to have permission to manage relationships of the group.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    ///
+    /// # Return Value Notes
+    /// * Will return `Ok(())` if the relationship was successfully removed.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    /// use roboat::groups::GroupRelationshipType;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let group_id = 1200769;
+    /// let related_group_id = 7;
+    ///
+    /// client
+    ///     .remove_group_relationship(group_id, GroupRelationshipType::Enemies, related_group_id)
+    ///     .await?;
+    ///
+    /// println!("Removed enemy!");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn remove_group_relationship(
+        &self,
+        group_id: u64,
+        relationship_type: GroupRelationshipType,
+        related_group_id: u64,
+    ) -> Result<(), RoboatError> {
+        match self
+            .remove_group_relationship_internal(group_id, relationship_type, related_group_id)
+            .await
+        {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.remove_group_relationship_internal(
+                        group_id,
+                        relationship_type,
+                        related_group_id,
+                    )
+                    .await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Grabs the members that joined a group after the member `since`, using
+    /// <https://groups.roblox.com/v1/groups/{group_id}/users?sortOrder=Desc>.
+    ///
+    /// Roblox does not expose join dates, so the cutoff is the user id of a member instead
+    /// (usually the newest member returned by the previous call). This is meant for
+    /// welcome bots that poll for new members without crawling the whole member list.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    /// * Will go through at most 10 pages (1000 members). If `since` is not found by then
+    ///   (for example, if that member left the group), the members found so far are returned.
+    ///
+    /// # Argument Notes
+    /// * `since` is the user id of the newest member that has already been handled. If `None`,
+    ///   only the first page of newest members is returned.
+    ///
+    /// # Return Value Notes
+    /// * Members are sorted newest first, so the first member should be used as `since` in the next call.
+    /// * The member `since` is not included.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let group_id = 1200769;
+    ///
+    /// let members = client.recent_group_joins(group_id, None).await?;
+    /// let newest_member_id = members.first().map(|member| member.user_id);
+    ///
+    /// // Later on...
+    /// let new_members = client.recent_group_joins(group_id, newest_member_id).await?;
+    ///
+    /// for member in new_members {
+    ///     println!("Welcome, {}!", member.display_name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn recent_group_joins(
+        &self,
+        group_id: u64,
+        since: Option<UserId>,
+    ) -> Result<Vec<GroupMember>, RoboatError> {
+        let mut members = Vec::new();
+        let mut cursor = None;
+
+        for _ in 0..MAX_RECENT_JOIN_PAGES {
+            let (page, next_cursor) = self
+                .group_members_newest_first(group_id, Limit::Hundred, cursor)
+                .await?;
+
+            for member in page {
+                if Some(member.user_id) == since {
+                    return Ok(members);
+                }
+
+                members.push(member);
+            }
+
+            if since.is_none() || next_cursor.is_none() {
+                break;
+            }
+
+            cursor = next_cursor;
+        }
+
+        Ok(members)
+    }
+
+    /// Grabs the audit log of a group from
+    /// <https://groups.roblox.com/v1/groups/{group_id}/audit-log?sortOrder=Desc&limit={limit}&cursor={cursor}>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * The current account must have permission to view the audit log of the group.
+    ///
+    /// # Argument Notes
+    /// * If `action_filter` is `Some`, only entries of that action type are returned.
+    /// * The cursor is used to get the a certain page of results. If you want the starting page, use `None`.
+    ///
+    /// # Return Value Notes
+    /// * The first value is a vector of audit log entries, sorted newest first.
+    /// * The second value is the cursor for the next page of results. If there are no more pages, this will be `None`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::groups::AuditAction;
+    /// use roboat::{ClientBuilder, Limit};
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let group_id = 1127093;
+    /// let action_filter = Some(AuditAction::ChangeRank);
+    ///
+    /// let (entries, _) = client
+    ///     .group_audit_log(group_id, action_filter, Limit::Ten, None)
+    ///     .await?;
+    ///
+    /// for entry in entries {
+    ///     println!("{}: {} ({})", entry.actor.username, entry.description, entry.created);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn group_audit_log(
+        &self,
+        group_id: u64,
+        action_filter: Option<AuditAction>,
+        limit: Limit,
+        cursor: Option<String>,
+    ) -> Result<(Vec<AuditLogEntry>, Option<String>), RoboatError> {
+        let limit = limit.to_u64();
+        let cursor = cursor.unwrap_or_default();
+
+        let mut formatted_url = format!(
+            "{}{}/audit-log?sortOrder=Desc&limit={}&cursor={}",
+            GROUPS_API, group_id, limit, cursor
+        );
+
+        if let Some(action_filter) = action_filter {
+            formatted_url.push_str(&format!("&actionType={}", action_filter.as_str()));
+        }
+
+        let cookie_string = self.cookie_string()?;
+
+        let request = self
+            .reqwest_client
+            .get(formatted_url)
+            .header(header::COOKIE, cookie_string);
+
+        let response = self.send_request(request).await?;
+        let raw = Self::parse_to_raw::<request_types::AuditLogResponse>(response).await?;
+
+        let entries = raw
+            .data
+            .into_iter()
+            .map(|entry| AuditLogEntry {
+                actor: GroupMember {
+                    user_id: entry.actor.user.user_id,
+                    username: entry.actor.user.username,
+                    display_name: entry.actor.user.display_name,
+                    has_verified_badge: entry.actor.user.has_verified_badge,
+                    role: GroupRole {
+                        rank: entry.actor.role.rank,
+                        role_id: entry.actor.role.id,
+                        name: entry.actor.role.name,
+                        member_count: None,
+                    },
+                },
+                action_type: entry.action_type,
+                description: entry.description.to_string(),
+                created: entry.created,
+            })
+            .collect();
+
+        Ok((entries, raw.next_page_cursor))
+    }
+
+    /// Polls the audit log of a group every `interval` using [`Client::group_audit_log`],
+    /// and yields every new entry as a stream. This allows bots to react to actions such as
+    /// unexpected rank changes or payouts shortly after they happen.
+    ///
+    /// Roblox does not give audit log entries an id, so an entry counts as new if it is
+    /// newer than every entry seen so far, or if it is as new as the newest entries but
+    /// was not one of them.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * The current account must have permission to view the audit log of the group.
+    /// * Entries that already exist when the stream starts are not yielded.
+    /// * Only the first page (100 entries) is checked on each poll, so an `interval` that
+    ///   allows more than 100 actions to happen between polls will miss entries.
+    /// * The stream never ends. Drop it to stop polling.
+    ///
+    /// # Argument Notes
+    /// * `interval` can be a [`Duration`](std::time::Duration) for a fixed interval, or an
+    ///   [`AdaptivePoller`](crate::polling::AdaptivePoller) to back off when rate limited or
+    ///   when the audit log is quiet.
+    ///
+    /// # Return Value Notes
+    /// * Entries are yielded oldest first.
+    /// * A failed poll yields the error, and the next poll happens as normal.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use futures_util::StreamExt;
+    /// use roboat::ClientBuilder;
+    /// use std::time::Duration;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let group_id = 1127093;
+    /// let mut entries = client.watch_group_audit_log(group_id, Duration::from_secs(30));
+    ///
+    /// while let Some(entry) = entries.next().await {
+    ///     let entry = entry?;
+    ///     println!("{}: {}", entry.actor.username, entry.action_type);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn watch_group_audit_log(
+        &self,
+        group_id: u64,
+        interval: impl Into<PollInterval>,
+    ) -> BoxStream<'_, Result<AuditLogEntry, RoboatError>> {
+        let watcher = AuditLogWatcher {
+            client: self,
+            group_id,
+            interval: interval.into(),
+            polled: false,
+            pending: VecDeque::new(),
+            newest_seen: None,
+        };
+
+        guarded_watcher(self, watcher, |mut watcher| async move {
+            loop {
+                if let Some(entry) = watcher.pending.pop_front() {
+                    return Some((Ok(entry), watcher));
+                }
+
+                if watcher.polled {
+                    tokio::time::sleep(watcher.interval.next_interval(Utc::now())).await;
+                }
+
+                watcher.polled = true;
+
+                let entries = match watcher
+                    .client
+                    .group_audit_log(watcher.group_id, None, Limit::Hundred, None)
+                    .await
+                {
+                    Ok((entries, _)) => entries,
+                    Err(e) => {
+                        let outcome = match e {
+                            RoboatError::TooManyRequests => PollOutcome::RateLimited,
+                            _ => PollOutcome::Failed,
+                        };
+
+                        watcher.interval.record(outcome);
+                        return Some((Err(e), watcher));
+                    }
+                };
+
+                watcher.pending = match &watcher.newest_seen {
+                    Some(newest_seen) => new_audit_log_entries(&entries, newest_seen),
+                    None => VecDeque::new(),
+                };
+
+                watcher.interval.record(match watcher.pending.is_empty() {
+                    true => PollOutcome::Idle,
+                    false => PollOutcome::Activity,
+                });
+
+                // Entries are sorted newest first, so the newest entries are at the start.
+                if let Some(newest) = entries.first() {
+                    let newest_created = newest.created;
+
+                    let newest_entries = entries
+                        .iter()
+                        .take_while(|entry| entry.created == newest_created)
+                        .cloned()
+                        .collect();
+
+                    watcher.newest_seen = Some(newest_entries);
+                } else if watcher.newest_seen.is_none() {
+                    watcher.newest_seen = Some(Vec::new());
+                }
+            }
+        })
+    }
+
+    /// Grabs the amount of members in a group from <https://groups.roblox.com/v1/groups/{group_id}>.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let group_id = 1200769;
+    /// let member_count = client.group_member_count(group_id).await?;
+    ///
+    /// println!("Members: {}", member_count);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn group_member_count(&self, group_id: u64) -> Result<u64, RoboatError> {
+        Ok(self.group_details(group_id).await?.member_count)
+    }
+
+    /// Polls the member count of a group every `interval` using [`Client::group_member_count`],
+    /// and yields the count along with how much it changed as a stream. This is meant for
+    /// dashboards that track how a community grows.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    /// * The first poll happens immediately and yields a change of 0.
+    /// * The stream never ends. Drop it to stop polling.
+    ///
+    /// # Argument Notes
+    /// * `interval` can be a [`Duration`](std::time::Duration) for a fixed interval, or an
+    ///   [`AdaptivePoller`](crate::polling::AdaptivePoller) to back off when rate limited or
+    ///   when the member count does not change.
+    ///
+    /// # Return Value Notes
+    /// * A failed poll yields the error, and the next poll happens as normal. The change
+    ///   after a failed poll is measured from the last successful poll.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use futures_util::StreamExt;
+    /// use roboat::ClientBuilder;
+    /// use std::time::Duration;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let group_id = 1200769;
+    /// let mut growth = client.watch_group_growth(group_id, Duration::from_secs(300));
+    ///
+    /// while let Some(growth) = growth.next().await {
+    ///     let growth = growth?;
+    ///     println!("{} members ({:+})", growth.member_count, growth.change);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn watch_group_growth(
+        &self,
+        group_id: u64,
+        interval: impl Into<PollInterval>,
+    ) -> BoxStream<'_, Result<GroupGrowth, RoboatError>> {
+        let watcher = GroupGrowthWatcher {
+            client: self,
+            group_id,
+            interval: interval.into(),
+            polled: false,
+            last_member_count: None,
+        };
+
+        guarded_watcher(self, watcher, |mut watcher| async move {
+            if watcher.polled {
+                tokio::time::sleep(watcher.interval.next_interval(Utc::now())).await;
+            }
+
+            watcher.polled = true;
+
+            let member_count = match watcher.client.group_member_count(watcher.group_id).await {
+                Ok(member_count) => member_count,
+                Err(e) => {
+                    let outcome = match e {
+                        RoboatError::TooManyRequests => PollOutcome::RateLimited,
+                        _ => PollOutcome::Failed,
+                    };
+
+                    watcher.interval.record(outcome);
+                    return Some((Err(e), watcher));
+                }
+            };
+
+            let previous_member_count = watcher.last_member_count.unwrap_or(member_count);
+            let change = member_count as i64 - previous_member_count as i64;
+
+            watcher.last_member_count = Some(member_count);
+
+            watcher.interval.record(match change {
+                0 => PollOutcome::Idle,
+                _ => PollOutcome::Activity,
+            });
+
+            let growth = GroupGrowth {
+                member_count,
+                change,
+                timestamp: Utc::now(),
+            };
+
+            Some((Ok(growth), watcher))
+        })
+    }
+
+    /// Grabs a page of group members, sorted by join date (newest first).
+    async fn group_members_newest_first(
+        &self,
+        group_id: u64,
+        limit: Limit,
+        cursor: Option<String>,
+    ) -> Result<(Vec<GroupMember>, Option<String>), RoboatError> {
+        let limit = limit.to_u64();
+        let cursor = cursor.unwrap_or_default();
+
+        let formatted_url = format!(
+            "{}{}/users?sortOrder=Desc&limit={}&cursor={}",
+            GROUPS_API, group_id, limit, cursor
+        );
+
+        let cookie_string = self.cookie_string().unwrap_or(HeaderValue::from_static(""));
+
+        let request = self
+            .reqwest_client
+            .get(formatted_url)
+            .header(header::COOKIE, cookie_string);
+
+        let response = self.send_request(request).await?;
+        let raw = Self::parse_to_raw::<request_types::GroupMembersResponse>(response).await?;
+
+        let members = raw
+            .data
+            .into_iter()
+            .map(|member| GroupMember {
+                user_id: member.user.user_id,
+                username: member.user.username,
+                display_name: member.user.display_name,
+                has_verified_badge: member.user.has_verified_badge,
+                role: GroupRole {
+                    rank: member.role.rank,
+                    role_id: member.role.id,
+                    name: member.role.name,
+                    member_count: member.role.member_count,
+                },
+            })
+            .collect();
+
+        Ok((members, raw.next_page_cursor))
+    }
+
+    /// Sets the icon (emblem) of a group using <https://groups.roblox.com/v1/groups/icon?groupId={group_id}>,
+    /// and then grabs the moderation state of the new icon using [`Client::thumbnails`].
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * The current account must be the owner of the group (or have permission to manage it).
+    /// * Will repeat once if the x-csrf-token is invalid.
+    ///
+    /// # Argument Notes
+    /// * `image_bytes` is the raw contents of an image file (png or jpeg).
+    ///
+    /// # Return Value Notes
+    /// * Returns the state of the group icon thumbnail after the upload. A new icon usually
+    ///   starts out as [`ThumbnailState::InReview`] or [`ThumbnailState::Pending`] and becomes
+    ///   [`ThumbnailState::Completed`] once approved, or [`ThumbnailState::Blocked`] if rejected.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let group_id = 1127093;
+    /// let image_bytes = std::fs::read("icon.png")?;
+    ///
+    /// let state = client.set_group_icon(group_id, image_bytes).await?;
+    /// println!("Icon State: {:?}", state);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_group_icon(
+        &self,
+        group_id: u64,
+        image_bytes: Vec<u8>,
+    ) -> Result<ThumbnailState, RoboatError> {
+        match self.set_group_icon_internal(group_id, &image_bytes).await {
+            Ok(_) => {}
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.set_group_icon_internal(group_id, &image_bytes).await?;
+                }
+                _ => return Err(e),
+            },
+        }
+
+        let thumbnail_args = ThumbnailArgs {
+            target_id: group_id,
+            thumbnail_type: ThumbnailType::GroupIcon,
+            size: ThumbnailSize::Size150x150,
+            format: ThumbnailFormat::Png,
+        };
+
+        let thumbnail = self
+            .thumbnails(vec![thumbnail_args])
+            .await?
+            .pop()
+            .ok_or(RoboatError::MalformedResponse)?;
+
+        Ok(thumbnail.state)
+    }
+
+    /// Changes the role of a member of a group using the endpoint
+    /// <https://groups.roblox.com/v1/groups/{group_id}/users/{user_id}>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * The current account needs permission to manage lower ranked members, and both the
+    ///   member's current role and `role_id` have to be ranked below the current account's role.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    ///
+    /// # Argument Notes
+    /// * `role_id` is the id of the role (see [`GroupRole::role_id`]), not its rank.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ids::UserId;
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let group_id = 1127093;
+    /// let user_id = UserId(2207291);
+    ///
+    /// let roles = client.group_roles(group_id).await?;
+    /// let member_role = roles.iter().find(|role| role.rank == 1).unwrap();
+    ///
+    /// client.set_member_role(group_id, user_id, member_role.role_id).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_member_role(
+        &self,
+        group_id: u64,
+        user_id: UserId,
+        role_id: u64,
+    ) -> Result<(), RoboatError> {
+        match self
+            .set_member_role_internal(group_id, user_id, role_id)
+            .await
+        {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.set_member_role_internal(group_id, user_id, role_id)
+                        .await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Removes a member from a group using the endpoint
+    /// <https://groups.roblox.com/v1/groups/{group_id}/users/{user_id}>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * The current account needs permission to kick lower ranked members.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ids::UserId;
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let group_id = 1127093;
+    /// let user_id = UserId(2207291);
+    ///
+    /// client.kick_member(group_id, user_id).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn kick_member(&self, group_id: u64, user_id: UserId) -> Result<(), RoboatError> {
+        match self.kick_member_internal(group_id, user_id).await {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.kick_member_internal(group_id, user_id).await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Grabs a page of the users banned from a group from
+    /// <https://groups.roblox.com/v1/groups/{group_id}/bans>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * The current account needs permission to ban members.
+    /// * Bans are ordered newest to oldest.
+    ///
+    /// # Return Value Notes
+    /// * The first value is a vector of bans.
+    /// * The second value is the cursor for the next page of results. If there are no more pages, this will be `None`.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    /// use roboat::Limit;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let group_id = 1127093;
+    /// let (bans, next_cursor) = client.group_bans(group_id, Limit::Hundred, None).await?;
+    ///
+    /// for ban in bans {
+    ///     println!("{} was banned at {}.", ban.username, ban.created);
+    /// }
+    ///
+    /// println!("Next Cursor: {}", next_cursor.unwrap_or_default());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn group_bans(
+        &self,
+        group_id: u64,
+        limit: Limit,
+        cursor: Option<String>,
+    ) -> Result<(Vec<GroupBan>, Option<String>), RoboatError> {
+        let formatted_url = format!(
+            "{}{}/bans?sortOrder=Desc&limit={}&cursor={}",
+            GROUPS_API,
+            group_id,
+            limit.to_u64(),
+            cursor.unwrap_or_default()
+        );
+
+        let cookie_string = self.cookie_string()?;
+
+        let request = self
+            .reqwest_client
+            .get(formatted_url)
+            .header(header::COOKIE, cookie_string);
+
+        let response = self.send_request(request).await?;
+        let raw = Self::parse_to_raw::<request_types::GroupBansResponse>(response).await?;
+
+        let bans = raw
+            .data
+            .into_iter()
+            .map(|ban| {
+                let banned_by = ban.acting_user.map(|acting_user| acting_user.user);
+
+                GroupBan {
+                    user_id: ban.user.user_id,
+                    username: ban.user.username,
+                    display_name: ban.user.display_name,
+                    banned_by_user_id: banned_by.as_ref().map(|user| user.user_id),
+                    banned_by_username: banned_by.map(|user| user.username),
+                    created: ban.created,
+                }
+            })
+            .collect();
+
+        Ok((bans, raw.next_page_cursor))
+    }
+
+    /// Bans a user from a group using the endpoint
+    /// <https://groups.roblox.com/v1/groups/{group_id}/bans/{user_id}>.
+    ///
+    /// Banned users are removed from the group and cannot join it again until unbanned
+    /// with [`Client::unban_member`].
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * The current account needs permission to ban lower ranked members.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ids::UserId;
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let group_id = 1127093;
+    /// let user_id = UserId(2207291);
+    ///
+    /// client.ban_member(group_id, user_id).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn ban_member(&self, group_id: u64, user_id: UserId) -> Result<(), RoboatError> {
+        match self.group_ban_internal(group_id, user_id, true).await {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.group_ban_internal(group_id, user_id, true).await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Unbans a user from a group using the endpoint
+    /// <https://groups.roblox.com/v1/groups/{group_id}/bans/{user_id}>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * The current account needs permission to ban members.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ids::UserId;
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let group_id = 1127093;
+    /// let user_id = UserId(2207291);
+    ///
+    /// client.unban_member(group_id, user_id).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn unban_member(&self, group_id: u64, user_id: UserId) -> Result<(), RoboatError> {
+        match self.group_ban_internal(group_id, user_id, false).await {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.group_ban_internal(group_id, user_id, false).await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Grabs a page of pending join requests of a group from
+    /// <https://groups.roblox.com/v1/groups/{group_id}/join-requests>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * The current account needs permission to manage join requests.
+    /// * Returns up to 100 requests per page, newest first.
+    ///
+    /// # Return Value Notes
+    /// * The first value is a vector of join requests.
+    /// * The second value is the cursor for the next page of results. If there are no more pages, this will be `None`.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let group_id = 1127093;
+    /// let (requests, next_cursor) = client.join_requests(group_id, None).await?;
+    ///
+    /// for request in requests {
+    ///     println!("{} wants to join.", request.username);
+    /// }
+    ///
+    /// println!("Next Cursor: {}", next_cursor.unwrap_or_default());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn join_requests(
+        &self,
+        group_id: u64,
+        cursor: Option<String>,
+    ) -> Result<(Vec<JoinRequest>, Option<String>), RoboatError> {
+        let formatted_url = format!(
+            "{}{}/join-requests?sortOrder=Desc&limit={}&cursor={}",
+            GROUPS_API,
+            group_id,
+            JOIN_REQUESTS_LIMIT,
+            cursor.unwrap_or_default()
+        );
+
+        let cookie_string = self.cookie_string()?;
+
+        let request = self
+            .reqwest_client
+            .get(formatted_url)
+            .header(header::COOKIE, cookie_string);
+
+        let response = self.send_request(request).await?;
+        let raw = Self::parse_to_raw::<request_types::JoinRequestsResponse>(response).await?;
+
+        let join_requests = raw
+            .data
+            .into_iter()
+            .map(|request| JoinRequest {
+                user_id: request.requester.user_id,
+                username: request.requester.username,
+                display_name: request.requester.display_name,
+                has_verified_badge: request.requester.has_verified_badge,
+                created: request.created,
+            })
+            .collect();
+
+        Ok((join_requests, raw.next_page_cursor))
+    }
+
+    /// Accepts or declines a request to join a group using the endpoint
+    /// <https://groups.roblox.com/v1/groups/{group_id}/join-requests/users/{user_id}>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * The current account needs permission to manage join requests.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    ///
+    /// # Argument Notes
+    /// * The user joins the group if `accept` is `true`, and the request is removed if it is `false`.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let group_id = 1127093;
+    /// let (requests, _) = client.join_requests(group_id, None).await?;
+    ///
+    /// for request in requests {
+    ///     let accept = request.has_verified_badge;
+    ///     client.handle_join_request(group_id, request.user_id, accept).await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn handle_join_request(
+        &self,
+        group_id: u64,
+        user_id: UserId,
+        accept: bool,
+    ) -> Result<(), RoboatError> {
+        match self
+            .handle_join_request_internal(group_id, user_id, accept)
+            .await
+        {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.handle_join_request_internal(group_id, user_id, accept)
+                        .await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Pays out robux from a group's funds to its members using the endpoint
+    /// <https://groups.roblox.com/v1/groups/{group_id}/payouts>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * The current account needs permission to spend group funds.
+    /// * Only available robux can be paid out. Use [`Client::group_funds`] to check first.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    ///
+    /// # Argument Notes
+    /// * Each recipient is a `(user_id, robux)` pair.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    /// * [`RoboatError::GroupPayoutError`] - If the group does not have enough funds or a
+    ///   recipient is not eligible.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::groups::GroupPayoutError;
+    /// use roboat::ids::UserId;
+    /// use roboat::{ClientBuilder, RoboatError};
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let group_id = 1127093;
+    /// let recipients = vec![(UserId(2207291), 100), (UserId(1), 50)];
+    ///
+    /// match client.group_payout(group_id, recipients).await {
+    ///     Ok(()) => println!("Paid out."),
+    ///     Err(RoboatError::GroupPayoutError(GroupPayoutError::InsufficientFunds)) => {
+    ///         println!("Not enough robux.")
+    ///     }
+    ///     Err(e) => return Err(e.into()),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn group_payout(
+        &self,
+        group_id: u64,
+        recipients: Vec<(UserId, u64)>,
+    ) -> Result<(), RoboatError> {
+        match self.group_payout_internal(group_id, &recipients).await {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.group_payout_internal(group_id, &recipients).await
+                }
+                _ => Err(e),
+            },
+        }
+        .map_err(group_payout_error)
+    }
+
+    /// Used by [`Client::group_allies`] and [`Client::group_enemies`] as they share the same endpoint.
+    async fn group_relationships(
+        &self,
+        group_id: u64,
+        relationship_type: GroupRelationshipType,
+        limit: Limit,
+        cursor: Option<u64>,
+    ) -> Result<(Vec<RelatedGroup>, Option<u64>), RoboatError> {
+        let limit = limit.to_u64();
+        let start_row_index = cursor.unwrap_or_default();
+
+        let formatted_url = format!(
+            "{}{}/relationships/{}?model.startRowIndex={}&model.maxRows={}",
+            GROUPS_API,
+            group_id,
+            relationship_type.as_str(),
+            start_row_index,
+            limit
+        );
+
+        let cookie_string = self.cookie_string().unwrap_or(HeaderValue::from_static(""));
+
+        let request = self
+            .reqwest_client
+            .get(formatted_url)
+            .header(header::COOKIE, cookie_string);
+
+        let response = self.send_request(request).await?;
+        let raw = Self::parse_to_raw::<request_types::GroupRelationshipsResponse>(response).await?;
+
+        let mut related_groups = Vec::new();
+
+        for group in raw.related_groups {
+            let owner = group.owner.map(group_owner);
+
+            let related_group = RelatedGroup {
+                group_id: group.id,
+                name: group.name,
+                description: group.description,
+                owner,
+                member_count: group.member_count,
+                public_entry_allowed: group.public_entry_allowed,
+                has_verified_badge: group.has_verified_badge,
+            };
+
+            related_groups.push(related_group);
+        }
+
+        // Roblox keeps returning a next row index even on the last page.
+        let next_cursor = match raw.next_row_index {
+            Some(next_row_index) if next_row_index < raw.total_group_count => Some(next_row_index),
+            _ => None,
+        };
+
+        Ok((related_groups, next_cursor))
+    }
+}
+
+mod internal {
+    use super::{request_types, GroupRelationshipType, GROUPS_API};
+    use crate::ids::UserId;
+    use crate::{Client, RoboatError, XCSRF_HEADER};
+    use reqwest::header;
+    use reqwest::multipart::{Form, Part};
+
+    impl Client {
+        pub(super) async fn set_group_icon_internal(
+            &self,
+            group_id: u64,
+            image_bytes: &[u8],
+        ) -> Result<(), RoboatError> {
+            let formatted_url = format!("{}icon?groupId={}", GROUPS_API, group_id);
+
+            let cookie = self.cookie_string()?;
+
+            let part = Part::bytes(image_bytes.to_vec()).file_name("icon.png");
+            let form = Form::new().part("Files", part);
+
+            let request = self
+                .reqwest_client
+                .patch(formatted_url)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, self.xcsrf().await)
+                .multipart(form);
+
+            let _ = self
+                .send_mutating_request(request, serde_json::json!({}))
+                .await?;
+
+            // We don't need to do anything, we just need a 200 status code.
+
+            Ok(())
+        }
+
+        pub(super) async fn add_group_relationship_internal(
+            &self,
+            group_id: u64,
+            relationship_type: GroupRelationshipType,
+            related_group_id: u64,
+        ) -> Result<(), RoboatError> {
+            let formatted_url = format!(
+                "{}{}/relationships/{}/{}",
+                GROUPS_API,
+                group_id,
+                relationship_type.as_str(),
+                related_group_id
+            );
+
+            let cookie = self.cookie_string()?;
+
+            let request = self
+                .reqwest_client
+                .post(formatted_url)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, self.xcsrf().await);
+
+            let _ = self
+                .send_mutating_request(request, serde_json::json!({}))
+                .await?;
+
+            // We don't need to do anything, we just need a 200 status code.
+
+            Ok(())
+        }
+
+        pub(super) async fn remove_group_relationship_internal(
+            &self,
+            group_id: u64,
+            relationship_type: GroupRelationshipType,
+            related_group_id: u64,
+        ) -> Result<(), RoboatError> {
+            let formatted_url = format!(
+                "{}{}/relationships/{}/{}",
+                GROUPS_API,
+                group_id,
+                relationship_type.as_str(),
+                related_group_id
+            );
+
+            let cookie = self.cookie_string()?;
+
+            let request = self
+                .reqwest_client
+                .delete(formatted_url)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, self.xcsrf().await);
+
+            let _ = self
+                .send_mutating_request(request, serde_json::json!({}))
+                .await?;
+
+            // We don't need to do anything, we just need a 200 status code.
+
+            Ok(())
+        }
+
+        pub(super) async fn group_payout_internal(
+            &self,
+            group_id: u64,
+            recipients: &[(UserId, u64)],
+        ) -> Result<(), RoboatError> {
+            let formatted_url = format!("{}{}/payouts", GROUPS_API, group_id);
+
+            let cookie = self.cookie_string()?;
+
+            let body = request_types::GroupPayoutReqBody {
+                payout_type: "FixedAmount".to_string(),
+                recipients: recipients
+                    .iter()
+                    .map(|(user_id, amount)| request_types::PayoutRecipientRaw {
+                        recipient_id: user_id.0,
+                        recipient_type: "User".to_string(),
+                        amount: *amount,
+                    })
+                    .collect(),
+            };
+
+            let request = self
+                .reqwest_client
+                .post(formatted_url)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, self.xcsrf().await)
+                .json(&body);
+
+            let _ = self
+                .send_mutating_request(request, serde_json::json!({}))
+                .await?;
+
+            // We don't need to do anything, we just need a 200 status code.
+
+            Ok(())
+        }
+
+        pub(super) async fn set_member_role_internal(
+            &self,
+            group_id: u64,
+            user_id: UserId,
+            role_id: u64,
+        ) -> Result<(), RoboatError> {
+            let formatted_url = format!("{}{}/users/{}", GROUPS_API, group_id, user_id);
+
+            let cookie = self.cookie_string()?;
+
+            let body = request_types::SetMemberRoleReqBody { role_id };
+
+            let request = self
+                .reqwest_client
+                .patch(formatted_url)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, self.xcsrf().await)
+                .json(&body);
+
+            let _ = self
+                .send_mutating_request(request, serde_json::json!({}))
+                .await?;
+
+            // We don't need to do anything, we just need a 200 status code.
+
+            Ok(())
+        }
+
+        pub(super) async fn kick_member_internal(
+            &self,
+            group_id: u64,
+            user_id: UserId,
+        ) -> Result<(), RoboatError> {
+            let formatted_url = format!("{}{}/users/{}", GROUPS_API, group_id, user_id);
+
+            let cookie = self.cookie_string()?;
+
+            let request = self
+                .reqwest_client
+                .delete(formatted_url)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, self.xcsrf().await);
+
+            let _ = self
+                .send_mutating_request(request, serde_json::json!({}))
+                .await?;
+
+            // We don't need to do anything, we just need a 200 status code.
+
+            Ok(())
+        }
+
+        /// Bans the user if `ban` is `true`, and unbans the user if it is `false`.
+        pub(super) async fn group_ban_internal(
+            &self,
+            group_id: u64,
+            user_id: UserId,
+            ban: bool,
+        ) -> Result<(), RoboatError> {
+            let formatted_url = format!("{}{}/bans/{}", GROUPS_API, group_id, user_id);
+
+            let cookie = self.cookie_string()?;
+
+            let request = match ban {
+                true => self.reqwest_client.post(formatted_url),
+                false => self.reqwest_client.delete(formatted_url),
+            };
+
+            let request = request
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, self.xcsrf().await);
+
+            let _ = self
+                .send_mutating_request(request, serde_json::json!({}))
+                .await?;
+
+            // We don't need to do anything, we just need a 200 status code.
+
+            Ok(())
+        }
+
+        pub(super) async fn handle_join_request_internal(
+            &self,
+            group_id: u64,
+            user_id: UserId,
+            accept: bool,
+        ) -> Result<(), RoboatError> {
+            let formatted_url =
+                format!("{}{}/join-requests/users/{}", GROUPS_API, group_id, user_id);
+
+            let cookie = self.cookie_string()?;
+
+            // Accepting and declining use the same url, and only differ in the method.
+            let request_builder = match accept {
+                true => self.reqwest_client.post(formatted_url),
+                false => self.reqwest_client.delete(formatted_url),
+            };
+
+            let request = request_builder
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, self.xcsrf().await);
+
+            let _ = self
+                .send_mutating_request(request, serde_json::json!({}))
+                .await?;
+
+            // We don't need to do anything, we just need a 200 status code.
+
+            Ok(())
+        }
+    }
+}
+
+/// Converts the errors Roblox gives for payouts into a [`GroupPayoutError`] when possible.
+/// Other errors are returned as-is.
+fn group_payout_error(error: RoboatError) -> RoboatError {
+    match &error {
+        RoboatError::UnknownRobloxErrorCode { message, .. } => {
+            let message = message.to_lowercase();
+
+            if message.contains("insufficient") {
+                RoboatError::GroupPayoutError(GroupPayoutError::InsufficientFunds)
+            } else if message.contains("recipient") || message.contains("eligible") {
+                RoboatError::GroupPayoutError(GroupPayoutError::RecipientNotEligible)
+            } else {
+                error
+            }
+        }
+        _ => error,
+    }
+}
+
+fn group_owner(raw: request_types::GroupOwnerRaw) -> GroupOwner {
+    GroupOwner {
+        user_id: raw.user_id,
+        username: raw.username,
+        display_name: raw.display_name,
+        has_verified_badge: raw.has_verified_badge,
+    }
+}
+
+/// Returns the entries in `entries` (sorted newest first) that are newer than `newest_seen`,
+/// sorted oldest first.
+fn new_audit_log_entries(
+    entries: &[AuditLogEntry],
+    newest_seen: &[AuditLogEntry],
+) -> VecDeque<AuditLogEntry> {
+    let newest_seen_created = match newest_seen.first() {
+        Some(entry) => entry.created,
+        None => return entries.iter().rev().cloned().collect(),
+    };
+
+    entries
+        .iter()
+        .filter(|entry| {
+            entry.created > newest_seen_created
+                || (entry.created == newest_seen_created && !newest_seen.contains(entry))
+        })
+        .rev()
+        .cloned()
+        .collect()
+}
@@ -0,0 +1,174 @@
+use super::AuditAction;
+use crate::ids::UserId;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct GroupRelationshipsResponse {
+    pub group_id: u64,
+    pub relationship_type: String,
+    pub total_group_count: u64,
+    pub related_groups: Vec<RelatedGroupRaw>,
+    pub next_row_index: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct GroupInfoResponse {
+    pub id: u64,
+    pub name: String,
+    pub description: String,
+    pub owner: Option<GroupOwnerRaw>,
+    pub shout: Option<GroupShoutRaw>,
+    pub member_count: u64,
+    pub public_entry_allowed: bool,
+    #[serde(default)]
+    pub is_locked: bool,
+    pub has_verified_badge: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct GroupShoutRaw {
+    pub body: String,
+    pub poster: Option<GroupOwnerRaw>,
+    pub created: DateTime<Utc>,
+    pub updated: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct RelatedGroupRaw {
+    pub id: u64,
+    pub name: String,
+    pub description: String,
+    pub owner: Option<GroupOwnerRaw>,
+    pub member_count: u64,
+    pub public_entry_allowed: bool,
+    pub has_verified_badge: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct GroupOwnerRaw {
+    pub user_id: UserId,
+    pub username: String,
+    pub display_name: String,
+    pub has_verified_badge: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct GroupRolesResponse {
+    pub group_id: u64,
+    pub roles: Vec<GroupRoleRaw>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct GroupRoleRaw {
+    pub id: u64,
+    pub name: String,
+    pub rank: u8,
+    pub member_count: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct GroupMembersResponse {
+    pub previous_page_cursor: Option<String>,
+    pub next_page_cursor: Option<String>,
+    pub data: Vec<GroupMemberRaw>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct GroupMemberRaw {
+    pub user: GroupMemberUserRaw,
+    pub role: GroupRoleRaw,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct GroupMemberUserRaw {
+    pub user_id: UserId,
+    pub username: String,
+    pub display_name: String,
+    pub has_verified_badge: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct AuditLogResponse {
+    pub previous_page_cursor: Option<String>,
+    pub next_page_cursor: Option<String>,
+    pub data: Vec<AuditLogEntryRaw>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct AuditLogEntryRaw {
+    pub actor: GroupMemberRaw,
+    pub action_type: AuditAction,
+    pub description: serde_json::Value,
+    pub created: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct JoinRequestsResponse {
+    pub previous_page_cursor: Option<String>,
+    pub next_page_cursor: Option<String>,
+    pub data: Vec<JoinRequestRaw>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct JoinRequestRaw {
+    pub requester: GroupOwnerRaw,
+    pub created: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct GroupBansResponse {
+    pub previous_page_cursor: Option<String>,
+    pub next_page_cursor: Option<String>,
+    pub data: Vec<GroupBanRaw>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct GroupBanRaw {
+    pub user: GroupOwnerRaw,
+    pub acting_user: Option<GroupBanActorRaw>,
+    pub created: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct GroupBanActorRaw {
+    pub user: GroupOwnerRaw,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct SetMemberRoleReqBody {
+    pub role_id: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub(super) struct GroupPayoutReqBody {
+    pub payout_type: String,
+    pub recipients: Vec<PayoutRecipientRaw>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct PayoutRecipientRaw {
+    pub recipient_id: u64,
+    pub recipient_type: String,
+    pub amount: u64,
+}
@@ -0,0 +1,162 @@
+use crate::ids::UniverseId;
+use crate::{Client, RoboatError};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// The shortest interval [`Client::record_player_counts`] samples at. Shorter intervals
+/// (including zero) are treated as this.
+const MIN_SAMPLE_INTERVAL: Duration = Duration::from_millis(1);
+
+/// The player count of a universe at a point in time. Created by [`Client::record_player_counts`].
+#[derive(
+    Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize, Copy,
+)]
+pub struct PlayerCountSample {
+    /// When the sample was taken.
+    pub timestamp: DateTime<Utc>,
+    /// The amount of players in the universe (the CCU).
+    pub playing: u64,
+}
+
+/// Somewhere to store player count samples as they are taken, such as a file or a database.
+///
+/// Implemented for `Vec<PlayerCountSample>`, which keeps samples in memory.
+pub trait HistorySink {
+    /// Stores a sample taken from the universe with the given id.
+    fn record(&mut self, universe_id: UniverseId, sample: PlayerCountSample);
+}
+
+impl HistorySink for Vec<PlayerCountSample> {
+    fn record(&mut self, _universe_id: UniverseId, sample: PlayerCountSample) {
+        self.push(sample);
+    }
+}
+
+/// Reduces samples into one sample per `bucket` of time, so that long histories can be
+/// charted or stored cheaply.
+///
+/// Each returned sample holds the start of its bucket as its timestamp, and the peak player
+/// count of the samples in that bucket.
+///
+/// # Argument Notes
+/// * `samples` must be sorted by timestamp.
+/// * A `bucket` shorter than a second is treated as one second.
+///
+/// # Example
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use roboat::history::{downsample, PlayerCountSample};
+/// use std::time::Duration;
+///
+/// let at = |second| Utc.timestamp_opt(second, 0).unwrap();
+///
+/// let samples = vec![
+///     PlayerCountSample { timestamp: at(0), playing: 10 },
+///     PlayerCountSample { timestamp: at(30), playing: 15 },
+///     PlayerCountSample { timestamp: at(60), playing: 12 },
+/// ];
+///
+/// let downsampled = downsample(&samples, Duration::from_secs(60));
+///
+/// assert_eq!(
+///     downsampled,
+///     vec![
+///         PlayerCountSample { timestamp: at(0), playing: 15 },
+///         PlayerCountSample { timestamp: at(60), playing: 12 },
+///     ]
+/// );
+/// ```
+pub fn downsample(samples: &[PlayerCountSample], bucket: Duration) -> Vec<PlayerCountSample> {
+    let bucket_secs = i64::try_from(bucket.as_secs()).unwrap_or(i64::MAX).max(1);
+
+    let mut downsampled: Vec<PlayerCountSample> = Vec::new();
+
+    for sample in samples {
+        let seconds = sample.timestamp.timestamp();
+        let bucket_start = DateTime::from_timestamp(seconds - seconds.rem_euclid(bucket_secs), 0)
+            .unwrap_or(sample.timestamp);
+
+        match downsampled.last_mut() {
+            Some(last) if last.timestamp == bucket_start => {
+                last.playing = last.playing.max(sample.playing);
+            }
+            _ => downsampled.push(PlayerCountSample {
+                timestamp: bucket_start,
+                playing: sample.playing,
+            }),
+        }
+    }
+
+    downsampled
+}
+
+impl Client {
+    /// Samples the player count of a universe every `interval` using [`Client::universe_stats`],
+    /// storing each sample in `sink`. Returns after `sample_count` samples have been taken.
+    ///
+    /// Use [`downsample`] to reduce the samples afterwards.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    /// * A failed request stops the sampling. Samples taken before the failure are kept in `sink`.
+    /// * An `interval` shorter than a millisecond (including zero) is treated as one millisecond.
+    ///
+    /// # Errors
+    /// * [`RoboatError::MalformedResponse`] - If the universe does not exist.
+    /// * Any error returned by [`Client::universe_stats`].
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::history::{downsample, PlayerCountSample};
+    /// use roboat::ids::UniverseId;
+    /// use roboat::ClientBuilder;
+    /// use std::time::Duration;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let universe_id = UniverseId(1686885941);
+    /// let mut samples: Vec<PlayerCountSample> = Vec::new();
+    ///
+    /// client
+    ///     .record_player_counts(universe_id, Duration::from_secs(60), 60, &mut samples)
+    ///     .await?;
+    ///
+    /// for sample in downsample(&samples, Duration::from_secs(60 * 15)) {
+    ///     println!("{}: {}", sample.timestamp, sample.playing);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn record_player_counts<S: HistorySink>(
+        &self,
+        universe_id: UniverseId,
+        interval: Duration,
+        sample_count: usize,
+        sink: &mut S,
+    ) -> Result<(), RoboatError> {
+        let mut ticker = tokio::time::interval(interval.max(MIN_SAMPLE_INTERVAL));
+
+        for _ in 0..sample_count {
+            ticker.tick().await;
+
+            let stats = self
+                .universe_stats(vec![universe_id])
+                .await?
+                .pop()
+                .ok_or(RoboatError::MalformedResponse)?;
+
+            sink.record(
+                universe_id,
+                PlayerCountSample {
+                    timestamp: Utc::now(),
+                    playing: stats.playing,
+                },
+            );
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,96 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+// Every id type is a transparent wrapper around a u64, so they are generated
+// by one macro to keep their impls identical.
+macro_rules! id_newtype {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[derive(
+            Clone,
+            Copy,
+            Debug,
+            PartialEq,
+            Eq,
+            PartialOrd,
+            Ord,
+            Hash,
+            Default,
+            Serialize,
+            Deserialize,
+        )]
+        #[serde(transparent)]
+        pub struct $name(pub u64);
+
+        impl From<u64> for $name {
+            fn from(id: u64) -> Self {
+                Self(id)
+            }
+        }
+
+        impl From<$name> for u64 {
+            fn from(id: $name) -> Self {
+                id.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = ParseIntError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                s.parse().map(Self)
+            }
+        }
+    };
+}
+
+id_newtype! {
+    /// The id of a Roblox user.
+    ///
+    /// # Example
+    /// ```
+    /// use roboat::ids::UserId;
+    ///
+    /// let user_id = UserId(2207291);
+    /// assert_eq!(user_id.to_string(), "2207291");
+    /// assert_eq!("2207291".parse::<UserId>(), Ok(user_id));
+    /// assert_eq!(u64::from(user_id), 2207291);
+    /// ```
+    UserId
+}
+
+id_newtype! {
+    /// The id of an asset, such as a limited item. This is the id shown in
+    /// the url of the item's catalog page.
+    AssetId
+}
+
+id_newtype! {
+    /// The product id of an item, used when purchasing it. This is not the
+    /// same as the item's [`AssetId`].
+    ProductId
+}
+
+id_newtype! {
+    /// The id of a universe (game). A universe contains one or more places.
+    UniverseId
+}
+
+id_newtype! {
+    /// The id of a place inside a universe.
+    PlaceId
+}
+
+id_newtype! {
+    /// The unique asset id (uaid) of one specific copy of a limited item.
+    /// This is not the same as the item's [`AssetId`].
+    Uaid
+}
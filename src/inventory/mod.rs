@@ -0,0 +1,688 @@
+use crate::catalog::avatar_catalog::AssetType;
+use crate::ids::{AssetId, Uaid, UserId};
+use crate::pagination::all_pages;
+use crate::{ApiVersion, BatchResult, Client, Limit, RoboatError};
+use chrono::{DateTime, Utc};
+use futures_util::stream::{self, StreamExt};
+use reqwest::header::{self, HeaderValue};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+mod request_types;
+
+const INVENTORY_API: &str = "https://inventory.roblox.com/v1/users/";
+
+/// The parts of the url of endpoints that exist under both `/v1/` and `/v2/`, with the
+/// version in between. Used with [`Client::send_versioned_request`].
+const VERSIONED_INVENTORY_API_PART_1: &str = "https://inventory.roblox.com/";
+const VERSIONED_INVENTORY_API_PART_2: &str = "/users/";
+
+/// The maximum amount of ownership checks [`Client::users_own_asset`] runs at once.
+const MAX_CONCURRENT_OWNERSHIP_CHECKS: usize = 10;
+
+const DELETE_ASSET_API: &str = "https://inventory.roblox.com/v2/inventory/asset/";
+
+/// The maximum amount of items [`Client::delete_inventory_items`] deletes at once.
+const MAX_CONCURRENT_DELETIONS: usize = 5;
+
+/// A collectible (limited or limited u) item held by a user. Retrieved from
+/// <https://inventory.roblox.com/v1/users/{user_id}/assets/collectibles>.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct Collectible {
+    /// The unique asset id of this copy of the item.
+    pub uaid: Uaid,
+    /// The id of the item.
+    pub asset_id: AssetId,
+    /// The name of the item.
+    pub name: String,
+    /// The serial number of the copy. Only exists for Limited U items.
+    pub serial_number: Option<u64>,
+    /// The recent average price (RAP) of the item. This is `None` if the item has no sales yet.
+    pub recent_average_price: Option<u64>,
+    /// The price the item was originally sold for, if it was ever sold for robux.
+    pub original_price: Option<u64>,
+    /// The amount of copies of the item that were released. Only exists for Limited U items.
+    pub asset_stock: Option<u64>,
+    /// Whether the copy is on hold and cannot be traded or sold yet.
+    pub is_on_hold: bool,
+}
+
+/// A copy of an item held by a user. Retrieved from
+/// <https://inventory.roblox.com/v2/users/{user_id}/inventory/{asset_type_id}>.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct InventoryItem {
+    /// The id of the item.
+    pub asset_id: AssetId,
+    /// The unique asset id of this copy of the item. This is the uaid used to list the copy
+    /// with [`Client::put_limited_on_sale`].
+    pub uaid: Uaid,
+    /// The name of the item.
+    pub name: String,
+    /// The serial number of the copy. Only exists for Limited U items.
+    pub serial_number: Option<u64>,
+    /// When the user got this copy of the item.
+    pub created: DateTime<Utc>,
+}
+
+/// A collectible along with the value assigned to it by [`Client::inventory_value`].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct CollectibleValuation {
+    /// The collectible that was valued.
+    pub collectible: Collectible,
+    /// The value of the collectible. This is the value returned by the value provider,
+    /// or the recent average price if the value provider returned `None`.
+    pub value: u64,
+}
+
+/// The valuation of all collectibles held by a user. Created by [`Client::inventory_value`].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct InventoryValuation {
+    /// The sum of the recent average prices of all collectibles.
+    pub total_recent_average_price: u64,
+    /// The sum of the values of all collectibles.
+    pub total_value: u64,
+    /// Every collectible and its value, sorted by value (highest first).
+    pub items: Vec<CollectibleValuation>,
+}
+
+/// An item that a user holds more than one copy of. Created by [`Client::duplicate_collectibles`].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct DuplicateCollectible {
+    /// The id of the item.
+    pub asset_id: AssetId,
+    /// The name of the item.
+    pub name: String,
+    /// Every copy of the item held by the user, sorted by serial number (copies without a
+    /// serial number come first).
+    pub copies: Vec<Collectible>,
+}
+
+impl Client {
+    /// Checks whether a user owns an asset using
+    /// <https://inventory.roblox.com/v1/users/{user_id}/items/Asset/{asset_id}/is-owned>.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ids::{AssetId, UserId};
+    /// use roboat::ClientBuilder;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let user_id = UserId(2207291);
+    /// let asset_id = AssetId(1365767);
+    ///
+    /// let owns_asset = client.user_owns_asset(user_id, asset_id).await?;
+    /// println!("Owns Valkyrie Helm: {}", owns_asset);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn user_owns_asset(
+        &self,
+        user_id: UserId,
+        asset_id: AssetId,
+    ) -> Result<bool, RoboatError> {
+        let formatted_url = format!(
+            "{}{}/items/Asset/{}/is-owned",
+            INVENTORY_API, user_id, asset_id
+        );
+
+        let cookie_string = self.cookie_string().unwrap_or(HeaderValue::from_static(""));
+
+        let request = self
+            .reqwest_client
+            .get(formatted_url)
+            .header(header::COOKIE, cookie_string);
+
+        let response = self.send_request(request).await?;
+        let owns_asset = Self::parse_to_raw::<bool>(response).await?;
+
+        Ok(owns_asset)
+    }
+
+    /// Checks whether the inventory of a user can be viewed using
+    /// <https://inventory.roblox.com/v1/users/{user_id}/can-view-inventory>.
+    ///
+    /// Endpoints such as [`Client::user_collectibles`] and [`Client::user_inventory`] return
+    /// an error for inventories that cannot be viewed, so this can be used to skip those users.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    /// * If a roblosecurity is set, the result is for the current account (for example,
+    ///   friends may be able to view an inventory that others cannot).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ids::UserId;
+    /// use roboat::ClientBuilder;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let user_id = UserId(2207291);
+    ///
+    /// let can_view = client.can_view_inventory(user_id).await?;
+    /// println!("Can view inventory: {}", can_view);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn can_view_inventory(&self, user_id: UserId) -> Result<bool, RoboatError> {
+        let formatted_url = format!("{}{}/can-view-inventory", INVENTORY_API, user_id);
+
+        let cookie_string = self.cookie_string().unwrap_or(HeaderValue::from_static(""));
+
+        let request = self
+            .reqwest_client
+            .get(formatted_url)
+            .header(header::COOKIE, cookie_string);
+
+        let response = self.send_request(request).await?;
+        let raw = Self::parse_to_raw::<request_types::CanViewInventoryResponse>(response).await?;
+
+        Ok(raw.can_view)
+    }
+
+    /// Checks whether each user in a list owns an asset using
+    /// <https://inventory.roblox.com/v1/users/{user_id}/items/Asset/{asset_id}/is-owned>.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    /// * Up to 10 ownership checks are made at once.
+    ///
+    /// # Return Value Notes
+    /// * Each user is checked on its own, so a failed check (such as for a user with a private
+    ///   inventory) does not discard the other checks. The index of each success and failure is
+    ///   the index of the user in `user_ids`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ids::{AssetId, UserId};
+    /// use roboat::ClientBuilder;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let user_ids = vec![UserId(2207291), UserId(1), UserId(156)];
+    /// let asset_id = AssetId(1365767);
+    ///
+    /// let result = client.users_own_asset(user_ids.clone(), asset_id).await;
+    ///
+    /// for (i, owns_asset) in result.successes {
+    ///     println!("User {} owns Valkyrie Helm: {}", user_ids[i], owns_asset);
+    /// }
+    ///
+    /// for (i, e) in result.failures {
+    ///     println!("Could not check user {}: {}", user_ids[i], e);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn users_own_asset(
+        &self,
+        user_ids: Vec<UserId>,
+        asset_id: AssetId,
+    ) -> BatchResult<bool> {
+        stream::iter(user_ids)
+            .map(|user_id| self.user_owns_asset(user_id, asset_id))
+            .buffered(MAX_CONCURRENT_OWNERSHIP_CHECKS)
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .collect()
+    }
+
+    /// Grabs the items of one asset type held by a user from
+    /// <https://inventory.roblox.com/v2/users/{user_id}/inventory/{asset_type_id}?sortOrder=Asc&cursor={cursor}&limit={limit}>.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    /// * Will return an error if the user's inventory is private.
+    /// * Falls back to the v1 endpoint if the v2 endpoint returns a 404 or 410 status code,
+    ///   unless a version is pinned using [`ClientBuilder::pin_api_version`](crate::ClientBuilder::pin_api_version).
+    ///
+    /// # Argument Notes
+    /// * The cursor is used to get the a certain page of results. If you want the starting page, use `None`.
+    ///
+    /// # Return Value Notes
+    /// * The first value is a vector of items. Every copy of an item is its own entry.
+    /// * The second value is the cursor for the next page of results. If there are no more pages, this will be `None`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::catalog::avatar_catalog::AssetType;
+    /// use roboat::ids::UserId;
+    /// use roboat::ClientBuilder;
+    /// use roboat::Limit;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let user_id = UserId(2207291);
+    ///
+    /// let (hats, next_page_cursor) = client
+    ///     .user_inventory(user_id, AssetType::Hat, Limit::Hundred, None)
+    ///     .await?;
+    ///
+    /// for hat in hats {
+    ///     println!("{} (uaid {})", hat.name, hat.uaid);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn user_inventory(
+        &self,
+        user_id: UserId,
+        asset_type: AssetType,
+        limit: Limit,
+        cursor: Option<String>,
+    ) -> Result<(Vec<InventoryItem>, Option<String>), RoboatError> {
+        let limit = limit.to_u64();
+        let cursor = cursor.unwrap_or_default();
+        let asset_type_id = u64::from(asset_type);
+
+        let cookie_string = self.cookie_string().unwrap_or(HeaderValue::from_static(""));
+
+        let request = |api_version: ApiVersion| {
+            let formatted_url = format!(
+                "{}{}{}{}/inventory/{}?sortOrder=Asc&cursor={}&limit={}",
+                VERSIONED_INVENTORY_API_PART_1,
+                api_version.as_str(),
+                VERSIONED_INVENTORY_API_PART_2,
+                user_id,
+                asset_type_id,
+                cursor,
+                limit
+            );
+
+            self.reqwest_client
+                .get(formatted_url)
+                .header(header::COOKIE, cookie_string.clone())
+        };
+
+        let response = self.send_versioned_request(ApiVersion::V2, request).await?;
+        let raw = Self::parse_to_raw::<request_types::InventoryResponse>(response).await?;
+
+        let items = raw
+            .data
+            .into_iter()
+            .map(|item| InventoryItem {
+                asset_id: AssetId(item.asset_id),
+                uaid: Uaid(item.user_asset_id),
+                name: item.name,
+                serial_number: item.serial_number,
+                created: item.created,
+            })
+            .collect();
+
+        Ok((items, raw.next_page_cursor))
+    }
+
+    /// Grabs the collectibles (limiteds and limited u items) held by a user from
+    /// <https://inventory.roblox.com/v1/users/{user_id}/assets/collectibles?sortOrder=Asc&cursor={cursor}&limit={limit}>.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    /// * Will return an error if the user's inventory is private.
+    /// * Falls back to the v2 endpoint if the v1 endpoint returns a 404 or 410 status code,
+    ///   unless a version is pinned using [`ClientBuilder::pin_api_version`](crate::ClientBuilder::pin_api_version).
+    ///
+    /// # Argument Notes
+    /// * The cursor is used to get the a certain page of results. If you want the starting page, use `None`.
+    ///
+    /// # Return Value Notes
+    /// * The first value is a vector of collectibles.
+    /// * The second value is the cursor for the next page of results. If there are no more pages, this will be `None`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ids::UserId;
+    /// use roboat::ClientBuilder;
+    /// use roboat::Limit;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let user_id = UserId(2207291);
+    ///
+    /// let (collectibles, next_page_cursor) =
+    ///     client.user_collectibles(user_id, Limit::Hundred, None).await?;
+    ///
+    /// for collectible in collectibles {
+    ///     println!("{} (uaid {})", collectible.name, collectible.uaid);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn user_collectibles(
+        &self,
+        user_id: UserId,
+        limit: Limit,
+        cursor: Option<String>,
+    ) -> Result<(Vec<Collectible>, Option<String>), RoboatError> {
+        let limit = limit.to_u64();
+        let cursor = cursor.unwrap_or_default();
+
+        let cookie_string = self.cookie_string().unwrap_or(HeaderValue::from_static(""));
+
+        let request = |api_version: ApiVersion| {
+            let formatted_url = format!(
+                "{}{}{}{}/assets/collectibles?sortOrder=Asc&cursor={}&limit={}",
+                VERSIONED_INVENTORY_API_PART_1,
+                api_version.as_str(),
+                VERSIONED_INVENTORY_API_PART_2,
+                user_id,
+                cursor,
+                limit
+            );
+
+            self.reqwest_client
+                .get(formatted_url)
+                .header(header::COOKIE, cookie_string.clone())
+        };
+
+        let response = self.send_versioned_request(ApiVersion::V1, request).await?;
+        let raw = Self::parse_to_raw::<request_types::CollectiblesResponse>(response).await?;
+
+        let collectibles = raw
+            .data
+            .into_iter()
+            .map(|collectible| Collectible {
+                uaid: Uaid(collectible.user_asset_id),
+                asset_id: AssetId(collectible.asset_id),
+                name: collectible.name,
+                serial_number: collectible.serial_number,
+                recent_average_price: collectible.recent_average_price,
+                original_price: collectible.original_price,
+                asset_stock: collectible.asset_stock,
+                is_on_hold: collectible.is_on_hold,
+            })
+            .collect();
+
+        Ok((collectibles, raw.next_page_cursor))
+    }
+
+    /// Values every collectible held by a user, returning the totals along with a
+    /// per-item breakdown.
+    ///
+    /// The value of each collectible comes from `value_provider`, which is usually backed by a
+    /// third-party value list. If the provider returns `None` for a collectible, its recent
+    /// average price is used instead (or 0 if it has none).
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    /// * Pages through the entire collectibles inventory using [`Client::user_collectibles`].
+    ///
+    /// # Return Value Notes
+    /// * Items are sorted by value, highest first.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ids::{AssetId, UserId};
+    /// use roboat::ClientBuilder;
+    /// use std::collections::HashMap;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let user_id = UserId(2207291);
+    ///
+    /// // Values from a value list, keyed by asset id.
+    /// let values = HashMap::from([(AssetId(1365767), 1_000_000)]);
+    ///
+    /// let valuation = client
+    ///     .inventory_value(user_id, |collectible| {
+    ///         values.get(&collectible.asset_id).copied()
+    ///     })
+    ///     .await?;
+    ///
+    /// println!("RAP: {}", valuation.total_recent_average_price);
+    /// println!("Value: {}", valuation.total_value);
+    ///
+    /// for item in valuation.items.iter().take(5) {
+    ///     println!("{}: {}", item.collectible.name, item.value);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn inventory_value<F>(
+        &self,
+        user_id: UserId,
+        value_provider: F,
+    ) -> Result<InventoryValuation, RoboatError>
+    where
+        F: Fn(&Collectible) -> Option<u64>,
+    {
+        let collectibles = self.all_user_collectibles(user_id).await?;
+
+        let mut items = collectibles
+            .into_iter()
+            .map(|collectible| {
+                let value = value_provider(&collectible)
+                    .or(collectible.recent_average_price)
+                    .unwrap_or_default();
+
+                CollectibleValuation { collectible, value }
+            })
+            .collect::<Vec<_>>();
+
+        items.sort_by_key(|item| std::cmp::Reverse(item.value));
+
+        let total_recent_average_price = items
+            .iter()
+            .filter_map(|item| item.collectible.recent_average_price)
+            .sum();
+
+        let total_value = items.iter().map(|item| item.value).sum();
+
+        let valuation = InventoryValuation {
+            total_recent_average_price,
+            total_value,
+            items,
+        };
+
+        Ok(valuation)
+    }
+
+    /// Finds every collectible that a user holds more than one copy (uaid) of.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    /// * Pages through the entire collectibles inventory using [`Client::user_collectibles`].
+    ///
+    /// # Return Value Notes
+    /// * Items are sorted by the amount of copies held, most first.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ids::UserId;
+    /// use roboat::ClientBuilder;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let user_id = UserId(2207291);
+    ///
+    /// let duplicates = client.duplicate_collectibles(user_id).await?;
+    ///
+    /// for duplicate in duplicates {
+    ///     let serials = duplicate
+    ///         .copies
+    ///         .iter()
+    ///         .map(|copy| copy.serial_number)
+    ///         .collect::<Vec<_>>();
+    ///
+    ///     println!("{} x{} (serials {:?})", duplicate.name, duplicate.copies.len(), serials);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn duplicate_collectibles(
+        &self,
+        user_id: UserId,
+    ) -> Result<Vec<DuplicateCollectible>, RoboatError> {
+        let collectibles = self.all_user_collectibles(user_id).await?;
+
+        let mut copies_by_asset: HashMap<AssetId, Vec<Collectible>> = HashMap::new();
+
+        for collectible in collectibles {
+            copies_by_asset
+                .entry(collectible.asset_id)
+                .or_default()
+                .push(collectible);
+        }
+
+        let mut duplicates = copies_by_asset
+            .into_iter()
+            .filter(|(_, copies)| copies.len() > 1)
+            .map(|(asset_id, mut copies)| {
+                copies.sort_by_key(|copy| copy.serial_number);
+
+                DuplicateCollectible {
+                    asset_id,
+                    name: copies[0].name.clone(),
+                    copies,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        // Sorted by asset id first so that items with the same amount of copies have a stable order.
+        duplicates.sort_by_key(|duplicate| duplicate.asset_id);
+        duplicates.sort_by_key(|duplicate| std::cmp::Reverse(duplicate.copies.len()));
+
+        Ok(duplicates)
+    }
+
+    /// Deletes an item from the inventory of the current account using
+    /// <https://inventory.roblox.com/v2/inventory/asset/{asset_id}>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Only free items can be deleted. Deleted items can be taken again from the catalog.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ids::AssetId;
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let asset_id = AssetId(1234567890);
+    /// client.delete_inventory_item(asset_id).await?;
+    ///
+    /// println!("Deleted item {}", asset_id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete_inventory_item(&self, asset_id: AssetId) -> Result<(), RoboatError> {
+        match self.delete_inventory_item_internal(asset_id).await {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.delete_inventory_item_internal(asset_id).await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Deletes multiple items from the inventory of the current account using
+    /// [`Client::delete_inventory_item`], up to 5 at a time.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Only free items can be deleted.
+    ///
+    /// # Return Value Notes
+    /// * A failed deletion does not discard the other deletions. The index of each
+    ///   success and failure is the index of the asset id in `asset_ids`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ids::AssetId;
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let asset_ids = vec![AssetId(1234567890), AssetId(1234567891)];
+    /// let result = client.delete_inventory_items(asset_ids.clone()).await;
+    ///
+    /// for (i, e) in result.failures {
+    ///     println!("Failed to delete {}: {}", asset_ids[i], e);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete_inventory_items(&self, asset_ids: Vec<AssetId>) -> BatchResult<()> {
+        stream::iter(asset_ids)
+            .map(|asset_id| self.delete_inventory_item(asset_id))
+            .buffered(MAX_CONCURRENT_DELETIONS)
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .collect()
+    }
+
+    /// Pages through every collectible held by a user.
+    pub(crate) async fn all_user_collectibles(
+        &self,
+        user_id: UserId,
+    ) -> Result<Vec<Collectible>, RoboatError> {
+        all_pages(None, |cursor| {
+            self.user_collectibles(user_id, Limit::Hundred, cursor)
+        })
+        .await
+    }
+}
+
+mod internal {
+    use super::DELETE_ASSET_API;
+    use crate::ids::AssetId;
+    use crate::{Client, RoboatError, XCSRF_HEADER};
+    use reqwest::header;
+
+    impl Client {
+        pub(super) async fn delete_inventory_item_internal(
+            &self,
+            asset_id: AssetId,
+        ) -> Result<(), RoboatError> {
+            let formatted_url = format!("{}{}", DELETE_ASSET_API, asset_id);
+
+            let cookie = self.cookie_string()?;
+
+            let request = self
+                .reqwest_client
+                .delete(formatted_url)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, self.xcsrf().await);
+
+            let _ = self
+                .send_mutating_request(request, serde_json::json!({}))
+                .await?;
+
+            // We don't need to do anything, we just need a 200 status code.
+
+            Ok(())
+        }
+    }
+}
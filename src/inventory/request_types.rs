@@ -0,0 +1,47 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct CollectiblesResponse {
+    pub previous_page_cursor: Option<String>,
+    pub next_page_cursor: Option<String>,
+    pub data: Vec<CollectibleRaw>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct CollectibleRaw {
+    pub user_asset_id: u64,
+    pub serial_number: Option<u64>,
+    pub asset_id: u64,
+    pub name: String,
+    pub recent_average_price: Option<u64>,
+    pub original_price: Option<u64>,
+    pub asset_stock: Option<u64>,
+    pub is_on_hold: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct InventoryResponse {
+    pub previous_page_cursor: Option<String>,
+    pub next_page_cursor: Option<String>,
+    pub data: Vec<InventoryItemRaw>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct InventoryItemRaw {
+    pub asset_id: u64,
+    pub name: String,
+    pub user_asset_id: u64,
+    pub serial_number: Option<u64>,
+    pub created: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct CanViewInventoryResponse {
+    pub can_view: bool,
+}
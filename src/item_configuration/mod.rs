@@ -0,0 +1,84 @@
+use crate::catalog::avatar_catalog::AssetType;
+use crate::{Client, RoboatError};
+use reqwest::header;
+use serde::{Deserialize, Serialize};
+
+mod request_types;
+
+const COLLECTIBLES_METADATA_API: &str =
+    "https://itemconfiguration.roblox.com/v1/collectibles/metadata";
+
+/// The lowest price an item of a certain asset type can be sold for. Retrieved from
+/// <https://itemconfiguration.roblox.com/v1/collectibles/metadata>.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct PriceFloor {
+    /// The asset type the price floor applies to.
+    pub asset_type: AssetType,
+    /// The minimum price in robux.
+    pub price_floor: u64,
+}
+
+impl Client {
+    /// Grabs the current price floors of clothing and accessories from
+    /// <https://itemconfiguration.roblox.com/v1/collectibles/metadata>.
+    ///
+    /// Roblox changes these from time to time, so pricing tools should use this instead
+    /// of hardcoding the minimum prices.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    ///
+    /// # Return Value Notes
+    /// * Price floors are sorted by asset type.
+    /// * Asset types that Roblox returns but are not in [`AssetType`] are left out.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let price_floors = client.clothing_price_floors().await?;
+    ///
+    /// for price_floor in price_floors {
+    ///     println!("{:?}: {} robux", price_floor.asset_type, price_floor.price_floor);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn clothing_price_floors(&self) -> Result<Vec<PriceFloor>, RoboatError> {
+        let cookie = self.cookie_string()?;
+
+        let request = self
+            .reqwest_client
+            .get(COLLECTIBLES_METADATA_API)
+            .header(header::COOKIE, cookie);
+
+        let response = self.send_request(request).await?;
+        let raw =
+            Self::parse_to_raw::<request_types::CollectiblesMetadataResponse>(response).await?;
+
+        let mut price_floors = raw
+            .unlimited_item_price_floors
+            .into_iter()
+            .filter_map(|(asset_type, price_floor)| {
+                let asset_type =
+                    serde_json::from_value::<AssetType>(serde_json::Value::String(asset_type))
+                        .ok()?;
+
+                Some(PriceFloor {
+                    asset_type,
+                    price_floor: price_floor.price_floor?,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        price_floors.sort();
+
+        Ok(price_floors)
+    }
+}
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct CollectiblesMetadataResponse {
+    /// A map of asset type names (such as `TShirt`) to their price floor.
+    pub unlimited_item_price_floors: HashMap<String, PriceFloorRaw>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct PriceFloorRaw {
+    pub price_floor: Option<u64>,
+}
@@ -9,23 +9,124 @@
 //! All public methods in this crate are documented and have at least one corresponding example.
 //!
 //! # Covered Endpoints
+//! * Abuse Reporting API
+//!   - Report User - [`Client::report_user`]
+//!   - Report Asset - [`Client::report_asset`]
+//! * Asset Delivery API
+//!   - Download Asset - [`Client::download_asset`]
+//!   - Asset Dependency Graph - [`Client::asset_dependency_graph`]
+//! * Auth API
+//!   - Username Available - [`Client::username_available`]
+//! * Avatar API
+//!   - Wearing Assets - [`Client::wearing_assets`]
+//!   - Set Wearing Assets - [`Client::set_wearing_assets`]
+//!   - Wear/Unwear Asset - [`Client::wear_asset`] and [`Client::unwear_asset`]
+//! * Billing API
+//!   - Credit Balance - [`Client::billing_balance`]
 //! * Catalog API
-//!    - Item Details - [`Client::item_details`]
+//!    - Item Details - [`Client::item_details`] and [`Client::try_item_details`]
+//!    - Resolve Item Ids - [`Client::resolve_item_ids`]
+//!    - Avatar Catalog Search - [`Client::avatar_catalog_search`]
+//!    - Catalog Search - [`Client::search_catalog`]
+//!    - Bundle Details - [`Client::bundle_details`]
+//!    - Bundle Contents - [`Client::bundle_contents`]
+//! * Cloud Operations API
+//!   - Wait For Operation - [`Client::wait_for_operation`]
+//! * Develop API
+//!   - Can Manage Place - [`Client::can_manage_place`]
+//!   - Authorized Universes - [`Client::authorized_universes`]
+//!   - Set Game Thumbnail Order - [`Client::set_game_thumbnail_order`]
 //! * Economy API
 //!   - Robux Balance - [`Client::robux`]
 //!   - Resellers - [`Client::resellers`]
+//!   - Resale Data - [`Client::resale_data`]
+//!   - Sales Velocity - [`Client::sales_velocity`]
+//!   - Asset Version Metadata - [`Client::asset_version_metadata`]
+//!   - Remaining Stock - [`Client::watch_remaining`]
 //!   - User Sales - [`Client::user_sales`]
+//!   - Pending Robux - [`Client::pending_robux`]
+//!   - Group Funds - [`Client::group_funds`]
+//!   - Commission Sales - [`Client::commission_sales`]
+//!   - Transactions - [`Client::transactions`]
+//!   - Billing History - [`Client::billing_history`]
+//!   - Group Transactions - [`Client::group_transactions`]
+//!   - Group Revenue Summary - [`Client::group_revenue_summary`]
 //!   - Put Limited On Sale - [`Client::put_limited_on_sale`]
 //!   - Take Limited Off Sale - [`Client::take_limited_off_sale`]
-//!   - Purchase Limited - [`Client::purchase_limited`]
+//!   - Purchase Limited - [`Client::purchase_limited`], [`Client::purchase_limited_for_group`], and [`Client::purchase_limited_with_retries`]
+//!   - Purchase Product - [`Client::purchase_product`]
 //! * Users API
 //!   - User Details - [`Client::user_id`], [`Client::username`], and [`Client::display_name`]
-//! (all of them use the same endpoint internally and cache the results)
+//!     (all of them use the same endpoint internally and cache the results)
+//!   - Who Am I - [`Client::whoami`]
 //!   - User Search - [`Client::user_search`]
+//!   - Ranked User Search - [`Client::search_users_ranked`]
+//! * Engagement Payouts API
+//!   - Premium Payouts - [`Client::premium_payouts`]
+//! * Friends API
+//!   - Friends List - [`Client::friends_list`]
+//!   - Friends Owning Asset - [`Client::friends_owning_asset`]
+//!   - Friends Online - [`Client::friends_online`]
+//!   - Friend Recommendations - [`Client::friend_recommendations`]
+//!   - Friend Requests - [`Client::friend_requests`]
+//!   - Accept/Decline Friend Request - [`Client::accept_friend_request`] and [`Client::decline_friend_request`]
+//!   - Send Friend Request - [`Client::send_friend_request`]
+//!   - Unfriend - [`Client::unfriend`]
+//!   - Followers/Followings - [`Client::followers`] and [`Client::followings`]
+//!   - Follow/Unfollow - [`Client::follow`] and [`Client::unfollow`]
+//! * Games API
+//!   - Universe Stats - [`Client::universe_stats`]
+//!   - Record Player Counts - [`Client::record_player_counts`]
+//! * Groups API
+//!   - Group Details - [`Client::group_details`]
+//!   - Group Roles - [`Client::group_roles`]
+//!   - Recent Group Joins - [`Client::recent_group_joins`]
+//!   - Group Allies - [`Client::group_allies`]
+//!   - Group Enemies - [`Client::group_enemies`]
+//!   - Add Group Relationship - [`Client::add_group_relationship`]
+//!   - Remove Group Relationship - [`Client::remove_group_relationship`]
+//!   - Set Group Icon - [`Client::set_group_icon`]
+//!   - Set Member Role - [`Client::set_member_role`]
+//!   - Kick Member - [`Client::kick_member`]
+//!   - Group Bans - [`Client::group_bans`]
+//!   - Ban/Unban Member - [`Client::ban_member`] and [`Client::unban_member`]
+//!   - Join Requests - [`Client::join_requests`] and [`Client::handle_join_request`]
+//!   - Group Payout - [`Client::group_payout`]
+//!   - Group Audit Log - [`Client::group_audit_log`] and [`Client::watch_group_audit_log`]
+//!   - Group Member Count - [`Client::group_member_count`] and [`Client::watch_group_growth`]
+//! * HBA Service API
+//!   - Server Nonce - [`Client::hba_server_nonce`]
+//! * Inventory API
+//!   - User Owns Asset - [`Client::user_owns_asset`] and [`Client::users_own_asset`]
+//!   - Can View Inventory - [`Client::can_view_inventory`]
+//!   - User Inventory - [`Client::user_inventory`]
+//!   - User Collectibles - [`Client::user_collectibles`]
+//!   - Inventory Value - [`Client::inventory_value`]
+//!   - Duplicate Collectibles - [`Client::duplicate_collectibles`]
+//!   - Delete Inventory Item - [`Client::delete_inventory_item`] and [`Client::delete_inventory_items`]
+//! * Item Configuration API
+//!   - Clothing Price Floors - [`Client::clothing_price_floors`]
+//! * Messaging Service API
+//!   - Broadcast To Game - [`Client::broadcast_to_game`]
 //! * Presence API
 //!   - Register Presence - [`Client::register_presence`]
+//! * Publish API
+//!   - Upload Game Icon - [`Client::upload_game_icon`]
+//! * Thumbnails API
+//!   - Thumbnails - [`Client::thumbnails`]
+//!   - Download Thumbnail (with disk caching) - [`Client::download_thumbnail`]
+//!   - Avatar 3D Model - [`Client::avatar_3d`]
 //! * Trades API
 //!   - Trades List - [`Client::trades`]
+//!   - Inbound/Outbound/Completed Trades - [`Client::inbound_trades`], [`Client::outbound_trades`], and [`Client::completed_trades`]
+//!   - Accept/Decline/Counter Trade - [`Client::accept_trade`], [`Client::decline_trade`], and [`Client::counter_trade`]
+//!   - Trade Details - [`Client::trade_details`]
+//!   - Send Trade - [`Client::send_trade`]
+//!   - Decline Stale Inbound Trades - [`Client::decline_stale_inbound_trades`]
+//!   - Cancel Outbound Trade - [`Client::cancel_outbound_trade`] and [`Client::cancel_all_outbound`]
+//! * User Restrictions API
+//!   - Ban/Unban User From Universe - [`Client::ban_user_from_universe`] and [`Client::unban_user_from_universe`]
+//!   - Universe User Restrictions - [`Client::universe_user_restrictions`]
 //!
 //! # Quick Start Examples
 //!
@@ -72,7 +173,7 @@
 //!         .roblosecurity(ROBLOSECURITY.to_string())
 //!         .build();
 //!
-//!     let item_id = 1365767;
+//!     let item_id = roboat::ids::AssetId(1365767);
 //!     let limit = roboat::Limit::Ten;
 //!     let cursor = None;
 //!
@@ -121,26 +222,100 @@
 // Re-export reqwest so people can use the correct version.
 pub use reqwest;
 
-pub use client::{Client, ClientBuilder};
-pub use economy::PurchaseLimitedError;
+pub use batch::BatchResult;
+pub use client::{
+    ApiVersion, CircuitBreakerConfig, Client, ClientBuilder, ClientConfig, IpVersion,
+};
+pub use debug_capture::{CapturedExchange, DebugSink};
+pub use economy::{PurchaseError, PurchaseLimitedError};
+pub use scope::Scope;
+pub use shared_cache::SharedCache;
+pub use signing::RequestSigner;
+pub use stats::ClientStats;
 
+/// A module for endpoints prefixed with <https://apis.roblox.com/abuse-reporting/*>.
+pub mod abuse_reporting;
+/// A module for endpoints prefixed with <https://assetdelivery.roblox.com/*>.
+pub mod asset_delivery;
+/// A module for endpoints prefixed with <https://auth.roblox.com/*>.
+pub mod auth;
+/// A module for endpoints prefixed with <https://avatar.roblox.com/*>.
+pub mod avatar;
+mod batch;
+/// A module for endpoints prefixed with <https://billing.roblox.com/*>.
+pub mod billing;
+/// A module for helpers that cancel operations, such as at a deadline.
+pub mod cancellation;
 /// A module for endpoints prefixed with <https://catalog.roblox.com/*>.
 pub mod catalog;
+mod circuit_breaker;
 mod client;
+/// A module for long-running Open Cloud operations and other types shared by Open Cloud endpoints.
+pub mod cloud;
+mod config_loader;
+/// A module for crash reports saved when a `watch_*` helper panics.
+pub mod crash;
+mod debug_capture;
+/// A module for endpoints prefixed with <https://develop.roblox.com/*>.
+pub mod develop;
 /// A module for endpoints prefixed with <https://economy.roblox.com/*>.
 pub mod economy;
+/// A module for endpoints prefixed with <https://engagementpayouts.roblox.com/*>.
+pub mod engagement_payouts;
+/// A module for endpoints prefixed with <https://friends.roblox.com/*>.
+pub mod friends;
+/// A module for endpoints prefixed with <https://games.roblox.com/*>.
+pub mod games;
+/// A module for endpoints prefixed with <https://groups.roblox.com/*>.
+pub mod groups;
+/// A module for hardware-backed authentication (HBA), including the endpoint
+/// <https://apis.roblox.com/hba-service/*>.
+#[cfg(feature = "hba")]
+pub mod hba;
+/// A module for recording player count history using the games endpoints.
+pub mod history;
+/// A module for typed id newtypes, such as [`ids::AssetId`] and [`ids::Uaid`].
+pub mod ids;
+/// A module for endpoints prefixed with <https://inventory.roblox.com/*>.
+pub mod inventory;
+/// A module for endpoints prefixed with <https://itemconfiguration.roblox.com/*>.
+pub mod item_configuration;
+/// A module for requesting responses in a specific language for part of a program.
+pub mod locale;
+/// A module for endpoints prefixed with <https://apis.roblox.com/messaging-service/*>.
+mod messaging;
+/// A module for helpers that collect every page of a paginated endpoint.
+pub mod pagination;
+/// A module for helpers that control how often `watch_*` helpers poll.
+pub mod polling;
 /// A module for endpoints prefixed with <https://presence.roblox.com/*>.
 mod presence;
+/// A module for pricing helpers that do not call any endpoints.
+pub mod pricing;
+/// A module for endpoints prefixed with <https://publish.roblox.com/*>.
+mod publish;
+mod rate_limit;
+mod response_cache;
+/// A module for backoff and retry helpers used to space out repeated attempts.
+pub mod retry;
+/// A module for finding differences between the responses Roblox sends and the structs
+/// they are parsed into. Enabled with the `schema-drift` feature.
+#[cfg(feature = "schema-drift")]
+pub mod schema_drift;
+mod scope;
+mod shared_cache;
+mod signing;
+mod stats;
+/// A module for endpoints prefixed with <https://thumbnails.roblox.com/*>.
+pub mod thumbnails;
 /// A module for endpoints prefixed with <https://trades.roblox.com/*>.
 pub mod trades;
+/// A module for endpoints prefixed with <https://apis.roblox.com/cloud/v2/universes/{universe_id}/user-restrictions>.
+pub mod user_restrictions;
 /// A module for endpoints prefixed with <https://users.roblox.com/*>.
 pub mod users;
 mod validation;
 
-// todo: add manual xcsrf refresh
-// todo: endpoints that require premium/robux to test: recent trades, send trade, buy limited item, buy non-limited item
-// todo: inventory api, groups api, follow api
-// todo: add usage to readme
 // todo: every type should have an explanation of the typical means by which the user will construct or fetch it, if the answer isn't “this is a struct literal with public methods”.
 // todo: figure out authtickets
 // todo: add ugc limited buying
@@ -153,6 +328,8 @@ use serde::{Deserialize, Serialize};
 
 // Used in reqwest header keys.
 const XCSRF_HEADER: &str = "x-csrf-token";
+// The header Open Cloud endpoints read the api key from.
+const API_KEY_HEADER: &str = "x-api-key";
 // The user agent used for fussy endpoints.
 const USER_AGENT: &str =
     "Mozilla/5.0 (Windows NT 10.0; Win64; x64; rv:101.0) Gecko/20100101 Firefox/101.0";
@@ -222,6 +399,14 @@ pub enum RoboatError {
     /// Used when no roblosecurity is set, on an endpoint that requires it.
     #[error("Roblosecurity Not Set")]
     RoblosecurityNotSet,
+    /// Used when no Open Cloud api key is set, on an endpoint that requires it.
+    /// Set one using [`ClientBuilder::api_key`].
+    #[error("Api Key Not Set")]
+    ApiKeyNotSet,
+    /// Used when an Open Cloud operation finishes with an error, such as in
+    /// [`Client::wait_for_operation`]. Contains the error of the operation.
+    #[error("Operation Failed ({}): {}", .0.code, .0.message)]
+    OperationFailed(cloud::OperationError),
     /// Used for any status codes that do not fit any enum variants of this error.
     /// If you encounter this enum variant, please submit an issue so a variant can be
     /// made or the crate can be fixed.
@@ -239,10 +424,88 @@ pub enum RoboatError {
     /// a new xcsrf.
     #[error("Missing Xcsrf")]
     XcsrfNotReturned,
+    /// Used when Roblox wants a challenge (such as two step verification) to be completed
+    /// before it allows a request. Contains the challenge type Roblox gave, such as
+    /// `twostepverification`. Challenges have to be completed on the website.
+    #[error("Challenge Required: {0}")]
+    ChallengeRequired(String),
+    /// Used when requests to a host are blocked by the circuit breaker after too many
+    /// failures in a row. See [`ClientBuilder::circuit_breaker`].
+    #[error("Circuit Open For {host}, Retry After {retry_after:?}")]
+    CircuitOpen {
+        /// The host requests are blocked for.
+        host: String,
+        /// How long until requests to the host are let through again.
+        retry_after: std::time::Duration,
+    },
+    /// Used when an operation does not finish before its deadline, such as in
+    /// [`cancellation::with_deadline`] and [`pagination::all_pages_with_deadline`].
+    #[error("Deadline Exceeded")]
+    DeadlineExceeded,
+    /// Used when a key given to [`hba::HbaSigner`] is not a valid P-256 private key.
+    #[cfg(feature = "hba")]
+    #[error("Invalid HBA Key")]
+    InvalidHbaKey,
+    /// Used when a [`thumbnails::ThumbnailArgs`] has a size or format that Roblox does not
+    /// accept for its thumbnail type.
+    #[error("Unsupported Thumbnail Args {0:?}")]
+    UnsupportedThumbnailArgs(thumbnails::ThumbnailArgs),
+    /// Used when Roblox refuses to wear some assets in [`Client::set_wearing_assets`]
+    /// (such as assets that are not owned). Contains the ids of the refused assets.
+    #[error("Invalid Wearing Assets {0:?}")]
+    InvalidWearingAssets(Vec<ids::AssetId>),
+    /// Used when a listing price is below the price floor of the item's asset type, such as in
+    /// [`Client::validate_listing_price`] and [`Client::put_limited_on_sale`].
+    #[error("Below Price Floor Of {floor}")]
+    BelowPriceFloor {
+        /// The lowest price the item can be listed for.
+        floor: u64,
+    },
+    /// Used when an endpoint that changes something on Roblox is called on a client in
+    /// read only mode. See [`ClientBuilder::read_only`].
+    #[error("Read Only Mode")]
+    ReadOnlyMode,
+    /// Used when a purchased copy does not show up in the inventory in time, such as in
+    /// [`Client::buy_and_relist`].
+    #[error("Purchase Not In Inventory")]
+    PurchaseNotInInventory,
+    /// Used when a message is too large to be published, such as in [`Client::broadcast_to_game`].
+    #[error("Message Too Large ({size} > {limit} bytes)")]
+    MessageTooLarge {
+        /// The size of the serialized message in bytes.
+        size: usize,
+        /// The largest size allowed, in bytes.
+        limit: usize,
+    },
+    /// Used when a message cannot be published, such as in [`Client::broadcast_to_game`]
+    /// when the topic is empty or too long, or in [`Client::report_user`] when the comment
+    /// is too long. Contains the reason.
+    #[error("Invalid Message: {0}")]
+    InvalidMessage(String),
     /// Custom Roblox errors sometimes thrown when the user calls [`Client::purchase_limited`].
     #[error("{0}")]
     PurchaseLimitedError(PurchaseLimitedError),
+    /// Custom Roblox errors sometimes thrown when the user calls [`Client::purchase_product`].
+    #[error("{0}")]
+    PurchaseError(PurchaseError),
+    /// Custom Roblox errors sometimes thrown when acting on a trade, such as in [`Client::accept_trade`].
+    #[error("{0}")]
+    TradeError(trades::TradeError),
+    /// Custom Roblox errors sometimes thrown when the user calls [`Client::group_payout`].
+    #[error("{0}")]
+    GroupPayoutError(groups::GroupPayoutError),
+    /// Used when a `watch_*` helper or a task in [`Client::scope`] panics. Contains the
+    /// report that was saved to the [`StateStore`](crash::StateStore), if one was set.
+    #[error("Watcher Crashed: {} panicked with \"{}\"", .0.watcher, .0.panic_message)]
+    WatcherCrashed(Box<crash::CrashReport>),
+    /// Used when a configuration loaded by [`ClientBuilder::from_env`] or
+    /// [`ClientBuilder::from_toml`] cannot be parsed. Contains the reason.
+    #[error("Invalid Config: {0}")]
+    InvalidConfig(String),
     /// Used for any reqwest error that occurs.
     #[error("RequestError {0}")]
     ReqwestError(reqwest::Error),
+    /// Used for any IO error that occurs (such as when reading or writing cached files).
+    #[error("IoError {0}")]
+    IoError(std::io::Error),
 }
@@ -0,0 +1,53 @@
+use reqwest::header::HeaderValue;
+use std::future::Future;
+
+tokio::task_local! {
+    static LOCALE_OVERRIDE: HeaderValue;
+}
+
+/// Runs `future` with every request it sends asking for responses in `locale` (such as
+/// `es-es` or `ja-jp`), overriding the locale set using
+/// [`ClientBuilder::locale`](crate::ClientBuilder::locale).
+///
+/// This is useful for localization QA tools that need the same item in several languages
+/// without building a client for each one.
+///
+/// # Notes
+/// * The locale is sent in the `Accept-Language` header. Endpoints that are not localized ignore it.
+/// * Only requests sent from inside `future` are affected, even if the client is shared
+///   with other tasks.
+///
+/// # Panics
+/// * If `locale` contains characters that are not allowed in a header.
+///
+/// # Example
+/// ```no_run
+/// use roboat::catalog::avatar_catalog::{ItemArgs, ItemType};
+/// use roboat::locale::with_locale;
+/// use roboat::ClientBuilder;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = ClientBuilder::new().build();
+///
+/// let items = vec![ItemArgs {
+///     item_type: ItemType::Asset,
+///     id: 1365767,
+/// }];
+///
+/// let spanish = with_locale("es-es", client.item_details(items.clone())).await?;
+/// let japanese = with_locale("ja-jp", client.item_details(items)).await?;
+///
+/// println!("{} / {}", spanish[0].name, japanese[0].name);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn with_locale<F: Future>(locale: &str, future: F) -> F::Output {
+    let header = HeaderValue::from_str(locale).expect("Invalid locale characters.");
+    LOCALE_OVERRIDE.scope(header, future).await
+}
+
+/// Returns the locale set by [`with_locale`] for the current task, if there is one.
+pub(crate) fn locale_override() -> Option<HeaderValue> {
+    LOCALE_OVERRIDE.try_with(|locale| locale.clone()).ok()
+}
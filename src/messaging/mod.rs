@@ -0,0 +1,112 @@
+use crate::ids::UniverseId;
+use crate::{Client, RoboatError, API_KEY_HEADER};
+use serde::Serialize;
+
+const MESSAGING_API: &str = "https://apis.roblox.com/messaging-service/v1/universes/";
+
+/// The largest message, in bytes, that MessagingService accepts.
+const MESSAGE_SIZE_LIMIT: usize = 1024;
+
+/// The longest topic, in characters, that MessagingService accepts.
+const TOPIC_LENGTH_LIMIT: usize = 80;
+
+impl Client {
+    /// Publishes a message to every running server of a game through MessagingService using
+    /// <https://apis.roblox.com/messaging-service/v1/universes/{universe_id}/topics/{topic}>.
+    ///
+    /// Servers receive it through `MessagingService:SubscribeAsync(topic)`, with the message
+    /// being `message` serialized to a JSON string. This is meant for ops tools, such as
+    /// pushing an announcement or a message of the day into all servers.
+    ///
+    /// # Notes
+    /// * Requires an Open Cloud api key set using [`ClientBuilder::api_key`](crate::ClientBuilder::api_key).
+    ///   The key needs the `universe-messaging-service:publish` permission for the universe.
+    /// * Does not require a valid roblosecurity.
+    /// * Messages are not chunked, as servers would have no way of reassembling them. Messages
+    ///   that do not fit are rejected before anything is sent.
+    ///
+    /// # Argument Notes
+    /// * `topic` can be up to 80 characters long.
+    /// * `message` must serialize to at most 1024 bytes of JSON.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * [`RoboatError::ApiKeyNotSet`] - If no api key is set.
+    /// * [`RoboatError::InvalidRoblosecurity`] - If the api key is invalid or lacks permission.
+    /// * [`RoboatError::MessageTooLarge`] - If `message` is too large once serialized.
+    /// * [`RoboatError::InvalidMessage`] - If `topic` is empty or too long, or if `message` cannot be serialized.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ids::UniverseId;
+    /// use roboat::ClientBuilder;
+    /// use serde_json::json;
+    ///
+    /// const API_KEY: &str = "api_key";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().api_key(API_KEY.to_string()).build();
+    ///
+    /// let universe_id = UniverseId(1234567890);
+    /// let message = json!({ "motd": "Double XP this weekend!" });
+    ///
+    /// client.broadcast_to_game(universe_id, "Announcements", &message).await?;
+    /// println!("Broadcasted message.");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn broadcast_to_game<T: Serialize>(
+        &self,
+        universe_id: UniverseId,
+        topic: &str,
+        message: &T,
+    ) -> Result<(), RoboatError> {
+        let api_key = self.api_key()?;
+
+        let topic_length = topic.chars().count();
+
+        if topic_length == 0 || topic_length > TOPIC_LENGTH_LIMIT {
+            return Err(RoboatError::InvalidMessage(format!(
+                "Topic must be between 1 and {} characters",
+                TOPIC_LENGTH_LIMIT
+            )));
+        }
+
+        let message = serde_json::to_string(message)
+            .map_err(|e| RoboatError::InvalidMessage(e.to_string()))?;
+
+        if message.len() > MESSAGE_SIZE_LIMIT {
+            return Err(RoboatError::MessageTooLarge {
+                size: message.len(),
+                limit: MESSAGE_SIZE_LIMIT,
+            });
+        }
+
+        let mut url = reqwest::Url::parse(&format!("{}{}/topics", MESSAGING_API, universe_id))
+            .map_err(|e| RoboatError::InvalidMessage(e.to_string()))?;
+
+        // Pushing the topic as a path segment percent-encodes it.
+        url.path_segments_mut()
+            .map_err(|_| RoboatError::InvalidMessage("Invalid url".to_string()))?
+            .push(topic);
+
+        let json = serde_json::json!({
+            "message": message,
+        });
+
+        let request = self
+            .reqwest_client
+            .post(url)
+            .header(API_KEY_HEADER, api_key)
+            .json(&json);
+
+        let _ = self
+            .send_mutating_request(request, serde_json::json!({}))
+            .await?;
+
+        // We don't need to do anything, we just need a 200 status code.
+
+        Ok(())
+    }
+}
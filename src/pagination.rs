@@ -0,0 +1,192 @@
+use crate::cancellation::with_deadline;
+use crate::RoboatError;
+use std::future::Future;
+use std::time::Instant;
+
+/// Collects every item from a paginated endpoint by repeatedly calling `fetch` with the
+/// cursor of the next page, until there are no more pages.
+///
+/// `fetch` is usually a closure that calls a paginated [`Client`](crate::Client) method,
+/// such as [`Client::resellers`](crate::Client::resellers).
+///
+/// # Argument Notes
+/// * `start_cursor` is the cursor of the first page to fetch. Use `None` to start from the beginning.
+///
+/// # Errors
+/// * Any error returned by `fetch`. Items collected before the error are discarded; use
+///   [`all_pages_with_checkpoint`] if a long crawl needs to be resumed after an error.
+///
+/// # Example
+/// ```no_run
+/// use roboat::ids::AssetId;
+/// use roboat::pagination::all_pages;
+/// use roboat::{ClientBuilder, Limit};
+///
+/// const ROBLOSECURITY: &str = "roblosecurity";
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+///
+/// let item_id = AssetId(1365767);
+///
+/// let listings = all_pages(None, |cursor| client.resellers(item_id, Limit::Hundred, cursor)).await?;
+/// println!("Total Listings: {}", listings.len());
+/// # Ok(())
+/// # }
+/// ```
+pub async fn all_pages<T, F, Fut>(
+    start_cursor: Option<String>,
+    fetch: F,
+) -> Result<Vec<T>, RoboatError>
+where
+    F: FnMut(Option<String>) -> Fut,
+    Fut: Future<Output = Result<(Vec<T>, Option<String>), RoboatError>>,
+{
+    all_pages_with_checkpoint(start_cursor, fetch, |_, _| {}).await
+}
+
+/// The same as [`all_pages`], but calls `checkpoint` with the items of each page and the
+/// cursor of the next page every time a page is fetched.
+///
+/// Storing the items given to `checkpoint` together with the cursor allows a long crawl
+/// (such as every reseller of a popular item) to be resumed from where it stopped by
+/// passing the cursor as `start_cursor`, without losing the pages fetched before an error.
+///
+/// # Notes
+/// * `checkpoint` is not called for the last page (as there is no next cursor). Its items
+///   are only part of the returned vector.
+/// * The returned vector also contains the items given to `checkpoint`.
+///
+/// # Errors
+/// * Any error returned by `fetch`. Items collected before the error are discarded, except
+///   for the ones already given to `checkpoint`.
+///
+/// # Example
+/// ```no_run
+/// use roboat::ids::AssetId;
+/// use roboat::pagination::all_pages_with_checkpoint;
+/// use roboat::{ClientBuilder, Limit};
+///
+/// const ROBLOSECURITY: &str = "roblosecurity";
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+///
+/// let item_id = AssetId(1365767);
+///
+/// // Resume from the last saved cursor, if there is one.
+/// let start_cursor = std::fs::read_to_string("cursor.txt").ok();
+/// let mut saved_listings = Vec::new();
+///
+/// let result = all_pages_with_checkpoint(
+///     start_cursor,
+///     |cursor| client.resellers(item_id, Limit::Hundred, cursor),
+///     |listings, cursor| {
+///         // Keep the listings together with the cursor that comes after them.
+///         saved_listings.extend_from_slice(listings);
+///         std::fs::write("cursor.txt", cursor).unwrap();
+///     },
+/// )
+/// .await;
+///
+/// match result {
+///     Ok(listings) => println!("Listings Since Last Checkpoint: {}", listings.len()),
+///     Err(e) => println!("Stopped at {} saved listings: {}", saved_listings.len(), e),
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn all_pages_with_checkpoint<T, F, Fut, C>(
+    start_cursor: Option<String>,
+    mut fetch: F,
+    mut checkpoint: C,
+) -> Result<Vec<T>, RoboatError>
+where
+    F: FnMut(Option<String>) -> Fut,
+    Fut: Future<Output = Result<(Vec<T>, Option<String>), RoboatError>>,
+    C: FnMut(&[T], &str),
+{
+    let mut items = Vec::new();
+    let mut cursor = start_cursor;
+
+    loop {
+        let (page, next_cursor) = fetch(cursor).await?;
+
+        match next_cursor {
+            // Roblox sometimes returns an empty string instead of null on the last page.
+            Some(next_cursor) if !next_cursor.is_empty() => {
+                checkpoint(&page, &next_cursor);
+                items.extend(page);
+                cursor = Some(next_cursor);
+            }
+            _ => {
+                items.extend(page);
+                break;
+            }
+        }
+    }
+
+    Ok(items)
+}
+
+/// The same as [`all_pages_with_checkpoint`], but gives up once `deadline` passes.
+///
+/// Combining this with a checkpoint lets a crawl be spread over several time-boxed runs,
+/// as the items and cursor of each fully collected page are given to `checkpoint` before the deadline hits.
+///
+/// # Errors
+/// * [`RoboatError::DeadlineExceeded`] - If `deadline` passes before every page is fetched.
+/// * Any error returned by `fetch`.
+///
+/// # Example
+/// ```no_run
+/// use roboat::ids::AssetId;
+/// use roboat::pagination::all_pages_with_deadline;
+/// use roboat::{ClientBuilder, Limit, RoboatError};
+/// use std::time::{Duration, Instant};
+///
+/// const ROBLOSECURITY: &str = "roblosecurity";
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+///
+/// let item_id = AssetId(1365767);
+/// let deadline = Instant::now() + Duration::from_secs(60);
+/// let mut last_cursor = None;
+///
+/// let result = all_pages_with_deadline(
+///     None,
+///     |cursor| client.resellers(item_id, Limit::Hundred, cursor),
+///     |_, cursor| last_cursor = Some(cursor.to_string()),
+///     deadline,
+/// )
+/// .await;
+///
+/// match result {
+///     Ok(listings) => println!("Total Listings: {}", listings.len()),
+///     Err(RoboatError::DeadlineExceeded) => println!("Ran out of time, resume from {:?}", last_cursor),
+///     Err(e) => return Err(e.into()),
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn all_pages_with_deadline<T, F, Fut, C>(
+    start_cursor: Option<String>,
+    fetch: F,
+    checkpoint: C,
+    deadline: Instant,
+) -> Result<Vec<T>, RoboatError>
+where
+    F: FnMut(Option<String>) -> Fut,
+    Fut: Future<Output = Result<(Vec<T>, Option<String>), RoboatError>>,
+    C: FnMut(&[T], &str),
+{
+    with_deadline(
+        deadline,
+        all_pages_with_checkpoint(start_cursor, fetch, checkpoint),
+    )
+    .await
+}
@@ -0,0 +1,192 @@
+use chrono::{DateTime, Timelike, Utc};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// How much the interval of an [`AdaptivePoller`] grows after a poll that found nothing new.
+const IDLE_GROWTH_FACTOR: f64 = 1.25;
+
+/// How much the interval of an [`AdaptivePoller`] grows after a poll that was rate limited.
+const RATE_LIMITED_GROWTH_FACTOR: f64 = 2.0;
+
+/// What happened during a poll. Used by an [`AdaptivePoller`] to decide the next interval.
+#[derive(
+    Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize, Copy,
+)]
+pub enum PollOutcome {
+    /// The poll found something new.
+    Activity,
+    /// The poll succeeded but found nothing new.
+    #[default]
+    Idle,
+    /// The poll failed with [`RoboatError::TooManyRequests`](crate::RoboatError::TooManyRequests).
+    RateLimited,
+    /// The poll failed for any other reason.
+    Failed,
+}
+
+/// A poll interval that adjusts itself based on how polls go, for watchers that run 24/7.
+///
+/// * Polls that find nothing new slowly stretch the interval.
+/// * Rate limited polls double the interval.
+/// * Polls that find something new reset the interval to the base interval.
+/// * During quiet hours, the interval is multiplied to poll less when little happens.
+///
+/// The interval never goes below the base interval or above the max interval (before the
+/// quiet hours multiplier is applied).
+///
+/// # Example
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use roboat::polling::{AdaptivePoller, PollOutcome};
+/// use std::time::Duration;
+///
+/// let mut poller = AdaptivePoller::new(Duration::from_secs(30))
+///     .max_interval(Duration::from_secs(300))
+///     .quiet_hours(2, 8, 4);
+///
+/// let noon = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+/// let night = Utc.with_ymd_and_hms(2024, 1, 1, 3, 0, 0).unwrap();
+///
+/// assert_eq!(poller.next_interval(noon), Duration::from_secs(30));
+/// assert_eq!(poller.next_interval(night), Duration::from_secs(120));
+///
+/// poller.record(PollOutcome::RateLimited);
+/// assert_eq!(poller.next_interval(noon), Duration::from_secs(60));
+///
+/// poller.record(PollOutcome::Activity);
+/// assert_eq!(poller.next_interval(noon), Duration::from_secs(30));
+///
+/// // Intervals stop growing at Duration::MAX instead of overflowing.
+/// let mut poller = AdaptivePoller::new(Duration::MAX).quiet_hours(2, 8, u32::MAX);
+///
+/// poller.record(PollOutcome::RateLimited);
+/// assert_eq!(poller.next_interval(night), Duration::MAX);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AdaptivePoller {
+    base_interval: Duration,
+    max_interval: Duration,
+    current_interval: Duration,
+    quiet_hours: Option<QuietHours>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct QuietHours {
+    start_hour: u32,
+    end_hour: u32,
+    multiplier: u32,
+}
+
+impl AdaptivePoller {
+    /// Creates a poller that starts at `base_interval`. The max interval defaults to
+    /// ten times the base interval (or [`Duration::MAX`] if that would overflow), and
+    /// there are no quiet hours.
+    pub fn new(base_interval: Duration) -> Self {
+        Self {
+            base_interval,
+            max_interval: base_interval.saturating_mul(10),
+            current_interval: base_interval,
+            quiet_hours: None,
+        }
+    }
+
+    /// Sets the longest interval the poller will stretch to. Values below the base
+    /// interval are treated as the base interval.
+    pub fn max_interval(mut self, max_interval: Duration) -> Self {
+        self.max_interval = max_interval.max(self.base_interval);
+        self
+    }
+
+    /// Multiplies the interval by `multiplier` from `start_hour` (inclusive) to `end_hour`
+    /// (exclusive), in UTC. The range can wrap past midnight, such as `22` to `6`.
+    pub fn quiet_hours(mut self, start_hour: u32, end_hour: u32, multiplier: u32) -> Self {
+        self.quiet_hours = Some(QuietHours {
+            start_hour: start_hour % 24,
+            end_hour: end_hour % 24,
+            multiplier: multiplier.max(1),
+        });
+        self
+    }
+
+    /// Updates the interval based on the outcome of the latest poll.
+    pub fn record(&mut self, outcome: PollOutcome) {
+        self.current_interval = match outcome {
+            PollOutcome::Activity => self.base_interval,
+            PollOutcome::Idle => saturating_mul_f64(self.current_interval, IDLE_GROWTH_FACTOR),
+            PollOutcome::RateLimited => {
+                saturating_mul_f64(self.current_interval, RATE_LIMITED_GROWTH_FACTOR)
+            }
+            PollOutcome::Failed => self.current_interval,
+        }
+        .clamp(self.base_interval, self.max_interval);
+    }
+
+    /// Returns how long to wait before the next poll, if it were to start at `now`.
+    pub fn next_interval(&self, now: DateTime<Utc>) -> Duration {
+        match &self.quiet_hours {
+            Some(quiet_hours) if quiet_hours.contains(now.hour()) => {
+                self.current_interval.saturating_mul(quiet_hours.multiplier)
+            }
+            _ => self.current_interval,
+        }
+    }
+}
+
+/// Multiplies a duration by a factor, returning [`Duration::MAX`] instead of panicking
+/// if the result does not fit.
+fn saturating_mul_f64(duration: Duration, factor: f64) -> Duration {
+    Duration::try_from_secs_f64(duration.as_secs_f64() * factor).unwrap_or(Duration::MAX)
+}
+
+impl QuietHours {
+    fn contains(&self, hour: u32) -> bool {
+        if self.start_hour <= self.end_hour {
+            (self.start_hour..self.end_hour).contains(&hour)
+        } else {
+            hour >= self.start_hour || hour < self.end_hour
+        }
+    }
+}
+
+/// How often a `watch_*` helper (such as [`Client::watch_group_audit_log`](crate::Client::watch_group_audit_log))
+/// polls its endpoint.
+///
+/// A [`Duration`] converts into [`PollInterval::Fixed`], and an [`AdaptivePoller`] converts
+/// into [`PollInterval::Adaptive`], so either can be passed directly.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PollInterval {
+    /// Polls at the same interval forever.
+    Fixed(Duration),
+    /// Polls at an interval that adjusts itself. See [`AdaptivePoller`].
+    Adaptive(AdaptivePoller),
+}
+
+impl PollInterval {
+    /// Updates the interval based on the outcome of the latest poll. Does nothing
+    /// for a fixed interval.
+    pub fn record(&mut self, outcome: PollOutcome) {
+        if let Self::Adaptive(poller) = self {
+            poller.record(outcome);
+        }
+    }
+
+    /// Returns how long to wait before the next poll, if it were to start at `now`.
+    pub fn next_interval(&self, now: DateTime<Utc>) -> Duration {
+        match self {
+            Self::Fixed(interval) => *interval,
+            Self::Adaptive(poller) => poller.next_interval(now),
+        }
+    }
+}
+
+impl From<Duration> for PollInterval {
+    fn from(interval: Duration) -> Self {
+        Self::Fixed(interval)
+    }
+}
+
+impl From<AdaptivePoller> for PollInterval {
+    fn from(poller: AdaptivePoller) -> Self {
+        Self::Adaptive(poller)
+    }
+}
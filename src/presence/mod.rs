@@ -60,16 +60,16 @@ mod internal {
                 "location": "Home",
             });
 
-            let request_result = self
+            let request = self
                 .reqwest_client
                 .post(REGISTER_PRESENCE_API)
                 .header(header::COOKIE, cookie)
                 .header(XCSRF_HEADER, self.xcsrf().await)
-                .json(&json)
-                .send()
-                .await;
+                .json(&json);
 
-            let _ = Self::validate_request_result(request_result).await?;
+            let _ = self
+                .send_mutating_request(request, serde_json::json!({}))
+                .await?;
 
             // We don't care about the response, just that it's a status code 200.
             Ok(())
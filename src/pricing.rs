@@ -0,0 +1,104 @@
+use serde::{Deserialize, Serialize};
+
+/// The market stats of an item used by [`suggest_price`]. These are usually gathered with
+/// [`Client::resellers`](crate::Client::resellers) and [`Client::item_details`](crate::Client::item_details).
+#[derive(Clone, Debug, PartialEq, PartialOrd, Default, Serialize, Deserialize)]
+pub struct ItemStats {
+    /// The recent average price (RAP) of the item, if it has one.
+    pub recent_average_price: Option<u64>,
+    /// The price of the cheapest reseller listing, if the item has any.
+    pub lowest_price: Option<u64>,
+    /// The average amount of sales per day. Use 0.0 if unknown.
+    pub sales_per_day: f64,
+}
+
+/// How [`suggest_price`] should price a listing.
+#[derive(Clone, Debug, PartialEq, PartialOrd, Default, Serialize, Deserialize)]
+pub struct PricingStrategy {
+    /// How badly the item needs to sell, from 0.0 to 1.0. At 0.0 the suggestion is
+    /// the higher of the RAP and the lowest price, and at 1.0 the suggestion undercuts the
+    /// lowest price. Values outside this range are clamped.
+    pub aggressiveness: f64,
+    /// The amount of robux to undercut the lowest price by when fully aggressive.
+    pub undercut: u64,
+    /// The suggestion will never be lower than this.
+    pub minimum_price: u64,
+}
+
+/// How much cheaper than the RAP to go when fully aggressive and there are no listings.
+const NO_LISTINGS_DISCOUNT: f64 = 0.1;
+
+/// Suggests a listing price for an item.
+///
+/// The suggestion sits between a patient price (the higher of the RAP and the lowest price) and
+/// a fast price (just under the lowest price), depending on the aggressiveness of the strategy.
+/// Items that sell often need less of an undercut to sell, so the aggressiveness is reduced
+/// by up to half as the sales per day go up.
+///
+/// Returns `None` if the item has neither a RAP nor any listings.
+///
+/// # Example
+/// ```
+/// use roboat::pricing::{suggest_price, ItemStats, PricingStrategy};
+///
+/// let stats = ItemStats {
+///     recent_average_price: Some(1200),
+///     lowest_price: Some(1000),
+///     sales_per_day: 0.0,
+/// };
+///
+/// let patient = PricingStrategy {
+///     aggressiveness: 0.0,
+///     undercut: 1,
+///     minimum_price: 0,
+/// };
+///
+/// let aggressive = PricingStrategy {
+///     aggressiveness: 1.0,
+///     undercut: 1,
+///     minimum_price: 0,
+/// };
+///
+/// assert_eq!(suggest_price(&stats, &patient), Some(1200));
+/// assert_eq!(suggest_price(&stats, &aggressive), Some(999));
+///
+/// // Items that sell often are undercut less.
+/// let liquid_stats = ItemStats {
+///     sales_per_day: 100.0,
+///     ..stats.clone()
+/// };
+///
+/// assert!(suggest_price(&liquid_stats, &aggressive) > suggest_price(&stats, &aggressive));
+///
+/// // The minimum price is always respected.
+/// let floor_strategy = PricingStrategy {
+///     minimum_price: 1100,
+///     ..aggressive.clone()
+/// };
+///
+/// assert_eq!(suggest_price(&stats, &floor_strategy), Some(1100));
+///
+/// assert_eq!(suggest_price(&ItemStats::default(), &patient), None);
+/// ```
+pub fn suggest_price(item_stats: &ItemStats, strategy: &PricingStrategy) -> Option<u64> {
+    let (patient_price, fast_price) =
+        match (item_stats.recent_average_price, item_stats.lowest_price) {
+            (Some(rap), Some(lowest_price)) => (
+                rap.max(lowest_price),
+                lowest_price.saturating_sub(strategy.undercut),
+            ),
+            (None, Some(lowest_price)) => {
+                (lowest_price, lowest_price.saturating_sub(strategy.undercut))
+            }
+            (Some(rap), None) => (rap, (rap as f64 * (1.0 - NO_LISTINGS_DISCOUNT)) as u64),
+            (None, None) => return None,
+        };
+
+    // Goes from 0.0 (no sales) towards 1.0 (many sales per day).
+    let liquidity = item_stats.sales_per_day.max(0.0) / (item_stats.sales_per_day.max(0.0) + 1.0);
+    let aggressiveness = strategy.aggressiveness.clamp(0.0, 1.0) * (1.0 - liquidity / 2.0);
+
+    let price = patient_price as f64 - (patient_price as f64 - fast_price as f64) * aggressiveness;
+
+    Some((price.round() as u64).max(strategy.minimum_price))
+}
@@ -0,0 +1,101 @@
+use crate::ids::UniverseId;
+use crate::{Client, RoboatError};
+
+mod request_types;
+
+const GAMES_API: &str = "https://publish.roblox.com/v1/games/";
+
+impl Client {
+    /// Uploads a new icon for a game using <https://publish.roblox.com/v1/games/{universe_id}/icon>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * The current account must have permission to edit the universe.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    /// * The new icon goes through moderation before it is shown. Its state can be checked
+    ///   with [`Client::thumbnails`] using [`ThumbnailType::GameIcon`](crate::thumbnails::ThumbnailType::GameIcon).
+    ///
+    /// # Argument Notes
+    /// * `image_bytes` is the raw contents of an image file (png or jpeg).
+    ///
+    /// # Return Value Notes
+    /// * Returns the id of the uploaded icon.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ids::UniverseId;
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let universe_id = UniverseId(1234567890);
+    /// let image_bytes = std::fs::read("icon.png")?;
+    ///
+    /// let icon_id = client.upload_game_icon(universe_id, image_bytes).await?;
+    /// println!("Uploaded Icon: {}", icon_id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn upload_game_icon(
+        &self,
+        universe_id: UniverseId,
+        image_bytes: Vec<u8>,
+    ) -> Result<u64, RoboatError> {
+        match self
+            .upload_game_icon_internal(universe_id, &image_bytes)
+            .await
+        {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.upload_game_icon_internal(universe_id, &image_bytes)
+                        .await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+}
+
+mod internal {
+    use super::{request_types, GAMES_API};
+    use crate::ids::UniverseId;
+    use crate::{Client, RoboatError, XCSRF_HEADER};
+    use reqwest::header;
+    use reqwest::multipart::{Form, Part};
+
+    impl Client {
+        pub(super) async fn upload_game_icon_internal(
+            &self,
+            universe_id: UniverseId,
+            image_bytes: &[u8],
+        ) -> Result<u64, RoboatError> {
+            let formatted_url = format!("{}{}/icon", GAMES_API, universe_id);
+
+            let cookie = self.cookie_string()?;
+
+            let part = Part::bytes(image_bytes.to_vec()).file_name("icon.png");
+            let form = Form::new().part("request.files", part);
+
+            let request = self
+                .reqwest_client
+                .post(formatted_url)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, self.xcsrf().await)
+                .multipart(form);
+
+            let dry_run_body = serde_json::json!({ "targetId": 0 });
+
+            let response = self.send_mutating_request(request, dry_run_body).await?;
+            let raw = Self::parse_to_raw::<request_types::UploadIconResponse>(response).await?;
+
+            Ok(raw.target_id)
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::time::Instant;
+
+/// Spaces requests out evenly so that no more than a set amount are sent per second.
+///
+/// Each request reserves the next free slot and waits for it, so bursts are smoothed out
+/// instead of being sent all at once.
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+    interval: Duration,
+    next_slot: Mutex<Instant>,
+}
+
+impl RateLimiter {
+    /// Creates a limiter that allows `requests_per_second` requests per second.
+    /// A value of 0 is treated as 1.
+    pub(crate) fn new(requests_per_second: u32) -> Self {
+        Self {
+            interval: Duration::from_secs(1) / requests_per_second.max(1),
+            next_slot: Mutex::new(Instant::now()),
+        }
+    }
+
+    /// Waits until the next request is allowed to be sent.
+    pub(crate) async fn wait(&self) {
+        let mut next_slot = self.next_slot.lock().await;
+
+        let slot = (*next_slot).max(Instant::now());
+        *next_slot = slot + self.interval;
+
+        // Release the lock before sleeping so other requests can reserve the slots after this one.
+        drop(next_slot);
+
+        tokio::time::sleep_until(slot).await;
+    }
+}
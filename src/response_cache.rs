@@ -0,0 +1,130 @@
+use bytes::Bytes;
+use reqwest::header::{self, HeaderMap};
+use reqwest::{Request, Response, StatusCode};
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// The maximum amount of responses kept in a [`ResponseCache`]. Once full, an
+/// arbitrary entry is evicted to make room for a new one.
+const MAX_CACHED_RESPONSES: usize = 1000;
+
+/// A cache of responses that came with a validator (an `ETag` or `Last-Modified` header).
+///
+/// When a request is sent again, the validators are attached as `If-None-Match` and
+/// `If-Modified-Since` headers. If Roblox replies with a status code 304, the cached response
+/// is used instead, which saves downloading the same body over and over on polled endpoints.
+#[derive(Debug, Default)]
+pub(crate) struct ResponseCache {
+    entries: Mutex<HashMap<CacheKey, CachedResponse>>,
+}
+
+/// The method, url, and body of a request.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct CacheKey {
+    method: String,
+    url: String,
+    body: Vec<u8>,
+}
+
+#[derive(Debug, Clone)]
+struct CachedResponse {
+    headers: HeaderMap,
+    body: Bytes,
+}
+
+impl CacheKey {
+    /// Creates a key for a request, or returns `None` if the request cannot be cached.
+    ///
+    /// Requests that already carry their own validators (such as
+    /// [`Client::download_thumbnail`](crate::Client::download_thumbnail)) and requests with a
+    /// streamed body (such as multipart uploads) are not cached.
+    pub(crate) fn new(request: &Request) -> Option<Self> {
+        if request.headers().contains_key(header::IF_NONE_MATCH)
+            || request.headers().contains_key(header::IF_MODIFIED_SINCE)
+        {
+            return None;
+        }
+
+        let body = match request.body() {
+            Some(body) => body.as_bytes()?.to_vec(),
+            None => Vec::new(),
+        };
+
+        Some(Self {
+            method: request.method().to_string(),
+            url: request.url().to_string(),
+            body,
+        })
+    }
+}
+
+impl ResponseCache {
+    /// Adds the validators of the cached response (if there is one) to the headers of a request.
+    pub(crate) fn add_validators(&self, key: &CacheKey, request_headers: &mut HeaderMap) {
+        let entries = self.entries.lock().unwrap();
+
+        let Some(cached_response) = entries.get(key) else {
+            return;
+        };
+
+        if let Some(etag) = cached_response.headers.get(header::ETAG) {
+            request_headers.insert(header::IF_NONE_MATCH, etag.clone());
+        }
+
+        if let Some(last_modified) = cached_response.headers.get(header::LAST_MODIFIED) {
+            request_headers.insert(header::IF_MODIFIED_SINCE, last_modified.clone());
+        }
+    }
+
+    /// Processes the response of a request that was sent with [`ResponseCache::add_validators`].
+    ///
+    /// * A status code 304 is replaced with the cached response.
+    /// * A status code 200 with validators is stored, and an identical response is returned.
+    /// * Anything else is returned untouched.
+    pub(crate) async fn process_response(
+        &self,
+        key: CacheKey,
+        response: Response,
+    ) -> Result<Response, reqwest::Error> {
+        match response.status() {
+            StatusCode::NOT_MODIFIED => {
+                let cached_response = self.entries.lock().unwrap().get(&key).cloned();
+
+                match cached_response {
+                    Some(cached_response) => Ok(rebuild_response(cached_response)),
+                    None => Ok(response),
+                }
+            }
+            StatusCode::OK
+                if response.headers().contains_key(header::ETAG)
+                    || response.headers().contains_key(header::LAST_MODIFIED) =>
+            {
+                let headers = response.headers().clone();
+                let body = response.bytes().await?;
+
+                let cached_response = CachedResponse { headers, body };
+
+                let mut entries = self.entries.lock().unwrap();
+
+                if entries.len() >= MAX_CACHED_RESPONSES && !entries.contains_key(&key) {
+                    if let Some(evicted_key) = entries.keys().next().cloned() {
+                        entries.remove(&evicted_key);
+                    }
+                }
+
+                entries.insert(key, cached_response.clone());
+
+                Ok(rebuild_response(cached_response))
+            }
+            _ => Ok(response),
+        }
+    }
+}
+
+/// Turns a cached response back into a [`Response`] with a status code of 200.
+fn rebuild_response(cached_response: CachedResponse) -> Response {
+    let mut http_response = http::Response::new(cached_response.body);
+    *http_response.headers_mut() = cached_response.headers;
+
+    Response::from(http_response)
+}
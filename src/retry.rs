@@ -0,0 +1,230 @@
+use crate::economy::{PurchaseError, PurchaseLimitedError};
+use crate::RoboatError;
+use std::collections::hash_map::RandomState;
+use std::future::Future;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+/// How many times larger than the initial delay the max delay of a [`Backoff`] is by default.
+const DEFAULT_MAX_DELAY_FACTOR: u32 = 32;
+
+/// The multiplier a [`Backoff`] uses by default.
+const DEFAULT_MULTIPLIER: u32 = 2;
+
+/// A delay that grows after every attempt, up to a max delay.
+///
+/// # Example
+/// ```
+/// use roboat::retry::Backoff;
+/// use std::time::Duration;
+///
+/// let mut backoff = Backoff::new(Duration::from_millis(100)).max_delay(Duration::from_millis(300));
+///
+/// assert_eq!(backoff.next_delay(), Duration::from_millis(100));
+/// assert_eq!(backoff.next_delay(), Duration::from_millis(200));
+/// assert_eq!(backoff.next_delay(), Duration::from_millis(300));
+/// assert_eq!(backoff.next_delay(), Duration::from_millis(300));
+///
+/// backoff.reset();
+/// assert_eq!(backoff.next_delay(), Duration::from_millis(100));
+///
+/// // Delays stop growing at Duration::MAX instead of overflowing.
+/// let mut backoff = Backoff::new(Duration::MAX).multiplier(u32::MAX);
+///
+/// assert_eq!(backoff.next_delay(), Duration::MAX);
+/// assert_eq!(backoff.next_delay(), Duration::MAX);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Backoff {
+    initial_delay: Duration,
+    max_delay: Duration,
+    multiplier: u32,
+    current_delay: Duration,
+    jitter: bool,
+}
+
+impl Backoff {
+    /// Creates a backoff that starts at `initial_delay` and doubles after every attempt.
+    /// The max delay defaults to 32 times the initial delay (or [`Duration::MAX`] if that
+    /// would overflow).
+    pub fn new(initial_delay: Duration) -> Self {
+        Self {
+            initial_delay,
+            max_delay: initial_delay.saturating_mul(DEFAULT_MAX_DELAY_FACTOR),
+            multiplier: DEFAULT_MULTIPLIER,
+            current_delay: initial_delay,
+            jitter: false,
+        }
+    }
+
+    /// Sets the longest delay the backoff will grow to. Values below the initial
+    /// delay are treated as the initial delay.
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay.max(self.initial_delay);
+        self
+    }
+
+    /// Sets how much the delay is multiplied by after every attempt. A multiplier of 1
+    /// keeps the delay constant. Values below 1 are treated as 1.
+    pub fn multiplier(mut self, multiplier: u32) -> Self {
+        self.multiplier = multiplier.max(1);
+        self
+    }
+
+    /// Randomly shortens each delay to between half of and the full delay, so that
+    /// many clients backing off at once do not all retry at the same moment.
+    pub fn jitter(mut self, enabled: bool) -> Self {
+        self.jitter = enabled;
+        self
+    }
+
+    /// Returns the delay to wait before the next attempt, and grows the delay for the one after.
+    pub fn next_delay(&mut self) -> Duration {
+        let delay = self.current_delay;
+        self.current_delay = self
+            .current_delay
+            .saturating_mul(self.multiplier)
+            .min(self.max_delay);
+
+        match self.jitter {
+            true => delay.mul_f64(0.5 + random_fraction() / 2.0),
+            false => delay,
+        }
+    }
+
+    /// Resets the delay back to the initial delay, such as after an attempt succeeds.
+    pub fn reset(&mut self) {
+        self.current_delay = self.initial_delay;
+    }
+}
+
+/// Decides how many times an operation is attempted, how long to wait between attempts,
+/// and which errors are worth retrying.
+///
+/// By default, only errors that are likely to go away on their own are retried
+/// (see [`RetryPolicy::is_transient`]).
+///
+/// # Example
+/// ```
+/// use roboat::retry::{Backoff, RetryPolicy};
+/// use roboat::RoboatError;
+/// use std::time::Duration;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let policy = RetryPolicy::new(3, Backoff::new(Duration::from_millis(1)));
+///
+/// let mut attempts = 0;
+///
+/// let result = policy
+///     .run(|| {
+///         attempts += 1;
+///         let attempt = attempts;
+///
+///         async move {
+///             match attempt {
+///                 1 => Err(RoboatError::TooManyRequests),
+///                 _ => Ok(attempt),
+///             }
+///         }
+///     })
+///     .await;
+///
+/// assert_eq!(result.unwrap(), 2);
+///
+/// // Errors that are not transient are returned right away.
+/// let result: Result<(), RoboatError> = policy
+///     .run(|| async { Err(RoboatError::InvalidRoblosecurity) })
+///     .await;
+///
+/// assert!(matches!(result, Err(RoboatError::InvalidRoblosecurity)));
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    max_attempts: usize,
+    backoff: Backoff,
+    should_retry: fn(&RoboatError) -> bool,
+}
+
+impl RetryPolicy {
+    /// Creates a policy that attempts an operation up to `max_attempts` times (including
+    /// the first attempt), waiting according to `backoff` between attempts. A value of 0
+    /// is treated as 1.
+    pub fn new(max_attempts: usize, backoff: Backoff) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            backoff,
+            should_retry: Self::is_transient,
+        }
+    }
+
+    /// Replaces the check used to decide whether an error is worth retrying.
+    ///
+    /// # Example
+    /// ```
+    /// use roboat::economy::PurchaseLimitedError;
+    /// use roboat::retry::{Backoff, RetryPolicy};
+    /// use roboat::RoboatError;
+    /// use std::time::Duration;
+    ///
+    /// // Keep trying to buy through price changes, on top of the usual transient errors.
+    /// let policy = RetryPolicy::new(5, Backoff::new(Duration::from_millis(500))).retry_if(|e| {
+    ///     RetryPolicy::is_transient(e)
+    ///         || matches!(
+    ///             e,
+    ///             RoboatError::PurchaseLimitedError(PurchaseLimitedError::PriceChanged)
+    ///         )
+    /// });
+    /// ```
+    pub fn retry_if(mut self, should_retry: fn(&RoboatError) -> bool) -> Self {
+        self.should_retry = should_retry;
+        self
+    }
+
+    /// Returns whether an error is likely to go away on its own. These are rate limits,
+    /// server errors, connection errors, [`PurchaseLimitedError::PendingTransaction`],
+    /// and [`PurchaseError::PendingTransaction`].
+    pub fn is_transient(error: &RoboatError) -> bool {
+        matches!(
+            error,
+            RoboatError::TooManyRequests
+                | RoboatError::InternalServerError
+                | RoboatError::ReqwestError(_)
+                | RoboatError::PurchaseLimitedError(PurchaseLimitedError::PendingTransaction)
+                | RoboatError::PurchaseError(PurchaseError::PendingTransaction)
+        )
+    }
+
+    /// Runs `operation` until it succeeds, fails with an error that is not worth retrying,
+    /// or runs out of attempts. The error of the last attempt is returned if it never succeeds.
+    pub async fn run<T, F, Fut>(&self, mut operation: F) -> Result<T, RoboatError>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, RoboatError>>,
+    {
+        let mut backoff = self.backoff.clone();
+        let mut attempt = 1;
+
+        loop {
+            match operation().await {
+                Ok(x) => return Ok(x),
+                Err(e) => {
+                    if attempt >= self.max_attempts || !(self.should_retry)(&e) {
+                        return Err(e);
+                    }
+                }
+            }
+
+            tokio::time::sleep(backoff.next_delay()).await;
+            attempt += 1;
+        }
+    }
+}
+
+/// Returns a random number between 0 and 1. Every [`RandomState`] is seeded with random keys,
+/// which is plenty of randomness for spreading out retries without pulling in a rng crate.
+fn random_fraction() -> f64 {
+    let random = RandomState::new().build_hasher().finish();
+    random as f64 / u64::MAX as f64
+}
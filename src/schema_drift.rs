@@ -0,0 +1,167 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Mutex;
+
+/// Every drift found so far, keyed by the name of the response struct.
+static DRIFT_REPORTS: Mutex<BTreeMap<String, DriftReport>> = Mutex::new(BTreeMap::new());
+
+/// The differences found between the json Roblox sent for one response struct and the fields
+/// of that struct. Retrieved using [`drift_reports`].
+///
+/// Fields are written as paths from the root of the response, such as `"data[].agent.type"`.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct DriftReport {
+    /// The name of the response struct, such as `"roboat::economy::request_types::TransactionsResponse"`.
+    pub response_type: String,
+    /// Fields Roblox sent that the struct does not have. These are ignored when parsing,
+    /// and usually mean Roblox added something to the endpoint.
+    pub unknown_fields: BTreeSet<String>,
+    /// Fields the struct has that Roblox did not send, which were filled in with `None` or a
+    /// default. These usually mean Roblox removed something, and become a
+    /// [`RoboatError::MalformedResponse`](crate::RoboatError::MalformedResponse) if the field
+    /// stops being optional.
+    pub missing_fields: BTreeSet<String>,
+}
+
+/// Returns every drift found since the program started (or since [`clear_drift_reports`]),
+/// with one report per response struct.
+///
+/// Every response parsed by a [`Client`](crate::Client) is compared against the struct it is
+/// parsed into. New differences are also logged as warnings when they are first found.
+///
+/// # Example
+/// ```
+/// use roboat::schema_drift::{clear_drift_reports, drift_reports};
+///
+/// // Run some requests here, then print anything that changed.
+/// for report in drift_reports() {
+///     println!("{}", report.response_type);
+///     println!("  Unknown fields: {:?}", report.unknown_fields);
+///     println!("  Missing fields: {:?}", report.missing_fields);
+/// }
+///
+/// clear_drift_reports();
+/// assert!(drift_reports().is_empty());
+/// ```
+pub fn drift_reports() -> Vec<DriftReport> {
+    let reports = DRIFT_REPORTS.lock().unwrap_or_else(|e| e.into_inner());
+    reports.values().cloned().collect()
+}
+
+/// Forgets every drift found so far.
+pub fn clear_drift_reports() {
+    let mut reports = DRIFT_REPORTS.lock().unwrap_or_else(|e| e.into_inner());
+    reports.clear();
+}
+
+/// Compares the json of a response against the struct it was parsed into, and records
+/// any differences that have not been seen before.
+pub(crate) fn check<T: Serialize>(received: &Value, parsed: &T) {
+    // If the struct cannot be turned back into json, there is nothing to compare against.
+    let Ok(expected) = serde_json::to_value(parsed) else {
+        return;
+    };
+
+    let mut unknown_fields = BTreeSet::new();
+    let mut missing_fields = BTreeSet::new();
+
+    compare(
+        received,
+        &expected,
+        "",
+        &mut unknown_fields,
+        &mut missing_fields,
+    );
+
+    if unknown_fields.is_empty() && missing_fields.is_empty() {
+        return;
+    }
+
+    let response_type = std::any::type_name::<T>();
+
+    let mut reports = DRIFT_REPORTS.lock().unwrap_or_else(|e| e.into_inner());
+
+    let report = reports
+        .entry(response_type.to_string())
+        .or_insert_with(|| DriftReport {
+            response_type: response_type.to_string(),
+            ..Default::default()
+        });
+
+    let new_unknown_fields = unknown_fields
+        .into_iter()
+        .filter(|field| report.unknown_fields.insert(field.clone()))
+        .collect::<Vec<_>>();
+
+    let new_missing_fields = missing_fields
+        .into_iter()
+        .filter(|field| report.missing_fields.insert(field.clone()))
+        .collect::<Vec<_>>();
+
+    if !new_unknown_fields.is_empty() || !new_missing_fields.is_empty() {
+        log::warn!(
+            "Schema drift in {}: unknown fields {:?}, missing fields {:?}",
+            response_type,
+            new_unknown_fields,
+            new_missing_fields
+        );
+    }
+}
+
+/// Walks the received and expected json together, collecting the paths of fields that
+/// only exist in one of them.
+fn compare(
+    received: &Value,
+    expected: &Value,
+    path: &str,
+    unknown_fields: &mut BTreeSet<String>,
+    missing_fields: &mut BTreeSet<String>,
+) {
+    match (received, expected) {
+        (Value::Object(received), Value::Object(expected)) => {
+            for (key, received_value) in received {
+                let field_path = match path.is_empty() {
+                    true => key.clone(),
+                    false => format!("{}.{}", path, key),
+                };
+
+                match expected.get(key) {
+                    Some(expected_value) => compare(
+                        received_value,
+                        expected_value,
+                        &field_path,
+                        unknown_fields,
+                        missing_fields,
+                    ),
+                    None => {
+                        unknown_fields.insert(field_path);
+                    }
+                }
+            }
+
+            for key in expected.keys().filter(|key| !received.contains_key(*key)) {
+                let field_path = match path.is_empty() {
+                    true => key.clone(),
+                    false => format!("{}.{}", path, key),
+                };
+
+                missing_fields.insert(field_path);
+            }
+        }
+        (Value::Array(received), Value::Array(expected)) => {
+            let item_path = format!("{}[]", path);
+
+            for (received_item, expected_item) in received.iter().zip(expected) {
+                compare(
+                    received_item,
+                    expected_item,
+                    &item_path,
+                    unknown_fields,
+                    missing_fields,
+                );
+            }
+        }
+        _ => {}
+    }
+}
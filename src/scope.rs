@@ -0,0 +1,115 @@
+use crate::{Client, RoboatError};
+use futures_util::future::FutureExt;
+use futures_util::stream::{FuturesUnordered, StreamExt};
+use std::future::Future;
+use std::panic::AssertUnwindSafe;
+use std::pin::Pin;
+
+type ScopedFuture<'a> = Pin<Box<dyn Future<Output = Result<(), RoboatError>> + Send + 'a>>;
+
+/// A group of related tasks created by [`Client::scope`].
+///
+/// Tasks are added with [`Scope::spawn`]. Unlike [`tokio::spawn`], tasks are allowed to
+/// borrow the client (and anything else that outlives the scope), as they all run
+/// concurrently inside the future returned by [`Client::scope`].
+#[derive(Default)]
+pub struct Scope<'a> {
+    tasks: Vec<ScopedFuture<'a>>,
+}
+
+impl std::fmt::Debug for Scope<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Scope")
+            .field("tasks", &self.tasks.len())
+            .finish()
+    }
+}
+
+impl<'a> Scope<'a> {
+    /// Adds a task to the scope. The task starts running once the closure given to
+    /// [`Client::scope`] returns.
+    pub fn spawn<F>(&mut self, task: F)
+    where
+        F: Future<Output = Result<(), RoboatError>> + Send + 'a,
+    {
+        self.tasks.push(Box::pin(task));
+    }
+}
+
+impl Client {
+    /// Runs a group of related tasks (such as several monitors) together, cancelling all of
+    /// them if one fails.
+    ///
+    /// The closure is given a [`Scope`] to add tasks to. The returned future then runs every
+    /// task concurrently until all of them finish, or until one of them returns an error.
+    /// Dropping the returned future cancels every task in the scope.
+    ///
+    /// # Notes
+    /// * Tasks run concurrently on the current task, not in parallel on other threads.
+    /// * Tasks share the client, so they share its x-csrf-token, cookie, and connection pool.
+    ///
+    /// # Errors
+    /// * The first error returned by any task. The remaining tasks are cancelled.
+    /// * [`RoboatError::WatcherCrashed`] - A task panicked. The remaining tasks are cancelled,
+    ///   and a crash report is saved to the [`StateStore`](crate::crash::StateStore) if one is set.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ids::AssetId;
+    /// use roboat::{ClientBuilder, Limit};
+    /// use std::time::Duration;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// client
+    ///     .scope(|s| {
+    ///         s.spawn(async {
+    ///             loop {
+    ///                 println!("Robux: {}", client.robux().await?);
+    ///                 tokio::time::sleep(Duration::from_secs(60)).await;
+    ///             }
+    ///         });
+    ///
+    ///         s.spawn(async {
+    ///             loop {
+    ///                 let (resellers, _) = client.resellers(AssetId(1365767), Limit::Ten, None).await?;
+    ///                 println!("Lowest Price: {:?}", resellers.first().map(|x| x.price));
+    ///                 tokio::time::sleep(Duration::from_secs(10)).await;
+    ///             }
+    ///         });
+    ///     })
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn scope<'a, F>(&'a self, f: F) -> Result<(), RoboatError>
+    where
+        F: FnOnce(&mut Scope<'a>),
+    {
+        let mut scope = Scope::default();
+        f(&mut scope);
+
+        let mut tasks = scope
+            .tasks
+            .into_iter()
+            .map(|task| AssertUnwindSafe(task).catch_unwind())
+            .collect::<FuturesUnordered<_>>();
+
+        while let Some(result) = tasks.next().await {
+            // Returning drops the remaining tasks, which cancels them.
+            match result {
+                Ok(result) => result?,
+                Err(panic) => {
+                    let state = serde_json::json!({ "unfinished_tasks": tasks.len() + 1 });
+                    return Err(self.report_crash("scope", state, panic));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,199 @@
+use crate::response_cache::CacheKey;
+use crate::API_KEY_HEADER;
+use bytes::Bytes;
+use reqwest::header::{self, HeaderMap, HeaderValue};
+use reqwest::{Request, Response};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// The maximum amount of responses kept in a [`SharedCache`]. Once full, expired entries
+/// are dropped, and then an arbitrary entry is evicted to make room for a new one.
+const MAX_SHARED_RESPONSES: usize = 5000;
+
+/// A cache of responses from public endpoints (such as thumbnails and item details) that
+/// honors the `Cache-Control` header sent by Roblox. Set using
+/// [`ClientBuilder::shared_cache`](crate::ClientBuilder::shared_cache).
+///
+/// A response is kept for as long as its `s-maxage` or `max-age` allows, and the same request
+/// is answered from the cache until then without being sent. The cache is cheap to clone,
+/// and every clone shares the same entries, so one cache can be given to many clients.
+///
+/// Only requests without credentials are cached. Requests sent with a roblosecurity or an
+/// api key are always sent, as their responses can differ between accounts.
+///
+/// # Example
+/// ```
+/// use roboat::{ClientBuilder, SharedCache};
+///
+/// let cache = SharedCache::new();
+///
+/// let client_1 = ClientBuilder::new().shared_cache(cache.clone()).build();
+/// let client_2 = ClientBuilder::new().shared_cache(cache.clone()).build();
+///
+/// assert!(cache.is_empty());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct SharedCache {
+    entries: Arc<Mutex<HashMap<SharedCacheKey, FreshResponse>>>,
+}
+
+/// A [`CacheKey`] along with the language the response was requested in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct SharedCacheKey {
+    key: CacheKey,
+    locale: Option<HeaderValue>,
+}
+
+#[derive(Debug, Clone)]
+struct FreshResponse {
+    headers: HeaderMap,
+    body: Bytes,
+    expires_at: Instant,
+}
+
+impl SharedCache {
+    /// Creates an empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the amount of responses in the cache, including ones that have expired but
+    /// have not been dropped yet.
+    pub fn len(&self) -> usize {
+        self.entries.lock().unwrap().len()
+    }
+
+    /// Returns whether the cache holds no responses.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Drops every response in the cache.
+    pub fn clear(&self) {
+        self.entries.lock().unwrap().clear();
+    }
+
+    /// Creates a key for a request, or returns `None` if the request is sent with credentials
+    /// or cannot be cached.
+    pub(crate) fn key(request: &Request) -> Option<SharedCacheKey> {
+        let headers = request.headers();
+
+        let has_cookie = headers
+            .get(header::COOKIE)
+            .is_some_and(|cookie| !cookie.is_empty());
+
+        if has_cookie || headers.contains_key(API_KEY_HEADER) {
+            return None;
+        }
+
+        Some(SharedCacheKey {
+            key: CacheKey::new(request)?,
+            locale: headers.get(header::ACCEPT_LANGUAGE).cloned(),
+        })
+    }
+
+    /// Returns the cached response for a request if it has not expired yet.
+    pub(crate) fn get(&self, key: &SharedCacheKey) -> Option<Response> {
+        let mut entries = self.entries.lock().unwrap();
+
+        let fresh_response = entries.get(key)?;
+
+        if fresh_response.expires_at <= Instant::now() {
+            entries.remove(key);
+            return None;
+        }
+
+        let mut http_response = http::Response::new(fresh_response.body.clone());
+        *http_response.headers_mut() = fresh_response.headers.clone();
+
+        Some(Response::from(http_response))
+    }
+
+    /// Stores a successful response if its `Cache-Control` header allows it, and returns an
+    /// identical response. Responses that cannot be cached are returned untouched.
+    pub(crate) async fn store(
+        &self,
+        key: SharedCacheKey,
+        response: Response,
+    ) -> Result<Response, reqwest::Error> {
+        let Some(freshness) = freshness(response.headers()) else {
+            return Ok(response);
+        };
+
+        let now = Instant::now();
+
+        // A freshness reaching past the latest representable instant is not cached.
+        let Some(expires_at) = now.checked_add(freshness) else {
+            return Ok(response);
+        };
+
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = response.bytes().await?;
+
+        let fresh_response = FreshResponse {
+            headers: headers.clone(),
+            body: body.clone(),
+            expires_at,
+        };
+
+        let mut entries = self.entries.lock().unwrap();
+
+        if entries.len() >= MAX_SHARED_RESPONSES && !entries.contains_key(&key) {
+            entries.retain(|_, fresh_response| fresh_response.expires_at > now);
+
+            if entries.len() >= MAX_SHARED_RESPONSES {
+                if let Some(evicted_key) = entries.keys().next().cloned() {
+                    entries.remove(&evicted_key);
+                }
+            }
+        }
+
+        entries.insert(key, fresh_response);
+
+        let mut http_response = http::Response::new(body);
+        *http_response.status_mut() = status;
+        *http_response.headers_mut() = headers;
+
+        Ok(Response::from(http_response))
+    }
+}
+
+/// Returns how much longer a response can be cached for, based on its `Cache-Control` and
+/// `Age` headers. Returns `None` if the response cannot be cached.
+fn freshness(headers: &HeaderMap) -> Option<Duration> {
+    let cache_control = headers.get(header::CACHE_CONTROL)?.to_str().ok()?;
+
+    let mut max_age = None;
+    let mut shared_max_age = None;
+
+    for directive in cache_control.split(',') {
+        let directive = directive.trim().to_ascii_lowercase();
+
+        match directive.split_once('=') {
+            Some(("max-age", seconds)) => max_age = seconds.trim_matches('"').parse::<u64>().ok(),
+            Some(("s-maxage", seconds)) => {
+                shared_max_age = seconds.trim_matches('"').parse::<u64>().ok()
+            }
+            None if matches!(directive.as_str(), "no-store" | "no-cache" | "private") => {
+                return None;
+            }
+            _ => {}
+        }
+    }
+
+    // The cache is shared, so s-maxage takes priority over max-age.
+    let lifetime = shared_max_age.or(max_age)?;
+
+    let age = headers
+        .get(header::AGE)
+        .and_then(|age| age.to_str().ok())
+        .and_then(|age| age.trim().parse::<u64>().ok())
+        .unwrap_or_default();
+
+    match lifetime.saturating_sub(age) {
+        0 => None,
+        seconds => Some(Duration::from_secs(seconds)),
+    }
+}
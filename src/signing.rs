@@ -0,0 +1,42 @@
+use crate::RoboatError;
+use std::fmt::Debug;
+
+/// A hook used to sign requests right before they are sent. Set using [`ClientBuilder::request_signer`](crate::ClientBuilder::request_signer).
+///
+/// This allows headers (such as signatures required by hardened endpoints) to be
+/// attached to every request without needing to fork the crate.
+///
+/// # Notes
+/// * The signer is called for every request the [`Client`](crate::Client) sends, including requests
+///   that do not require authentication. It is up to the signer to decide which requests to sign.
+/// * The signer is called again when a request is repeated due to an invalid x-csrf-token.
+///
+/// # Example
+/// ```
+/// use roboat::reqwest::header::HeaderValue;
+/// use roboat::{ClientBuilder, RequestSigner, RoboatError};
+/// use std::sync::Arc;
+///
+/// #[derive(Debug)]
+/// struct StaticHeaderSigner;
+///
+/// impl RequestSigner for StaticHeaderSigner {
+///     fn sign(&self, request: &mut roboat::reqwest::Request) -> Result<(), RoboatError> {
+///         request
+///             .headers_mut()
+///             .insert("x-custom-signature", HeaderValue::from_static("signature"));
+///
+///         Ok(())
+///     }
+/// }
+///
+/// let client = ClientBuilder::new()
+///     .request_signer(Arc::new(StaticHeaderSigner))
+///     .build();
+/// ```
+pub trait RequestSigner: Debug + Send + Sync {
+    /// Signs the request by modifying it in place (usually by inserting headers).
+    ///
+    /// Returning an error aborts the request and the error is returned to the caller.
+    fn sign(&self, request: &mut reqwest::Request) -> Result<(), RoboatError>;
+}
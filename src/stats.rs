@@ -0,0 +1,94 @@
+use crate::RoboatError;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// A snapshot of the requests a [`Client`](crate::Client) has sent since it was built.
+/// Returned by [`Client::stats`](crate::Client::stats).
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct ClientStats {
+    /// The total amount of requests sent, including ones that failed.
+    pub requests_sent: u64,
+    /// The amount of requests sent to each endpoint. Endpoints are keyed by host and path,
+    /// with ids replaced by `{id}` (such as `economy.roblox.com/v1/assets/{id}/resellers`).
+    pub requests_per_endpoint: BTreeMap<String, u64>,
+    /// The amount of requests that failed with [`RoboatError::TooManyRequests`].
+    pub too_many_requests: u64,
+    /// The amount of requests that were sent again, such as after the x-csrf-token expired
+    /// or in [`Client::purchase_limited_with_retries`](crate::Client::purchase_limited_with_retries).
+    pub retries: u64,
+    /// The average time between sending a request and getting its response. Does not
+    /// exist if no requests have been sent.
+    pub average_latency: Option<Duration>,
+}
+
+/// Keeps the counters behind [`ClientStats`]. Every client has one.
+#[derive(Debug, Default)]
+pub(crate) struct StatsRecorder {
+    stats: Mutex<ClientStats>,
+    /// The summed latency of every request in nanoseconds.
+    total_latency_nanos: Mutex<u128>,
+}
+
+impl StatsRecorder {
+    /// Records a request sent to `host` and `path` that took `latency` and ended in `result`.
+    pub(crate) fn record_request<T>(
+        &self,
+        host: &str,
+        path: &str,
+        latency: Duration,
+        result: &Result<T, RoboatError>,
+    ) {
+        let mut stats = self.stats.lock().unwrap();
+        let mut total_latency_nanos = self.total_latency_nanos.lock().unwrap();
+
+        stats.requests_sent += 1;
+
+        *stats
+            .requests_per_endpoint
+            .entry(endpoint_key(host, path))
+            .or_default() += 1;
+
+        match result {
+            Err(RoboatError::TooManyRequests) => stats.too_many_requests += 1,
+            // Every method sends the request again after getting a new x-csrf-token.
+            Err(RoboatError::InvalidXcsrf(_)) => stats.retries += 1,
+            _ => {}
+        }
+
+        *total_latency_nanos = total_latency_nanos.saturating_add(latency.as_nanos());
+
+        let average_nanos = *total_latency_nanos / u128::from(stats.requests_sent);
+        stats.average_latency = Some(Duration::from_nanos(
+            u64::try_from(average_nanos).unwrap_or(u64::MAX),
+        ));
+    }
+
+    /// Records a request that is about to be sent again.
+    pub(crate) fn record_retry(&self) {
+        self.stats.lock().unwrap().retries += 1;
+    }
+
+    /// Returns a copy of the current counters.
+    pub(crate) fn snapshot(&self) -> ClientStats {
+        self.stats.lock().unwrap().clone()
+    }
+}
+
+/// Joins a host and path, replacing numeric path segments with `{id}` so that requests to the
+/// same endpoint with different ids are counted together.
+fn endpoint_key(host: &str, path: &str) -> String {
+    let path = path
+        .split('/')
+        .map(|segment| {
+            match !segment.is_empty() && segment.bytes().all(|byte| byte.is_ascii_digit()) {
+                true => "{id}",
+                false => segment,
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/");
+
+    format!("{}{}", host, path)
+}
@@ -0,0 +1,713 @@
+use crate::ids::UserId;
+use crate::{Client, RoboatError};
+use reqwest::header;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::time::Duration;
+
+mod request_types;
+
+const THUMBNAILS_BATCH_API: &str = "https://thumbnails.roblox.com/v1/batch";
+const AVATAR_3D_API: &str = "https://thumbnails.roblox.com/v1/users/avatar-3d";
+
+/// The amount of times [`Client::thumbnails`] will re-request thumbnails that are still pending.
+const MAX_PENDING_RETRIES: usize = 5;
+
+/// The delay between re-requesting pending thumbnails in [`Client::thumbnails`].
+const PENDING_RETRY_DELAY: Duration = Duration::from_secs(1);
+
+/// The type of thumbnail being requested (Asset, AvatarHeadShot, GroupIcon).
+#[allow(missing_docs)]
+#[derive(
+    Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize, Copy,
+)]
+pub enum ThumbnailType {
+    #[default]
+    Asset,
+    Avatar,
+    AvatarHeadShot,
+    AvatarBust,
+    BadgeIcon,
+    BundleThumbnail,
+    GameIcon,
+    GamePass,
+    GroupIcon,
+    Outfit,
+}
+
+/// The size of a thumbnail. Not every size is accepted for every [`ThumbnailType`];
+/// use [`ThumbnailType::supported_sizes`] to see which sizes can be used.
+#[allow(missing_docs)]
+#[derive(
+    Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize, Copy,
+)]
+pub enum ThumbnailSize {
+    #[serde(rename = "30x30")]
+    Size30x30,
+    #[serde(rename = "42x42")]
+    Size42x42,
+    #[serde(rename = "48x48")]
+    Size48x48,
+    #[serde(rename = "50x50")]
+    Size50x50,
+    #[serde(rename = "60x60")]
+    Size60x60,
+    #[serde(rename = "60x62")]
+    Size60x62,
+    #[serde(rename = "75x75")]
+    Size75x75,
+    #[serde(rename = "100x100")]
+    Size100x100,
+    #[serde(rename = "110x110")]
+    Size110x110,
+    #[serde(rename = "128x128")]
+    Size128x128,
+    #[serde(rename = "140x140")]
+    Size140x140,
+    #[serde(rename = "150x150")]
+    Size150x150,
+    #[serde(rename = "150x200")]
+    Size150x200,
+    #[serde(rename = "160x100")]
+    Size160x100,
+    #[serde(rename = "160x600")]
+    Size160x600,
+    #[serde(rename = "180x180")]
+    Size180x180,
+    #[serde(rename = "250x250")]
+    Size250x250,
+    #[serde(rename = "256x144")]
+    Size256x144,
+    #[serde(rename = "256x256")]
+    Size256x256,
+    #[serde(rename = "300x250")]
+    Size300x250,
+    #[serde(rename = "304x166")]
+    Size304x166,
+    #[serde(rename = "352x352")]
+    Size352x352,
+    #[serde(rename = "384x216")]
+    Size384x216,
+    #[serde(rename = "396x216")]
+    Size396x216,
+    #[default]
+    #[serde(rename = "420x420")]
+    Size420x420,
+    #[serde(rename = "480x270")]
+    Size480x270,
+    #[serde(rename = "512x512")]
+    Size512x512,
+    #[serde(rename = "576x324")]
+    Size576x324,
+    #[serde(rename = "700x700")]
+    Size700x700,
+    #[serde(rename = "720x720")]
+    Size720x720,
+    #[serde(rename = "728x90")]
+    Size728x90,
+    #[serde(rename = "768x432")]
+    Size768x432,
+    #[serde(rename = "1200x80")]
+    Size1200x80,
+}
+
+/// The image format of a thumbnail. Not every format is accepted for every [`ThumbnailType`];
+/// use [`ThumbnailType::supported_formats`] to see which formats can be used.
+#[allow(missing_docs)]
+#[derive(
+    Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize, Copy,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum ThumbnailFormat {
+    #[default]
+    Png,
+    Jpeg,
+    Webp,
+}
+
+impl ThumbnailType {
+    /// Returns the sizes Roblox accepts for this type of thumbnail.
+    ///
+    /// # Example
+    /// ```
+    /// use roboat::thumbnails::{ThumbnailSize, ThumbnailType};
+    ///
+    /// assert!(ThumbnailType::GroupIcon
+    ///     .supported_sizes()
+    ///     .contains(&ThumbnailSize::Size420x420));
+    ///
+    /// assert!(!ThumbnailType::BadgeIcon
+    ///     .supported_sizes()
+    ///     .contains(&ThumbnailSize::Size420x420));
+    /// ```
+    pub fn supported_sizes(self) -> &'static [ThumbnailSize] {
+        match self {
+            Self::Asset => &[
+                ThumbnailSize::Size30x30,
+                ThumbnailSize::Size42x42,
+                ThumbnailSize::Size50x50,
+                ThumbnailSize::Size60x62,
+                ThumbnailSize::Size75x75,
+                ThumbnailSize::Size110x110,
+                ThumbnailSize::Size140x140,
+                ThumbnailSize::Size150x150,
+                ThumbnailSize::Size160x100,
+                ThumbnailSize::Size160x600,
+                ThumbnailSize::Size250x250,
+                ThumbnailSize::Size256x144,
+                ThumbnailSize::Size300x250,
+                ThumbnailSize::Size304x166,
+                ThumbnailSize::Size384x216,
+                ThumbnailSize::Size396x216,
+                ThumbnailSize::Size420x420,
+                ThumbnailSize::Size480x270,
+                ThumbnailSize::Size512x512,
+                ThumbnailSize::Size576x324,
+                ThumbnailSize::Size700x700,
+                ThumbnailSize::Size728x90,
+                ThumbnailSize::Size768x432,
+                ThumbnailSize::Size1200x80,
+            ],
+            Self::Avatar => &[
+                ThumbnailSize::Size30x30,
+                ThumbnailSize::Size48x48,
+                ThumbnailSize::Size60x60,
+                ThumbnailSize::Size75x75,
+                ThumbnailSize::Size100x100,
+                ThumbnailSize::Size110x110,
+                ThumbnailSize::Size140x140,
+                ThumbnailSize::Size150x150,
+                ThumbnailSize::Size150x200,
+                ThumbnailSize::Size180x180,
+                ThumbnailSize::Size250x250,
+                ThumbnailSize::Size352x352,
+                ThumbnailSize::Size420x420,
+                ThumbnailSize::Size720x720,
+            ],
+            Self::AvatarHeadShot => &[
+                ThumbnailSize::Size48x48,
+                ThumbnailSize::Size50x50,
+                ThumbnailSize::Size60x60,
+                ThumbnailSize::Size75x75,
+                ThumbnailSize::Size100x100,
+                ThumbnailSize::Size110x110,
+                ThumbnailSize::Size150x150,
+                ThumbnailSize::Size180x180,
+                ThumbnailSize::Size352x352,
+                ThumbnailSize::Size420x420,
+                ThumbnailSize::Size720x720,
+            ],
+            Self::AvatarBust => &[
+                ThumbnailSize::Size48x48,
+                ThumbnailSize::Size50x50,
+                ThumbnailSize::Size60x60,
+                ThumbnailSize::Size75x75,
+                ThumbnailSize::Size100x100,
+                ThumbnailSize::Size150x150,
+                ThumbnailSize::Size180x180,
+                ThumbnailSize::Size352x352,
+                ThumbnailSize::Size420x420,
+            ],
+            Self::BadgeIcon => &[ThumbnailSize::Size150x150],
+            Self::BundleThumbnail => &[ThumbnailSize::Size150x150, ThumbnailSize::Size420x420],
+            Self::GameIcon => &[
+                ThumbnailSize::Size50x50,
+                ThumbnailSize::Size128x128,
+                ThumbnailSize::Size150x150,
+                ThumbnailSize::Size256x256,
+                ThumbnailSize::Size420x420,
+                ThumbnailSize::Size512x512,
+            ],
+            Self::GamePass => &[ThumbnailSize::Size150x150],
+            Self::GroupIcon => &[ThumbnailSize::Size150x150, ThumbnailSize::Size420x420],
+            Self::Outfit => &[ThumbnailSize::Size150x150, ThumbnailSize::Size420x420],
+        }
+    }
+
+    /// Returns the formats Roblox accepts for this type of thumbnail.
+    pub fn supported_formats(self) -> &'static [ThumbnailFormat] {
+        match self {
+            Self::Asset | Self::Avatar | Self::AvatarHeadShot | Self::GameIcon => &[
+                ThumbnailFormat::Png,
+                ThumbnailFormat::Jpeg,
+                ThumbnailFormat::Webp,
+            ],
+            _ => &[ThumbnailFormat::Png, ThumbnailFormat::Webp],
+        }
+    }
+}
+
+/// The state of a thumbnail. Only [`ThumbnailState::Completed`] thumbnails have an image url.
+#[allow(missing_docs)]
+#[derive(
+    Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize, Copy,
+)]
+pub enum ThumbnailState {
+    #[default]
+    Completed,
+    /// The thumbnail is still being generated by Roblox.
+    Pending,
+    /// The thumbnail was moderated.
+    Blocked,
+    InReview,
+    Error,
+    TemporarilyUnavailable,
+}
+
+/// Holds information used to request a thumbnail from the [`Client::thumbnails`] endpoint.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct ThumbnailArgs {
+    /// The id of the asset, user, group, etc. that the thumbnail is for.
+    pub target_id: u64,
+    /// The type of thumbnail.
+    pub thumbnail_type: ThumbnailType,
+    /// The size of the thumbnail. Must be one of [`ThumbnailType::supported_sizes`].
+    pub size: ThumbnailSize,
+    /// The image format of the thumbnail. Must be one of [`ThumbnailType::supported_formats`].
+    pub format: ThumbnailFormat,
+}
+
+impl ThumbnailArgs {
+    /// Returns whether the size and format are accepted by Roblox for the thumbnail type.
+    pub fn is_supported(&self) -> bool {
+        self.thumbnail_type.supported_sizes().contains(&self.size)
+            && self
+                .thumbnail_type
+                .supported_formats()
+                .contains(&self.format)
+    }
+}
+
+/// A thumbnail retrieved from <https://thumbnails.roblox.com/v1/batch>.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct Thumbnail {
+    /// The id of the asset, user, group, etc. that the thumbnail is for.
+    pub target_id: u64,
+    /// The type of thumbnail.
+    pub thumbnail_type: ThumbnailType,
+    /// The final state of the thumbnail. This can still be [`ThumbnailState::Pending`]
+    /// if Roblox did not finish generating the thumbnail in time.
+    pub state: ThumbnailState,
+    /// The url of the thumbnail image. Only exists if the state is [`ThumbnailState::Completed`].
+    pub image_url: Option<String>,
+}
+
+/// The 3D model of a user's avatar, created by [`Client::avatar_3d`].
+///
+/// The files can be written to disk as-is and opened by most 3D tools. The mtl file refers
+/// to textures by their hash, so each texture should be saved with its hash as the file name.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct Avatar3d {
+    /// The hash of the obj file.
+    pub obj_hash: String,
+    /// The contents of the obj (model) file.
+    pub obj: Vec<u8>,
+    /// The hash of the mtl file.
+    pub mtl_hash: String,
+    /// The contents of the mtl (material) file.
+    pub mtl: Vec<u8>,
+    /// The textures used by the mtl file.
+    pub textures: Vec<Avatar3dTexture>,
+}
+
+/// A texture of an [`Avatar3d`].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct Avatar3dTexture {
+    /// The hash of the texture, which is how the mtl file refers to it.
+    pub hash: String,
+    /// The contents of the texture (png) file.
+    pub bytes: Vec<u8>,
+}
+
+impl Client {
+    /// Grabs the urls of one or more thumbnails from <https://thumbnails.roblox.com/v1/batch>.
+    ///
+    /// Thumbnails that are still being generated by Roblox are returned as pending. Instead of
+    /// returning these right away, pending thumbnails are re-requested (up to 5 times, one second apart)
+    /// until they reach a final state.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    /// * This endpoint will accept up to 100 thumbnails at a time.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    /// * [`RoboatError::UnsupportedThumbnailArgs`] - If a size or format is not accepted for its
+    ///   thumbnail type (see [`ThumbnailArgs::is_supported`]). No request is made in this case.
+    ///
+    /// # Return Value Notes
+    /// * Thumbnails are returned in the same order as the arguments.
+    /// * If a thumbnail is still pending after all retries, it is returned with a state of
+    ///   [`ThumbnailState::Pending`] and no image url.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::thumbnails::{ThumbnailArgs, ThumbnailFormat, ThumbnailSize, ThumbnailType};
+    /// use roboat::ClientBuilder;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let args = ThumbnailArgs {
+    ///     target_id: 1365767,
+    ///     thumbnail_type: ThumbnailType::Asset,
+    ///     size: ThumbnailSize::Size420x420,
+    ///     format: ThumbnailFormat::Png,
+    /// };
+    ///
+    /// let thumbnails = client.thumbnails(vec![args]).await?;
+    /// println!("Thumbnail Url: {:?}", thumbnails[0].image_url);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn thumbnails(
+        &self,
+        args: Vec<ThumbnailArgs>,
+    ) -> Result<Vec<Thumbnail>, RoboatError> {
+        if let Some(arg) = args.iter().find(|arg| !arg.is_supported()) {
+            return Err(RoboatError::UnsupportedThumbnailArgs(arg.clone()));
+        }
+
+        let mut thumbnails = self.thumbnails_batch(&args).await?;
+
+        for _ in 0..MAX_PENDING_RETRIES {
+            let pending_indexes = thumbnails
+                .iter()
+                .enumerate()
+                .filter(|(_, thumbnail)| thumbnail.state == ThumbnailState::Pending)
+                .map(|(i, _)| i)
+                .collect::<Vec<_>>();
+
+            if pending_indexes.is_empty() {
+                break;
+            }
+
+            tokio::time::sleep(PENDING_RETRY_DELAY).await;
+
+            let pending_args = pending_indexes
+                .iter()
+                .map(|i| args[*i].clone())
+                .collect::<Vec<_>>();
+
+            let retried_thumbnails = self.thumbnails_batch(&pending_args).await?;
+
+            for (i, thumbnail) in pending_indexes.into_iter().zip(retried_thumbnails) {
+                thumbnails[i] = thumbnail;
+            }
+        }
+
+        Ok(thumbnails)
+    }
+
+    /// Downloads the image of a thumbnail, caching it inside `cache_dir`.
+    ///
+    /// The ETag of the image is stored alongside it, so later downloads of the same url
+    /// send an `If-None-Match` header and only download the image again if it changed.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    /// * `cache_dir` is created if it does not exist.
+    ///
+    /// # Argument Notes
+    /// * `url` is usually the [`Thumbnail::image_url`] of a thumbnail retrieved from [`Client::thumbnails`].
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * [`RoboatError::IoError`] - If the cache could not be read or written.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::thumbnails::{ThumbnailArgs, ThumbnailFormat, ThumbnailSize, ThumbnailType};
+    /// use roboat::ClientBuilder;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let args = ThumbnailArgs {
+    ///     target_id: 1365767,
+    ///     thumbnail_type: ThumbnailType::Asset,
+    ///     size: ThumbnailSize::Size420x420,
+    ///     format: ThumbnailFormat::Png,
+    /// };
+    ///
+    /// let thumbnail = client.thumbnails(vec![args]).await?.remove(0);
+    ///
+    /// if let Some(image_url) = thumbnail.image_url {
+    ///     let image = client.download_thumbnail(image_url, "thumbnail_cache").await?;
+    ///     println!("Downloaded {} bytes.", image.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn download_thumbnail(
+        &self,
+        url: String,
+        cache_dir: impl AsRef<Path>,
+    ) -> Result<Vec<u8>, RoboatError> {
+        let cache_dir = cache_dir.as_ref();
+        let file_name = cache_file_name(&url);
+        let image_path = cache_dir.join(&file_name);
+        let etag_path = cache_dir.join(format!("{}.etag", file_name));
+
+        // Only use the ETag if the image it belongs to still exists.
+        let cached_etag = match tokio::fs::try_exists(&image_path).await {
+            Ok(true) => tokio::fs::read_to_string(&etag_path).await.ok(),
+            _ => None,
+        };
+
+        let mut request = self.reqwest_client.get(&url);
+
+        if let Some(etag) = &cached_etag {
+            request = request.header(header::IF_NONE_MATCH, etag.as_str());
+        }
+
+        let response = match self.send_request(request).await {
+            Ok(response) => response,
+            // The image has not changed since it was cached.
+            Err(RoboatError::UnidentifiedStatusCode(304)) if cached_etag.is_some() => {
+                return tokio::fs::read(&image_path)
+                    .await
+                    .map_err(RoboatError::IoError);
+            }
+            Err(e) => return Err(e),
+        };
+
+        let etag = response
+            .headers()
+            .get(header::ETAG)
+            .and_then(|etag| etag.to_str().ok())
+            .map(|etag| etag.to_string());
+
+        let image = response
+            .bytes()
+            .await
+            .map_err(RoboatError::ReqwestError)?
+            .to_vec();
+
+        tokio::fs::create_dir_all(cache_dir)
+            .await
+            .map_err(RoboatError::IoError)?;
+
+        tokio::fs::write(&image_path, &image)
+            .await
+            .map_err(RoboatError::IoError)?;
+
+        match etag {
+            Some(etag) => tokio::fs::write(&etag_path, etag).await,
+            None => match tokio::fs::remove_file(&etag_path).await {
+                Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e),
+                _ => Ok(()),
+            },
+        }
+        .map_err(RoboatError::IoError)?;
+
+        Ok(image)
+    }
+
+    /// Downloads the 3D model of a user's avatar (the obj, mtl, and texture files) using
+    /// <https://thumbnails.roblox.com/v1/users/avatar-3d?userId={user_id}>.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    /// * If the model is still being generated, it is re-requested (up to 5 times, one second apart).
+    /// * Makes one request for the metadata, one for each file, and one for each texture.
+    ///
+    /// # Return Value Notes
+    /// * Returns `None` if the model is not available (for example, if it is still pending
+    ///   after all retries or was blocked).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ids::UserId;
+    /// use roboat::ClientBuilder;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let user_id = UserId(2207291);
+    ///
+    /// if let Some(avatar) = client.avatar_3d(user_id).await? {
+    ///     std::fs::write("avatar.obj", &avatar.obj)?;
+    ///     std::fs::write("avatar.mtl", &avatar.mtl)?;
+    ///
+    ///     for texture in avatar.textures {
+    ///         std::fs::write(&texture.hash, &texture.bytes)?;
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn avatar_3d(&self, user_id: UserId) -> Result<Option<Avatar3d>, RoboatError> {
+        let formatted_url = format!("{}?userId={}", AVATAR_3D_API, user_id);
+
+        let mut metadata_url = None;
+
+        for attempt in 0..=MAX_PENDING_RETRIES {
+            if attempt != 0 {
+                tokio::time::sleep(PENDING_RETRY_DELAY).await;
+            }
+
+            let request = self.reqwest_client.get(&formatted_url);
+            let response = self.send_request(request).await?;
+            let raw = Self::parse_to_raw::<request_types::Avatar3dResponse>(response).await?;
+
+            match raw.state {
+                ThumbnailState::Pending => continue,
+                ThumbnailState::Completed => {
+                    metadata_url = raw.image_url.filter(|url| !url.is_empty());
+                    break;
+                }
+                _ => break,
+            }
+        }
+
+        let Some(metadata_url) = metadata_url else {
+            return Ok(None);
+        };
+
+        let request = self.reqwest_client.get(metadata_url);
+        let response = self.send_request(request).await?;
+        let metadata = Self::parse_to_raw::<request_types::Avatar3dMetadataRaw>(response).await?;
+
+        let obj = self.download_rbxcdn_file(&metadata.obj).await?;
+        let mtl = self.download_rbxcdn_file(&metadata.mtl).await?;
+
+        let mut textures = Vec::new();
+
+        for hash in metadata.textures {
+            let bytes = self.download_rbxcdn_file(&hash).await?;
+            textures.push(Avatar3dTexture { hash, bytes });
+        }
+
+        let avatar_3d = Avatar3d {
+            obj_hash: metadata.obj,
+            obj,
+            mtl_hash: metadata.mtl,
+            mtl,
+            textures,
+        };
+
+        Ok(Some(avatar_3d))
+    }
+
+    /// Downloads a file from the rbxcdn server that hosts the file with the given hash.
+    async fn download_rbxcdn_file(&self, hash: &str) -> Result<Vec<u8>, RoboatError> {
+        let request = self.reqwest_client.get(rbxcdn_url(hash));
+        let response = self.send_request(request).await?;
+
+        let bytes = response
+            .bytes()
+            .await
+            .map_err(RoboatError::ReqwestError)?
+            .to_vec();
+
+        Ok(bytes)
+    }
+
+    /// Makes a single request to the batch endpoint, handling the x-csrf-token trampoline.
+    async fn thumbnails_batch(
+        &self,
+        args: &[ThumbnailArgs],
+    ) -> Result<Vec<Thumbnail>, RoboatError> {
+        match self.thumbnails_batch_internal(args).await {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.thumbnails_batch_internal(args).await
+                }
+                _ => Err(e),
+            },
+        }
+    }
+}
+
+/// Returns the url of a file hosted on rbxcdn. Files are spread over the servers
+/// `t0` to `t7`, where the server is picked by xoring the characters of the hash.
+fn rbxcdn_url(hash: &str) -> String {
+    let server = hash.bytes().take(32).fold(31, |acc, byte| acc ^ byte) % 8;
+    format!("https://t{}.rbxcdn.com/{}", server, hash)
+}
+
+/// Turns a url into a file name by replacing every character that is not
+/// safe to use in a file name with an underscore.
+fn cache_file_name(url: &str) -> String {
+    let url = url
+        .trim_start_matches("https://")
+        .trim_start_matches("http://");
+
+    url.chars()
+        .map(
+            |c| match c.is_ascii_alphanumeric() || c == '-' || c == '.' {
+                true => c,
+                false => '_',
+            },
+        )
+        .collect()
+}
+
+mod internal {
+    use super::{request_types, Thumbnail, ThumbnailArgs, THUMBNAILS_BATCH_API};
+    use crate::{Client, RoboatError, XCSRF_HEADER};
+
+    impl Client {
+        pub(super) async fn thumbnails_batch_internal(
+            &self,
+            args: &[ThumbnailArgs],
+        ) -> Result<Vec<Thumbnail>, RoboatError> {
+            // The request id is the index of the argument so that the
+            // response can be put back in the same order.
+            let request_body = args
+                .iter()
+                .enumerate()
+                .map(|(i, arg)| request_types::ThumbnailReq {
+                    request_id: i.to_string(),
+                    target_id: arg.target_id,
+                    thumbnail_type: arg.thumbnail_type,
+                    size: arg.size,
+                    format: arg.format,
+                })
+                .collect::<Vec<_>>();
+
+            let request = self
+                .reqwest_client
+                .post(THUMBNAILS_BATCH_API)
+                .header(XCSRF_HEADER, self.xcsrf().await)
+                .json(&request_body);
+
+            let response = self.send_request(request).await?;
+            let raw = Self::parse_to_raw::<request_types::ThumbnailBatchResponse>(response).await?;
+
+            let mut thumbnails = vec![None; args.len()];
+
+            for raw_thumbnail in raw.data {
+                let index = raw_thumbnail
+                    .request_id
+                    .and_then(|request_id| request_id.parse::<usize>().ok())
+                    .ok_or(RoboatError::MalformedResponse)?;
+
+                let arg = args.get(index).ok_or(RoboatError::MalformedResponse)?;
+
+                let thumbnail = Thumbnail {
+                    target_id: raw_thumbnail.target_id,
+                    thumbnail_type: arg.thumbnail_type,
+                    state: raw_thumbnail.state,
+                    image_url: raw_thumbnail.image_url.filter(|url| !url.is_empty()),
+                };
+
+                thumbnails[index] = Some(thumbnail);
+            }
+
+            thumbnails
+                .into_iter()
+                .collect::<Option<Vec<_>>>()
+                .ok_or(RoboatError::MalformedResponse)
+        }
+    }
+}
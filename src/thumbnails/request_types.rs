@@ -0,0 +1,45 @@
+use super::{ThumbnailFormat, ThumbnailSize, ThumbnailState, ThumbnailType};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct ThumbnailReq {
+    pub request_id: String,
+    pub target_id: u64,
+    #[serde(rename = "type")]
+    pub thumbnail_type: ThumbnailType,
+    pub size: ThumbnailSize,
+    pub format: ThumbnailFormat,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub(super) struct ThumbnailBatchResponse {
+    pub data: Vec<ThumbnailRaw>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct ThumbnailRaw {
+    pub request_id: Option<String>,
+    pub error_code: u64,
+    pub error_message: String,
+    pub target_id: u64,
+    pub state: ThumbnailState,
+    pub image_url: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct Avatar3dResponse {
+    pub target_id: u64,
+    pub state: ThumbnailState,
+    pub image_url: Option<String>,
+}
+
+/// The json file that the image url of an [`Avatar3dResponse`] points to.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub(super) struct Avatar3dMetadataRaw {
+    pub obj: String,
+    pub mtl: String,
+    pub textures: Vec<String>,
+}
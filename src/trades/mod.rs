@@ -1,15 +1,39 @@
+use crate::ids::{AssetId, Uaid, UserId};
 use crate::{Client, Limit, RoboatError};
+use chrono::{DateTime, Utc};
+use futures_util::stream::{self, StreamExt};
 use reqwest::header;
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 
 mod request_types;
 
 const INBOUND_TRADES_API: &str = "https://trades.roblox.com/v1/trades/";
 
+const DECLINE_TRADE_API_PART_1: &str = "https://trades.roblox.com/v1/trades/";
+const DECLINE_TRADE_API_PART_2: &str = "/decline";
+
+const ACCEPT_TRADE_API_PART_1: &str = "https://trades.roblox.com/v1/trades/";
+const ACCEPT_TRADE_API_PART_2: &str = "/accept";
+
+const COUNTER_TRADE_API_PART_1: &str = "https://trades.roblox.com/v1/trades/";
+const COUNTER_TRADE_API_PART_2: &str = "/counter";
+
+const TRADE_DETAILS_API: &str = "https://trades.roblox.com/v1/trades/";
+
+const SEND_TRADE_API: &str = "https://trades.roblox.com/v1/trades/send";
+
+/// The challenge type Roblox uses for two step verification. See [`RoboatError::ChallengeRequired`].
+const TWO_STEP_VERIFICATION_CHALLENGE: &str = "twostepverification";
+
 /// For requests related to trades, we use Descending as the sort order.
 /// This is because there is hardly any use case for using a reverse sort order for trades.
 const SORT_ORDER: &str = "Desc";
 
+/// The maximum amount of trades [`Client::cancel_all_outbound`] cancels at once. This is kept
+/// low as the trade endpoints have a strict rate limit.
+const MAX_CONCURRENT_CANCELLATIONS: usize = 3;
+
 /// The type of the trade you want to request (Inbound, Outbound, Completed, Inactive).
 #[derive(
     Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize, Copy,
@@ -34,6 +58,10 @@ pub struct Trade {
     pub is_active: bool,
     /// The status of the trade.
     pub status: TradeStatus,
+    /// When the trade was sent.
+    pub created: DateTime<Utc>,
+    /// When the trade expires if nobody acts on it.
+    pub expiration: DateTime<Utc>,
 }
 
 /// The status of a Roblox trade. [`Self::Open`] is the status for both
@@ -55,11 +83,125 @@ pub enum TradeStatus {
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
 #[allow(missing_docs)]
 pub struct Partner {
-    pub user_id: u64,
+    pub user_id: UserId,
     pub username: String,
     pub display_name: String,
 }
 
+/// Custom Roblox errors that occur when acting on a trade, such as in [`Client::accept_trade`],
+/// [`Client::decline_trade`], and [`Client::counter_trade`].
+#[derive(
+    thiserror::Error,
+    Debug,
+    Default,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize,
+)]
+pub enum TradeError {
+    /// Thrown when the trade is no longer active, such as when it expired or the other
+    /// party already acted on it. There is no point in retrying after this error.
+    #[default]
+    #[error("Trade Expired")]
+    TradeExpired,
+    /// Thrown when one of the collectibles in the trade is no longer owned by the
+    /// account offering it. There is no point in retrying after this error.
+    #[error("Item No Longer Owned")]
+    ItemNoLongerOwned,
+    /// Thrown when Roblox wants the account to complete two step verification before
+    /// acting on the trade. This has to be done on the website.
+    #[error("Two Step Verification Required")]
+    TwoStepVerificationRequired,
+}
+
+/// The full details of a single trade, including every item on both sides.
+/// Fetched using [`Client::trade_details`].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct TradeDetails {
+    /// The id of the trade.
+    pub trade_id: u64,
+    /// The details of the person you're trading with.
+    pub partner: Partner,
+    /// Whether one of the parties can still act on the trade.
+    pub is_active: bool,
+    /// The status of the trade.
+    pub status: TradeStatus,
+    /// When the trade was sent.
+    pub created: DateTime<Utc>,
+    /// When the trade expires if nobody acts on it.
+    pub expiration: DateTime<Utc>,
+    /// What the current account gives in the trade.
+    pub offer: TradeSide,
+    /// What the partner gives in the trade.
+    pub request: TradeSide,
+}
+
+/// One side of a trade returned by [`Client::trade_details`].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct TradeSide {
+    /// The id of the user giving this side of the trade.
+    pub user_id: UserId,
+    /// The collectibles on this side of the trade.
+    pub items: Vec<TradeItem>,
+    /// The robux on this side of the trade.
+    pub robux: u64,
+}
+
+/// A single collectible in a trade returned by [`Client::trade_details`].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct TradeItem {
+    /// The uaid of this copy of the item.
+    pub uaid: Uaid,
+    /// The asset id of the item. Used to look up its resale data (such as rap).
+    pub asset_id: AssetId,
+    /// The name of the item.
+    pub name: String,
+    /// The serial number of this copy. Only limited u items have serial numbers.
+    pub serial_number: Option<u64>,
+    /// The recent average price (rap) of the item.
+    pub recent_average_price: u64,
+    /// The price the item was originally sold for, if Roblox has one.
+    pub original_price: Option<u64>,
+    /// The total amount of copies of the item. Only limited u items have a stock.
+    pub asset_stock: Option<u64>,
+}
+
+/// One side of a trade sent using [`Client::send_trade`].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct TradeOffer {
+    /// The uaids of the collectibles on this side of the trade (up to 4).
+    pub uaids: Vec<Uaid>,
+    /// The robux on this side of the trade. Roblox takes a 30% fee from robux that are traded.
+    pub robux: u64,
+}
+
+/// The result of cancelling a single outbound trade using [`Client::cancel_all_outbound`].
+#[derive(Debug)]
+pub struct OutboundCancellation {
+    /// The trade that was cancelled.
+    pub trade: Trade,
+    /// The reason the trade was cancelled, as returned by the filter.
+    pub reason: String,
+    /// Whether the cancellation succeeded.
+    pub result: Result<(), RoboatError>,
+}
+
+/// The result of [`Client::decline_stale_inbound_trades`].
+#[derive(Debug, Default)]
+pub struct StaleTradesReport {
+    /// The amount of inbound trades that were checked.
+    pub checked: usize,
+    /// The stale trades that were declined.
+    pub declined: Vec<Trade>,
+    /// The stale trades that could not be declined, along with the error.
+    pub failed: Vec<(Trade, RoboatError)>,
+}
+
 impl Client {
     /// Returns a list of trades using the endpoint <https://trades.roblox.com/v1/{trade_type}>.
     ///
@@ -95,6 +237,656 @@ impl Client {
         limit: Limit,
         cursor: Option<String>,
     ) -> Result<Vec<Trade>, RoboatError> {
+        let (trades, _) = self.trades_page(trade_type, limit, cursor).await?;
+        Ok(trades)
+    }
+
+    /// Returns a page of inbound trades using the endpoint <https://trades.roblox.com/v1/trades/inbound>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Trades are ordered newest to oldest.
+    ///
+    /// # Return Value Notes
+    /// * The first value is a vector of trades.
+    /// * The second value is the cursor for the next page of results. If there are no more pages, this will be `None`.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    /// use roboat::Limit;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let limit = Limit::Ten;
+    /// let cursor = None;
+    ///
+    /// let (trades, next_cursor) = client.inbound_trades(limit, cursor).await?;
+    ///
+    /// for trade in trades {
+    ///     println!("Trade {} with {}", trade.trade_id, trade.partner.username);
+    /// }
+    ///
+    /// println!("Next Cursor: {}", next_cursor.unwrap_or_default());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn inbound_trades(
+        &self,
+        limit: Limit,
+        cursor: Option<String>,
+    ) -> Result<(Vec<Trade>, Option<String>), RoboatError> {
+        self.trades_page(TradeType::Inbound, limit, cursor).await
+    }
+
+    /// Returns a page of outbound trades using the endpoint <https://trades.roblox.com/v1/trades/outbound>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Trades are ordered newest to oldest.
+    ///
+    /// # Return Value Notes
+    /// * The first value is a vector of trades.
+    /// * The second value is the cursor for the next page of results. If there are no more pages, this will be `None`.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    /// use roboat::Limit;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let limit = Limit::Ten;
+    /// let cursor = None;
+    ///
+    /// let (trades, next_cursor) = client.outbound_trades(limit, cursor).await?;
+    ///
+    /// for trade in trades {
+    ///     println!("Trade {} with {}", trade.trade_id, trade.partner.username);
+    /// }
+    ///
+    /// println!("Next Cursor: {}", next_cursor.unwrap_or_default());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn outbound_trades(
+        &self,
+        limit: Limit,
+        cursor: Option<String>,
+    ) -> Result<(Vec<Trade>, Option<String>), RoboatError> {
+        self.trades_page(TradeType::Outbound, limit, cursor).await
+    }
+
+    /// Returns a page of completed trades using the endpoint <https://trades.roblox.com/v1/trades/completed>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Trades are ordered newest to oldest.
+    ///
+    /// # Return Value Notes
+    /// * The first value is a vector of trades.
+    /// * The second value is the cursor for the next page of results. If there are no more pages, this will be `None`.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    /// use roboat::Limit;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let limit = Limit::Ten;
+    /// let cursor = None;
+    ///
+    /// let (trades, next_cursor) = client.completed_trades(limit, cursor).await?;
+    ///
+    /// for trade in trades {
+    ///     println!("Trade {} with {}", trade.trade_id, trade.partner.username);
+    /// }
+    ///
+    /// println!("Next Cursor: {}", next_cursor.unwrap_or_default());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn completed_trades(
+        &self,
+        limit: Limit,
+        cursor: Option<String>,
+    ) -> Result<(Vec<Trade>, Option<String>), RoboatError> {
+        self.trades_page(TradeType::Completed, limit, cursor).await
+    }
+
+    /// Declines a trade using the endpoint <https://trades.roblox.com/v1/trades/{trade_id}/decline>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    /// * [`RoboatError::TradeError`] - If the trade expired or two step verification is required.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let trade_id = 123456789;
+    /// client.decline_trade(trade_id).await?;
+    ///
+    /// println!("Declined trade {}", trade_id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn decline_trade(&self, trade_id: u64) -> Result<(), RoboatError> {
+        match self.decline_trade_internal(trade_id).await {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.decline_trade_internal(trade_id).await
+                }
+                _ => Err(e),
+            },
+        }
+        .map_err(trade_error)
+    }
+
+    /// Accepts an inbound trade using the endpoint <https://trades.roblox.com/v1/trades/{trade_id}/accept>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    /// * [`RoboatError::TradeError`] - If the trade expired, an item is no longer owned, or
+    ///   two step verification is required.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::trades::TradeError;
+    /// use roboat::{ClientBuilder, RoboatError};
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let trade_id = 123456789;
+    ///
+    /// match client.accept_trade(trade_id).await {
+    ///     Ok(()) => println!("Accepted trade {}", trade_id),
+    ///     Err(RoboatError::TradeError(TradeError::TradeExpired)) => println!("Too late!"),
+    ///     Err(e) => return Err(e.into()),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn accept_trade(&self, trade_id: u64) -> Result<(), RoboatError> {
+        match self.accept_trade_internal(trade_id).await {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.accept_trade_internal(trade_id).await
+                }
+                _ => Err(e),
+            },
+        }
+        .map_err(trade_error)
+    }
+
+    /// Counters an inbound trade with a new offer using the endpoint
+    /// <https://trades.roblox.com/v1/trades/{trade_id}/counter>. The original trade is
+    /// declined and a new trade is sent to the same user.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    /// * Looks up the trade first to find the partner, so this makes an extra request.
+    ///
+    /// # Argument Notes
+    /// * `offer` is what the current account gives in the new trade.
+    /// * `request` is what the current account asks the partner for in the new trade.
+    ///
+    /// # Return Value Notes
+    /// * Returns the id of the new trade.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    /// * [`RoboatError::TradeError`] - If the trade expired, an item is no longer owned, or
+    ///   two step verification is required.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ids::Uaid;
+    /// use roboat::trades::TradeOffer;
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let trade_id = 123456789;
+    ///
+    /// let offer = TradeOffer {
+    ///     uaids: vec![Uaid(123456789)],
+    ///     robux: 0,
+    /// };
+    ///
+    /// let request = TradeOffer {
+    ///     uaids: vec![Uaid(987654321)],
+    ///     robux: 0,
+    /// };
+    ///
+    /// let new_trade_id = client.counter_trade(trade_id, offer, request).await?;
+    /// println!("Countered with trade {}", new_trade_id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn counter_trade(
+        &self,
+        trade_id: u64,
+        offer: TradeOffer,
+        request: TradeOffer,
+    ) -> Result<u64, RoboatError> {
+        let partner_id = self.trade_details(trade_id).await?.partner.user_id;
+
+        match self
+            .counter_trade_internal(trade_id, partner_id, &offer, &request)
+            .await
+        {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.counter_trade_internal(trade_id, partner_id, &offer, &request)
+                        .await
+                }
+                _ => Err(e),
+            },
+        }
+        .map_err(trade_error)
+    }
+
+    /// Returns the full details of a trade (including the items and robux on both sides)
+    /// using the endpoint <https://trades.roblox.com/v1/trades/{trade_id}>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Only works for trades the current account is a part of.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * [`RoboatError::MalformedResponse`] - If the trade does not have exactly one side from each party.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let trade_id = 123456789;
+    /// let details = client.trade_details(trade_id).await?;
+    ///
+    /// let offered_rap: u64 = details.offer.items.iter().map(|x| x.recent_average_price).sum();
+    /// let requested_rap: u64 = details.request.items.iter().map(|x| x.recent_average_price).sum();
+    ///
+    /// println!("Giving {} rap for {} rap.", offered_rap, requested_rap);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn trade_details(&self, trade_id: u64) -> Result<TradeDetails, RoboatError> {
+        let formatted_url = format!("{}{}", TRADE_DETAILS_API, trade_id);
+
+        let cookie_string = self.cookie_string()?;
+
+        let request = self
+            .reqwest_client
+            .get(formatted_url)
+            .header(header::COOKIE, cookie_string);
+
+        let response = self.send_request(request).await?;
+        let raw = Self::parse_to_raw::<request_types::TradeDetailsResponse>(response).await?;
+
+        let partner = Partner {
+            user_id: UserId(raw.user.id as u64),
+            username: raw.user.name,
+            display_name: raw.user.display_name,
+        };
+
+        // The partner's side is told apart by its user id, as Roblox does not
+        // guarantee which side comes first.
+        let (request_sides, offer_sides): (Vec<_>, Vec<_>) = raw
+            .offers
+            .into_iter()
+            .partition(|side| UserId(side.user.id as u64) == partner.user_id);
+
+        let (offer, request) = match (offer_sides.as_slice(), request_sides.as_slice()) {
+            ([offer], [request]) => (trade_side(offer), trade_side(request)),
+            _ => return Err(RoboatError::MalformedResponse),
+        };
+
+        Ok(TradeDetails {
+            trade_id: raw.id as u64,
+            partner,
+            is_active: raw.is_active,
+            status: raw.status,
+            created: raw.created,
+            expiration: raw.expiration,
+            offer,
+            request,
+        })
+    }
+
+    /// Sends a trade to another user using the endpoint <https://trades.roblox.com/v1/trades/send>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    /// * Both accounts must have Roblox Premium and trading enabled.
+    ///
+    /// # Argument Notes
+    /// * `offer` is what the current account gives.
+    /// * `request` is what the current account asks `partner_id` for.
+    ///
+    /// # Return Value Notes
+    /// * Returns the id of the new trade, which can be passed to [`Client::cancel_outbound_trade`].
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    /// * All errors under [X-CSRF-TOKEN Required Errors](#x-csrf-token-required-errors).
+    /// * [`RoboatError::TradeError`] - If an item is no longer owned or two step verification
+    ///   is required.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ids::{Uaid, UserId};
+    /// use roboat::trades::TradeOffer;
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let partner_id = UserId(2207291);
+    ///
+    /// let offer = TradeOffer {
+    ///     uaids: vec![Uaid(123456789)],
+    ///     robux: 1000,
+    /// };
+    ///
+    /// let request = TradeOffer {
+    ///     uaids: vec![Uaid(987654321)],
+    ///     robux: 0,
+    /// };
+    ///
+    /// let trade_id = client.send_trade(partner_id, offer, request).await?;
+    /// println!("Sent trade {}", trade_id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn send_trade(
+        &self,
+        partner_id: UserId,
+        offer: TradeOffer,
+        request: TradeOffer,
+    ) -> Result<u64, RoboatError> {
+        match self.send_trade_internal(partner_id, &offer, &request).await {
+            Ok(x) => Ok(x),
+            Err(e) => match e {
+                RoboatError::InvalidXcsrf(new_xcsrf) => {
+                    self.set_xcsrf(new_xcsrf).await;
+
+                    self.send_trade_internal(partner_id, &offer, &request).await
+                }
+                _ => Err(e),
+            },
+        }
+        .map_err(trade_error)
+    }
+
+    /// Cancels an outbound trade (a trade sent by the current account).
+    ///
+    /// Roblox uses the same endpoint for declining inbound trades and cancelling outbound trades,
+    /// so this is the same as [`Client::decline_trade`] and exists to make intent clearer.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Will repeat once if the x-csrf-token is invalid.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let trade_id = 123456789;
+    /// client.cancel_outbound_trade(trade_id).await?;
+    ///
+    /// println!("Cancelled trade {}", trade_id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn cancel_outbound_trade(&self, trade_id: u64) -> Result<(), RoboatError> {
+        self.decline_trade(trade_id).await
+    }
+
+    /// Cancels every outbound trade that `filter` gives a reason for. This is useful for
+    /// clearing out offers that no longer make sense after item values change.
+    ///
+    /// Every page of outbound trades is grabbed first, then `filter` is called on each
+    /// trade. Trades where `filter` returns `Some(reason)` are cancelled using
+    /// [`Client::cancel_outbound_trade`], up to 3 at a time.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * A trade that fails to cancel does not stop the other trades from being cancelled.
+    ///
+    /// # Return Value Notes
+    /// * Returns one [`OutboundCancellation`] for each trade that `filter` selected, in the
+    ///   order the trades were returned by Roblox (newest first).
+    ///
+    /// # Errors
+    /// * Errors from grabbing the outbound trades are returned. Errors from cancelling
+    ///   trades are put in [`OutboundCancellation::result`] instead.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ids::UserId;
+    /// use roboat::ClientBuilder;
+    /// use std::collections::HashSet;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let blocked_partners: HashSet<UserId> = HashSet::from([UserId(1), UserId(2), UserId(3)]);
+    ///
+    /// let cancellations = client
+    ///     .cancel_all_outbound(|trade| {
+    ///         blocked_partners
+    ///             .contains(&trade.partner.user_id)
+    ///             .then(|| "partner is blocked".to_string())
+    ///     })
+    ///     .await?;
+    ///
+    /// for cancellation in cancellations {
+    ///     println!(
+    ///         "Trade {} ({}): {:?}",
+    ///         cancellation.trade.trade_id, cancellation.reason, cancellation.result
+    ///     );
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn cancel_all_outbound<F>(
+        &self,
+        mut filter: F,
+    ) -> Result<Vec<OutboundCancellation>, RoboatError>
+    where
+        F: FnMut(&Trade) -> Option<String>,
+    {
+        let outbound_trades = crate::pagination::all_pages(None, |cursor| {
+            self.trades_page(TradeType::Outbound, Limit::Hundred, cursor)
+        })
+        .await?;
+
+        let selected_trades = outbound_trades
+            .into_iter()
+            .filter_map(|trade| filter(&trade).map(|reason| (trade, reason)))
+            .collect::<Vec<_>>();
+
+        let cancellations = stream::iter(selected_trades)
+            .map(|(trade, reason)| async move {
+                let result = self.cancel_outbound_trade(trade.trade_id).await;
+
+                OutboundCancellation {
+                    trade,
+                    reason,
+                    result,
+                }
+            })
+            .buffered(MAX_CONCURRENT_CANCELLATIONS)
+            .collect()
+            .await;
+
+        Ok(cancellations)
+    }
+
+    /// Declines every inbound trade that was sent more than `older_than` ago.
+    ///
+    /// Every page of inbound trades is grabbed first, and then the stale trades are declined
+    /// one at a time using [`Client::decline_trade`].
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * A trade that fails to decline does not stop the other trades from being declined.
+    ///
+    /// # Errors
+    /// * Errors from grabbing the inbound trades are returned. Errors from declining
+    ///   trades are put in [`StaleTradesReport::failed`] instead.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    /// use std::time::Duration;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let three_days = Duration::from_secs(3 * 86400);
+    /// let report = client.decline_stale_inbound_trades(three_days).await?;
+    ///
+    /// println!(
+    ///     "Checked {} trades, declined {}, failed to decline {}.",
+    ///     report.checked,
+    ///     report.declined.len(),
+    ///     report.failed.len()
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn decline_stale_inbound_trades(
+        &self,
+        older_than: Duration,
+    ) -> Result<StaleTradesReport, RoboatError> {
+        // No trade can be older than an age reaching past the earliest representable date.
+        let now = Utc::now();
+        let cutoff = chrono::Duration::from_std(older_than)
+            .ok()
+            .and_then(|older_than| now.checked_sub_signed(older_than))
+            .unwrap_or(DateTime::<Utc>::MIN_UTC);
+
+        let inbound_trades = crate::pagination::all_pages(None, |cursor| {
+            self.trades_page(TradeType::Inbound, Limit::Hundred, cursor)
+        })
+        .await?;
+
+        let mut report = StaleTradesReport {
+            checked: inbound_trades.len(),
+            ..Default::default()
+        };
+
+        for trade in inbound_trades {
+            if trade.created >= cutoff {
+                continue;
+            }
+
+            match self.decline_trade(trade.trade_id).await {
+                Ok(()) => report.declined.push(trade),
+                Err(e) => report.failed.push((trade, e)),
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// The same as [`Client::trades`], but also returns the cursor of the next page.
+    async fn trades_page(
+        &self,
+        trade_type: TradeType,
+        limit: Limit,
+        cursor: Option<String>,
+    ) -> Result<(Vec<Trade>, Option<String>), RoboatError> {
         let limit = limit.to_u64();
         let cursor = cursor.unwrap_or_default();
 
@@ -112,21 +904,19 @@ impl Client {
             INBOUND_TRADES_API, trade_type_str, SORT_ORDER, cursor, limit
         );
 
-        let request_result = self
+        let request = self
             .reqwest_client
             .get(&formatted_url)
-            .header(header::COOKIE, cookie_string)
-            .send()
-            .await;
+            .header(header::COOKIE, cookie_string);
 
-        let response = Self::validate_request_result(request_result).await?;
+        let response = self.send_request(request).await?;
         let raw = Self::parse_to_raw::<request_types::InboundTradesResponse>(response).await?;
 
         let mut trades = Vec::new();
 
         for trade in raw.data {
             let partner = Partner {
-                user_id: trade.user.id as u64,
+                user_id: UserId(trade.user.id as u64),
                 username: trade.user.name,
                 display_name: trade.user.display_name,
             };
@@ -136,11 +926,200 @@ impl Client {
                 partner,
                 is_active: trade.is_active,
                 status: trade.status,
+                created: trade.created,
+                expiration: trade.expiration,
             };
 
             trades.push(trade);
         }
 
-        Ok(trades)
+        Ok((trades, raw.next_page_cursor))
+    }
+}
+
+fn trade_side(raw: &request_types::TradeSideRaw) -> TradeSide {
+    let items = raw
+        .user_assets
+        .iter()
+        .map(|item| TradeItem {
+            uaid: Uaid(item.id),
+            asset_id: AssetId(item.asset_id),
+            name: item.name.clone(),
+            serial_number: item.serial_number,
+            recent_average_price: item.recent_average_price,
+            original_price: item.original_price,
+            asset_stock: item.asset_stock,
+        })
+        .collect();
+
+    TradeSide {
+        user_id: UserId(raw.user.id as u64),
+        items,
+        robux: raw.robux,
+    }
+}
+
+/// Converts the errors Roblox gives for trade actions into a [`TradeError`] when possible.
+/// Other errors are returned as-is.
+fn trade_error(error: RoboatError) -> RoboatError {
+    match &error {
+        RoboatError::UnknownRobloxErrorCode { message, .. } => {
+            let message = message.to_lowercase();
+
+            if message.contains("inactive") || message.contains("expired") {
+                RoboatError::TradeError(TradeError::TradeExpired)
+            } else if message.contains("userassets are invalid") || message.contains("not owned") {
+                RoboatError::TradeError(TradeError::ItemNoLongerOwned)
+            } else {
+                error
+            }
+        }
+        RoboatError::ChallengeRequired(challenge_type)
+            if challenge_type.eq_ignore_ascii_case(TWO_STEP_VERIFICATION_CHALLENGE) =>
+        {
+            RoboatError::TradeError(TradeError::TwoStepVerificationRequired)
+        }
+        _ => error,
+    }
+}
+
+mod internal {
+    use super::{
+        request_types, TradeOffer, ACCEPT_TRADE_API_PART_1, ACCEPT_TRADE_API_PART_2,
+        COUNTER_TRADE_API_PART_1, COUNTER_TRADE_API_PART_2, DECLINE_TRADE_API_PART_1,
+        DECLINE_TRADE_API_PART_2, SEND_TRADE_API,
+    };
+    use crate::ids::UserId;
+    use crate::{Client, RoboatError, XCSRF_HEADER};
+    use reqwest::header;
+
+    impl Client {
+        pub(super) async fn decline_trade_internal(
+            &self,
+            trade_id: u64,
+        ) -> Result<(), RoboatError> {
+            let formatted_url = format!(
+                "{}{}{}",
+                DECLINE_TRADE_API_PART_1, trade_id, DECLINE_TRADE_API_PART_2
+            );
+
+            let cookie = self.cookie_string()?;
+
+            let request = self
+                .reqwest_client
+                .post(formatted_url)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, self.xcsrf().await);
+
+            let _ = self
+                .send_mutating_request(request, serde_json::json!({}))
+                .await?;
+
+            Ok(())
+        }
+
+        pub(super) async fn send_trade_internal(
+            &self,
+            partner_id: UserId,
+            offer: &TradeOffer,
+            request: &TradeOffer,
+        ) -> Result<u64, RoboatError> {
+            let cookie = self.cookie_string()?;
+            let user_id = self.user_id().await?;
+
+            let body = trade_req_body(user_id, partner_id, offer, request);
+
+            let request = self
+                .reqwest_client
+                .post(SEND_TRADE_API)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, self.xcsrf().await)
+                .json(&body);
+
+            let response = self
+                .send_mutating_request(request, serde_json::json!({ "id": 0 }))
+                .await?;
+
+            let raw = Self::parse_to_raw::<request_types::SendTradeResponse>(response).await?;
+
+            Ok(raw.id)
+        }
+
+        pub(super) async fn accept_trade_internal(&self, trade_id: u64) -> Result<(), RoboatError> {
+            let formatted_url = format!(
+                "{}{}{}",
+                ACCEPT_TRADE_API_PART_1, trade_id, ACCEPT_TRADE_API_PART_2
+            );
+
+            let cookie = self.cookie_string()?;
+
+            let request = self
+                .reqwest_client
+                .post(formatted_url)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, self.xcsrf().await);
+
+            let _ = self
+                .send_mutating_request(request, serde_json::json!({}))
+                .await?;
+
+            Ok(())
+        }
+
+        pub(super) async fn counter_trade_internal(
+            &self,
+            trade_id: u64,
+            partner_id: UserId,
+            offer: &TradeOffer,
+            request: &TradeOffer,
+        ) -> Result<u64, RoboatError> {
+            let formatted_url = format!(
+                "{}{}{}",
+                COUNTER_TRADE_API_PART_1, trade_id, COUNTER_TRADE_API_PART_2
+            );
+
+            let cookie = self.cookie_string()?;
+            let user_id = self.user_id().await?;
+
+            let body = trade_req_body(user_id, partner_id, offer, request);
+
+            let request = self
+                .reqwest_client
+                .post(formatted_url)
+                .header(header::COOKIE, cookie)
+                .header(XCSRF_HEADER, self.xcsrf().await)
+                .json(&body);
+
+            let response = self
+                .send_mutating_request(request, serde_json::json!({ "id": 0 }))
+                .await?;
+
+            let raw = Self::parse_to_raw::<request_types::SendTradeResponse>(response).await?;
+
+            Ok(raw.id)
+        }
+    }
+
+    /// Builds the body used to send or counter a trade. Roblox expects the current
+    /// account's side first.
+    fn trade_req_body(
+        user_id: UserId,
+        partner_id: UserId,
+        offer: &TradeOffer,
+        request: &TradeOffer,
+    ) -> request_types::SendTradeReqBody {
+        let to_req_body =
+            |user_id: UserId, trade_offer: &TradeOffer| request_types::TradeOfferReqBody {
+                user_id,
+                user_asset_ids: trade_offer.uaids.iter().map(|uaid| uaid.0).collect(),
+                robux: trade_offer.robux,
+            };
+
+        request_types::SendTradeReqBody {
+            offers: vec![
+                to_req_body(user_id, offer),
+                to_req_body(partner_id, request),
+            ],
+        }
     }
 }
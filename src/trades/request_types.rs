@@ -1,4 +1,6 @@
 use super::TradeStatus;
+use crate::ids::UserId;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize)]
@@ -14,8 +16,8 @@ pub(super) struct InboundTradesResponse {
 pub(super) struct TradeRaw {
     pub id: i64,
     pub user: TradeUserRaw,
-    pub created: String,
-    pub expiration: String,
+    pub created: DateTime<Utc>,
+    pub expiration: DateTime<Utc>,
     pub is_active: bool,
     pub status: TradeStatus,
 }
@@ -27,3 +29,55 @@ pub(super) struct TradeUserRaw {
     pub name: String,
     pub display_name: String,
 }
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct SendTradeReqBody {
+    pub offers: Vec<TradeOfferReqBody>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct TradeOfferReqBody {
+    pub user_id: UserId,
+    pub user_asset_ids: Vec<u64>,
+    pub robux: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct SendTradeResponse {
+    pub id: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct TradeDetailsResponse {
+    pub offers: Vec<TradeSideRaw>,
+    pub id: i64,
+    pub user: TradeUserRaw,
+    pub created: DateTime<Utc>,
+    pub expiration: DateTime<Utc>,
+    pub is_active: bool,
+    pub status: TradeStatus,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct TradeSideRaw {
+    pub user: TradeUserRaw,
+    pub user_assets: Vec<TradeUserAssetRaw>,
+    pub robux: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct TradeUserAssetRaw {
+    pub id: u64,
+    pub serial_number: Option<u64>,
+    pub asset_id: u64,
+    pub name: String,
+    pub recent_average_price: u64,
+    pub original_price: Option<u64>,
+    pub asset_stock: Option<u64>,
+}
@@ -0,0 +1,293 @@
+use crate::ids::{UniverseId, UserId};
+use crate::{cloud, Client, RoboatError, API_KEY_HEADER};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+mod request_types;
+
+const USER_RESTRICTIONS_API: &str = "https://apis.roblox.com/cloud/v2/universes/";
+
+/// The amount of restrictions requested per page by [`Client::universe_user_restrictions`].
+const USER_RESTRICTIONS_PAGE_SIZE: u64 = 100;
+
+/// The longest reason, in characters, that a restriction can have.
+const REASON_LENGTH_LIMIT: usize = 400;
+
+/// A ban of a user from a universe (experience). Retrieved from
+/// <https://apis.roblox.com/cloud/v2/universes/{universe_id}/user-restrictions>.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct UserRestriction {
+    /// The id of the banned user.
+    pub user_id: UserId,
+    /// Whether the ban is in effect. Bans that expired or were lifted are not active.
+    pub active: bool,
+    /// When the ban started.
+    pub start_time: Option<DateTime<Utc>>,
+    /// How long the ban lasts for. This is `None` if the ban is permanent.
+    pub duration: Option<Duration>,
+    /// The reason shown to moderators.
+    pub private_reason: String,
+    /// The reason shown to the banned user.
+    pub display_reason: String,
+    /// Whether alternate accounts of the user are left out of the ban.
+    pub exclude_alt_accounts: bool,
+}
+
+impl Client {
+    /// Bans a user from a universe (experience) using the Open Cloud endpoint
+    /// <https://apis.roblox.com/cloud/v2/universes/{universe_id}/user-restrictions/{user_id}>.
+    ///
+    /// Banned users are kicked from every server of the universe and cannot join it again
+    /// until the ban ends or is lifted with [`Client::unban_user_from_universe`].
+    ///
+    /// # Notes
+    /// * Requires an Open Cloud api key set using [`ClientBuilder::api_key`](crate::ClientBuilder::api_key).
+    ///   The key needs the `universe.user-restriction:write` permission for the universe.
+    /// * Does not require a valid roblosecurity.
+    /// * Alternate accounts of the user are banned as well.
+    ///
+    /// # Argument Notes
+    /// * The ban is permanent if `duration` is `None`.
+    /// * `reason` is shown to the banned user and to moderators, and can be up to 400 characters long.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * [`RoboatError::ApiKeyNotSet`] - If no api key is set.
+    /// * [`RoboatError::InvalidRoblosecurity`] - If the api key is invalid or lacks permission.
+    /// * [`RoboatError::InvalidMessage`] - If `reason` is too long.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ids::{UniverseId, UserId};
+    /// use roboat::ClientBuilder;
+    /// use std::time::Duration;
+    ///
+    /// const API_KEY: &str = "api_key";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().api_key(API_KEY.to_string()).build();
+    ///
+    /// let universe_id = UniverseId(1234567890);
+    /// let user_id = UserId(1);
+    /// let one_week = Duration::from_secs(7 * 86400);
+    ///
+    /// client
+    ///     .ban_user_from_universe(universe_id, user_id, Some(one_week), "Exploiting")
+    ///     .await?;
+    ///
+    /// println!("Banned user.");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn ban_user_from_universe(
+        &self,
+        universe_id: UniverseId,
+        user_id: UserId,
+        duration: Option<Duration>,
+        reason: &str,
+    ) -> Result<(), RoboatError> {
+        if reason.chars().count() > REASON_LENGTH_LIMIT {
+            return Err(RoboatError::InvalidMessage(format!(
+                "Reason must be at most {} characters",
+                REASON_LENGTH_LIMIT
+            )));
+        }
+
+        let restriction = request_types::GameJoinRestrictionReq {
+            active: true,
+            duration: duration.map(cloud::format_duration),
+            private_reason: Some(reason.to_string()),
+            display_reason: Some(reason.to_string()),
+            exclude_alt_accounts: Some(false),
+        };
+
+        self.update_user_restriction(universe_id, user_id, restriction)
+            .await
+    }
+
+    /// Lifts a ban of a user from a universe (experience) using the Open Cloud endpoint
+    /// <https://apis.roblox.com/cloud/v2/universes/{universe_id}/user-restrictions/{user_id}>.
+    ///
+    /// # Notes
+    /// * Requires an Open Cloud api key set using [`ClientBuilder::api_key`](crate::ClientBuilder::api_key).
+    ///   The key needs the `universe.user-restriction:write` permission for the universe.
+    /// * Does not require a valid roblosecurity.
+    /// * Succeeds even if the user is not banned.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * [`RoboatError::ApiKeyNotSet`] - If no api key is set.
+    /// * [`RoboatError::InvalidRoblosecurity`] - If the api key is invalid or lacks permission.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ids::{UniverseId, UserId};
+    /// use roboat::ClientBuilder;
+    ///
+    /// const API_KEY: &str = "api_key";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().api_key(API_KEY.to_string()).build();
+    ///
+    /// let universe_id = UniverseId(1234567890);
+    /// let user_id = UserId(1);
+    ///
+    /// client.unban_user_from_universe(universe_id, user_id).await?;
+    ///
+    /// println!("Unbanned user.");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn unban_user_from_universe(
+        &self,
+        universe_id: UniverseId,
+        user_id: UserId,
+    ) -> Result<(), RoboatError> {
+        let restriction = request_types::GameJoinRestrictionReq {
+            active: false,
+            duration: None,
+            private_reason: None,
+            display_reason: None,
+            exclude_alt_accounts: None,
+        };
+
+        self.update_user_restriction(universe_id, user_id, restriction)
+            .await
+    }
+
+    /// Grabs a page of the user bans of a universe (experience) using the Open Cloud endpoint
+    /// <https://apis.roblox.com/cloud/v2/universes/{universe_id}/user-restrictions>.
+    ///
+    /// # Notes
+    /// * Requires an Open Cloud api key set using [`ClientBuilder::api_key`](crate::ClientBuilder::api_key).
+    ///   The key needs the `universe.user-restriction:read` permission for the universe.
+    /// * Does not require a valid roblosecurity.
+    /// * Returns up to 100 restrictions per page.
+    ///
+    /// # Argument Notes
+    /// * The page token is used to get a certain page of results. If you want the starting page, use `None`.
+    ///
+    /// # Return Value Notes
+    /// * The first value is a vector of restrictions, including ones that are no longer active.
+    /// * The second value is the token for the next page of results. If there are no more pages, this will be `None`.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * [`RoboatError::ApiKeyNotSet`] - If no api key is set.
+    /// * [`RoboatError::InvalidRoblosecurity`] - If the api key is invalid or lacks permission.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ids::UniverseId;
+    /// use roboat::ClientBuilder;
+    ///
+    /// const API_KEY: &str = "api_key";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().api_key(API_KEY.to_string()).build();
+    ///
+    /// let universe_id = UniverseId(1234567890);
+    /// let (restrictions, _) = client.universe_user_restrictions(universe_id, None).await?;
+    ///
+    /// for restriction in restrictions.iter().filter(|x| x.active) {
+    ///     println!("{} is banned: {}", restriction.user_id, restriction.private_reason);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn universe_user_restrictions(
+        &self,
+        universe_id: UniverseId,
+        page_token: Option<String>,
+    ) -> Result<(Vec<UserRestriction>, Option<String>), RoboatError> {
+        let api_key = self.api_key()?;
+
+        let formatted_url = format!(
+            "{}{}/user-restrictions?maxPageSize={}&pageToken={}",
+            USER_RESTRICTIONS_API,
+            universe_id,
+            USER_RESTRICTIONS_PAGE_SIZE,
+            page_token.unwrap_or_default()
+        );
+
+        let request = self
+            .reqwest_client
+            .get(formatted_url)
+            .header(API_KEY_HEADER, api_key);
+
+        let response = self.send_request(request).await?;
+        let raw = Self::parse_to_raw::<request_types::UserRestrictionsResponse>(response).await?;
+
+        let mut restrictions = Vec::new();
+
+        for restriction in raw.user_restrictions {
+            let user_id = restriction
+                .user
+                .strip_prefix("users/")
+                .and_then(|user_id| user_id.parse().ok())
+                .ok_or(RoboatError::MalformedResponse)?;
+
+            let game_join_restriction = restriction.game_join_restriction;
+
+            let duration = match game_join_restriction.duration {
+                Some(duration) => Some(cloud::parse_duration(&duration)?),
+                None => None,
+            };
+
+            restrictions.push(UserRestriction {
+                user_id,
+                active: game_join_restriction.active,
+                start_time: game_join_restriction.start_time,
+                duration,
+                private_reason: game_join_restriction.private_reason,
+                display_reason: game_join_restriction.display_reason,
+                exclude_alt_accounts: game_join_restriction.exclude_alt_accounts,
+            });
+        }
+
+        let next_page_token = match raw.next_page_token.is_empty() {
+            true => None,
+            false => Some(raw.next_page_token),
+        };
+
+        Ok((restrictions, next_page_token))
+    }
+
+    /// Used by [`Client::ban_user_from_universe`] and [`Client::unban_user_from_universe`],
+    /// which only differ in `restriction`.
+    async fn update_user_restriction(
+        &self,
+        universe_id: UniverseId,
+        user_id: UserId,
+        restriction: request_types::GameJoinRestrictionReq,
+    ) -> Result<(), RoboatError> {
+        let api_key = self.api_key()?;
+
+        let formatted_url = format!(
+            "{}{}/user-restrictions/{}?updateMask=gameJoinRestriction",
+            USER_RESTRICTIONS_API, universe_id, user_id
+        );
+
+        let body = request_types::UpdateUserRestrictionReqBody {
+            game_join_restriction: restriction,
+        };
+
+        let request = self
+            .reqwest_client
+            .patch(formatted_url)
+            .header(API_KEY_HEADER, api_key)
+            .json(&body);
+
+        let _ = self
+            .send_mutating_request(request, serde_json::json!({}))
+            .await?;
+
+        // We don't need to do anything, we just need a 200 status code.
+
+        Ok(())
+    }
+}
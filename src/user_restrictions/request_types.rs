@@ -0,0 +1,55 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct UserRestrictionsResponse {
+    #[serde(default)]
+    pub user_restrictions: Vec<UserRestrictionRaw>,
+    #[serde(default)]
+    pub next_page_token: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct UserRestrictionRaw {
+    /// In the form of `users/{user_id}`.
+    pub user: String,
+    pub game_join_restriction: GameJoinRestrictionRaw,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct GameJoinRestrictionRaw {
+    #[serde(default)]
+    pub active: bool,
+    pub start_time: Option<DateTime<Utc>>,
+    /// In the form of `{seconds}s`. Does not exist for permanent bans.
+    pub duration: Option<String>,
+    #[serde(default)]
+    pub private_reason: String,
+    #[serde(default)]
+    pub display_reason: String,
+    #[serde(default)]
+    pub exclude_alt_accounts: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct UpdateUserRestrictionReqBody {
+    pub game_join_restriction: GameJoinRestrictionReq,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct GameJoinRestrictionReq {
+    pub active: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private_reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exclude_alt_accounts: Option<bool>,
+}
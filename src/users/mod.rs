@@ -1,36 +1,75 @@
+use crate::ids::UserId;
 use crate::{Client, RoboatError};
 use reqwest::header::{self, HeaderValue};
 use serde::{Deserialize, Serialize};
+use std::future::Future;
 
 mod request_types;
 
 const USER_DETAILS_API: &str = "https://users.roblox.com/v1/users/authenticated";
 const USERS_SEARCH_API: &str = "https://users.roblox.com/v1/users/search";
 
+const PREMIUM_MEMBERSHIP_API_PART_1: &str = "https://premiumfeatures.roblox.com/v1/users/";
+const PREMIUM_MEMBERSHIP_API_PART_2: &str = "/validate-membership";
+
+const TWO_STEP_CONFIGURATION_API_PART_1: &str = "https://twostepverification.roblox.com/v1/users/";
+const TWO_STEP_CONFIGURATION_API_PART_2: &str = "/configuration";
+
+/// The score bonus given to users with a verified badge in [`Client::search_users_ranked`].
+const VERIFIED_BADGE_BONUS: f64 = 0.1;
+
 /// Basic information about the account of the Roblosecurity. Retrieved
 /// from <https://users.roblox.com/v1/users/authenticated>.
 #[allow(missing_docs)]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
 pub(crate) struct ClientUserInformation {
     #[serde(alias = "id")]
-    pub user_id: u64,
+    pub user_id: UserId,
     #[serde(alias = "name")]
     pub username: String,
     #[serde(alias = "displayName")]
     pub display_name: String,
 }
 
+/// A summary of the account of the Roblosecurity, such as for checking that a bot is logged
+/// into the right account on startup. Retrieved using [`Client::whoami`].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+pub struct WhoAmI {
+    /// The user id of the account.
+    pub user_id: UserId,
+    /// The username of the account.
+    pub username: String,
+    /// The display name of the account.
+    pub display_name: String,
+    /// The robux of the account.
+    pub robux: u64,
+    /// Whether the account has Roblox Premium.
+    pub has_premium: bool,
+    /// Whether the account has two step verification turned on with any method.
+    pub two_step_verification_enabled: bool,
+}
+
 /// The details of a user. Fetched from <https://users.roblox.com/v1/users/search?keyword={keyword}>.
 #[allow(missing_docs)]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
 pub struct User {
-    pub user_id: u64,
+    pub user_id: UserId,
     pub username: String,
     pub display_name: String,
     pub has_verified_badge: bool,
     pub previous_usernames: Vec<String>,
 }
 
+/// A user returned from [`Client::search_users_ranked`], along with how closely it matched the query.
+#[derive(Clone, Debug, PartialEq, PartialOrd, Default, Serialize, Deserialize)]
+pub struct RankedUser {
+    /// The user that matched the query.
+    pub user: User,
+    /// How closely the user matched the query. A score of 1.0 is an exact match, and
+    /// verified users receive a small bonus on top of their similarity.
+    pub score: f64,
+}
+
 impl Client {
     /// Grabs information about the user from <https://catalog.roblox.com/v1/catalog/items/details> using the
     /// Roblosecurity inside the client.
@@ -44,14 +83,12 @@ impl Client {
     ) -> Result<ClientUserInformation, RoboatError> {
         let cookie = self.cookie_string()?;
 
-        let request_result = self
+        let request = self
             .reqwest_client
             .get(USER_DETAILS_API)
-            .header(header::COOKIE, cookie)
-            .send()
-            .await;
+            .header(header::COOKIE, cookie);
 
-        let response = Self::validate_request_result(request_result).await?;
+        let response = self.send_request(request).await?;
         let user_information = Self::parse_to_raw::<ClientUserInformation>(response).await?;
 
         // Cache results.
@@ -60,6 +97,104 @@ impl Client {
         Ok(user_information)
     }
 
+    /// Grabs a summary of the account of the Roblosecurity: its user details, robux, premium
+    /// status, and whether two step verification is turned on.
+    ///
+    /// The user details come from [`Client::user_id`], [`Client::username`], and
+    /// [`Client::display_name`], the robux from [`Client::robux`], the premium status from
+    /// <https://premiumfeatures.roblox.com/v1/users/{user_id}/validate-membership>, and the
+    /// two step verification status from
+    /// <https://twostepverification.roblox.com/v1/users/{user_id}/configuration>.
+    ///
+    /// # Notes
+    /// * Requires a valid roblosecurity.
+    /// * Everything after the user id is grabbed at the same time.
+    ///
+    /// # Errors
+    /// * All errors under [Standard Errors](#standard-errors).
+    /// * All errors under [Auth Required Errors](#auth-required-errors).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// const ROBLOSECURITY: &str = "roblosecurity";
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().roblosecurity(ROBLOSECURITY.to_string()).build();
+    ///
+    /// let me = client.whoami().await?;
+    ///
+    /// println!("Logged in as {} ({})", me.username, me.user_id);
+    /// println!("Robux: {}", me.robux);
+    /// println!("Premium: {}", me.has_premium);
+    /// println!("2SV: {}", me.two_step_verification_enabled);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn whoami(&self) -> Result<WhoAmI, RoboatError> {
+        let user_id = self.user_id().await?;
+
+        let (username, display_name, robux, has_premium, two_step_verification_enabled) = tokio::try_join!(
+            self.username(),
+            self.display_name(),
+            self.robux(),
+            self.has_premium(user_id),
+            self.two_step_verification_enabled(user_id),
+        )?;
+
+        Ok(WhoAmI {
+            user_id,
+            username,
+            display_name,
+            robux,
+            has_premium,
+            two_step_verification_enabled,
+        })
+    }
+
+    /// Used by [`Client::whoami`] to check whether a user has Roblox Premium.
+    async fn has_premium(&self, user_id: UserId) -> Result<bool, RoboatError> {
+        let formatted_url = format!(
+            "{}{}{}",
+            PREMIUM_MEMBERSHIP_API_PART_1, user_id, PREMIUM_MEMBERSHIP_API_PART_2
+        );
+
+        let cookie = self.cookie_string()?;
+
+        let request = self
+            .reqwest_client
+            .get(formatted_url)
+            .header(header::COOKIE, cookie);
+
+        let response = self.send_request(request).await?;
+
+        Self::parse_to_raw::<bool>(response).await
+    }
+
+    /// Used by [`Client::whoami`] to check whether a user has any two step verification
+    /// method turned on.
+    async fn two_step_verification_enabled(&self, user_id: UserId) -> Result<bool, RoboatError> {
+        let formatted_url = format!(
+            "{}{}{}",
+            TWO_STEP_CONFIGURATION_API_PART_1, user_id, TWO_STEP_CONFIGURATION_API_PART_2
+        );
+
+        let cookie = self.cookie_string()?;
+
+        let request = self
+            .reqwest_client
+            .get(formatted_url)
+            .header(header::COOKIE, cookie);
+
+        let response = self.send_request(request).await?;
+        let raw =
+            Self::parse_to_raw::<request_types::TwoStepConfigurationResponse>(response).await?;
+
+        Ok(raw.methods.iter().any(|method| method.enabled))
+    }
+
     /// Searches for a user using <https://users.roblox.com/v1/users/search>.
     ///
     /// # Notes
@@ -97,21 +232,19 @@ impl Client {
 
         let cookie_string = self.cookie_string().unwrap_or(HeaderValue::from_static(""));
 
-        let request_result = self
+        let request = self
             .reqwest_client
             .get(formatted_url)
-            .header(header::COOKIE, cookie_string)
-            .send()
-            .await;
+            .header(header::COOKIE, cookie_string);
 
-        let response = Self::validate_request_result(request_result).await?;
+        let response = self.send_request(request).await?;
         let raw = Self::parse_to_raw::<request_types::UserSearchResponse>(response).await?;
 
         let mut users = Vec::new();
 
         for user in raw.data {
             let user_data = User {
-                user_id: user.id,
+                user_id: UserId(user.id),
                 username: user.name,
                 display_name: user.display_name,
                 has_verified_badge: user.has_verified_badge,
@@ -123,4 +256,187 @@ impl Client {
 
         Ok(users)
     }
+
+    /// Searches for a user using <https://users.roblox.com/v1/users/search>, then ranks
+    /// the results by how closely their username or display name matches the query.
+    ///
+    /// # Notes
+    /// * Does not require a valid roblosecurity.
+    /// * HOWEVER, if a valid roblosecurity is not provided then there will be a very low rate limit.
+    /// * Similarity is measured using the Levenshtein distance (case insensitive) against the
+    ///   username, display name, and previous usernames of each user, using whichever matches best.
+    /// * Verified users receive a small bonus to their score.
+    ///
+    /// # Argument Notes
+    /// * `limit` is the maximum amount of ranked users returned.
+    ///
+    /// # Return Value Notes
+    /// * Users are sorted from the best match to the worst match.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use roboat::ClientBuilder;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = ClientBuilder::new().build();
+    ///
+    /// let query = "linkmon".to_string();
+    /// let ranked_users = client.search_users_ranked(query, 5).await?;
+    ///
+    /// for ranked_user in ranked_users {
+    ///     println!("{} ({:.2})", ranked_user.user.username, ranked_user.score);
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn search_users_ranked(
+        &self,
+        query: String,
+        limit: usize,
+    ) -> Result<Vec<RankedUser>, RoboatError> {
+        let users = self.user_search(query.clone()).await?;
+
+        let query = query.to_lowercase();
+
+        let mut ranked_users = users
+            .into_iter()
+            .map(|user| {
+                let best_similarity = std::iter::once(&user.username)
+                    .chain(std::iter::once(&user.display_name))
+                    .chain(user.previous_usernames.iter())
+                    .map(|name| similarity(&query, &name.to_lowercase()))
+                    .fold(0.0, f64::max);
+
+                let score = match user.has_verified_badge {
+                    true => best_similarity + VERIFIED_BADGE_BONUS,
+                    false => best_similarity,
+                };
+
+                RankedUser { user, score }
+            })
+            .collect::<Vec<_>>();
+
+        ranked_users.sort_by(|a, b| b.score.total_cmp(&a.score));
+        ranked_users.truncate(limit);
+
+        Ok(ranked_users)
+    }
+}
+
+/// The endpoints of this module as a trait, implemented by [`Client`]. This also includes
+/// the user details of the current account ([`Client::user_id`], [`Client::username`],
+/// and [`Client::display_name`]).
+///
+/// Code written against `impl UsersApi` instead of [`Client`] can be given a mock
+/// implementation in tests. Every method behaves exactly like the [`Client`] method of the same name.
+///
+/// # Example
+/// ```no_run
+/// use roboat::users::UsersApi;
+/// use roboat::{ClientBuilder, RoboatError};
+///
+/// async fn greet(users: &impl UsersApi) -> Result<String, RoboatError> {
+///     Ok(format!("Hello, {}!", users.display_name().await?))
+/// }
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = ClientBuilder::new().roblosecurity("roblosecurity".to_string()).build();
+///
+/// println!("{}", greet(&client).await?);
+/// # Ok(())
+/// # }
+/// ```
+#[allow(missing_docs)]
+pub trait UsersApi {
+    fn user_id(&self) -> impl Future<Output = Result<UserId, RoboatError>> + Send;
+
+    fn username(&self) -> impl Future<Output = Result<String, RoboatError>> + Send;
+
+    fn display_name(&self) -> impl Future<Output = Result<String, RoboatError>> + Send;
+
+    fn whoami(&self) -> impl Future<Output = Result<WhoAmI, RoboatError>> + Send;
+
+    fn user_search(
+        &self,
+        keyword: String,
+    ) -> impl Future<Output = Result<Vec<User>, RoboatError>> + Send;
+
+    fn search_users_ranked(
+        &self,
+        query: String,
+        limit: usize,
+    ) -> impl Future<Output = Result<Vec<RankedUser>, RoboatError>> + Send;
+}
+
+impl UsersApi for Client {
+    fn user_id(&self) -> impl Future<Output = Result<UserId, RoboatError>> + Send {
+        Client::user_id(self)
+    }
+
+    fn username(&self) -> impl Future<Output = Result<String, RoboatError>> + Send {
+        Client::username(self)
+    }
+
+    fn display_name(&self) -> impl Future<Output = Result<String, RoboatError>> + Send {
+        Client::display_name(self)
+    }
+
+    fn whoami(&self) -> impl Future<Output = Result<WhoAmI, RoboatError>> + Send {
+        Client::whoami(self)
+    }
+
+    fn user_search(
+        &self,
+        keyword: String,
+    ) -> impl Future<Output = Result<Vec<User>, RoboatError>> + Send {
+        Client::user_search(self, keyword)
+    }
+
+    fn search_users_ranked(
+        &self,
+        query: String,
+        limit: usize,
+    ) -> impl Future<Output = Result<Vec<RankedUser>, RoboatError>> + Send {
+        Client::search_users_ranked(self, query, limit)
+    }
+}
+
+/// Returns a value from 0.0 to 1.0 representing how similar two strings are,
+/// where 1.0 means they are identical.
+fn similarity(a: &str, b: &str) -> f64 {
+    let max_len = a.chars().count().max(b.chars().count());
+
+    if max_len == 0 {
+        return 1.0;
+    }
+
+    1.0 - (levenshtein_distance(a, b) as f64 / max_len as f64)
+}
+
+/// Computes the Levenshtein distance between two strings using a single row buffer.
+fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let b_chars = b.chars().collect::<Vec<_>>();
+    let mut row = (0..=b_chars.len()).collect::<Vec<_>>();
+
+    for (i, a_char) in a.chars().enumerate() {
+        let mut previous_diagonal = row[0];
+        row[0] = i + 1;
+
+        for (j, b_char) in b_chars.iter().enumerate() {
+            let substitution_cost = usize::from(a_char != *b_char);
+
+            let new_value = (row[j] + 1)
+                .min(row[j + 1] + 1)
+                .min(previous_diagonal + substitution_cost);
+
+            previous_diagonal = row[j + 1];
+            row[j + 1] = new_value;
+        }
+    }
+
+    row[b_chars.len()]
 }
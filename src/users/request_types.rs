@@ -17,3 +17,15 @@ pub(super) struct UserSearchUserInformationRaw {
     pub previous_usernames: Vec<String>,
     pub display_name: String,
 }
+
+#[derive(Serialize, Deserialize)]
+pub(super) struct TwoStepConfigurationResponse {
+    #[serde(default)]
+    pub methods: Vec<TwoStepMethodRaw>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(super) struct TwoStepMethodRaw {
+    #[serde(default)]
+    pub enabled: bool,
+}
@@ -1,8 +1,27 @@
 use crate::{Client, RoboatError, XCSRF_HEADER};
+use reqwest::header::HeaderName;
 use reqwest::Response;
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
+/// A struct that a response can be parsed into using [`Client::parse_to_raw`]. With the
+/// `schema-drift` feature, response structs also need to be serializable so that they can be
+/// compared against the json they were parsed from.
+#[cfg(not(feature = "schema-drift"))]
+pub(crate) trait RawResponse: DeserializeOwned {}
+
+#[cfg(not(feature = "schema-drift"))]
+impl<T: DeserializeOwned> RawResponse for T {}
+
+/// A struct that a response can be parsed into using [`Client::parse_to_raw`]. With the
+/// `schema-drift` feature, response structs also need to be serializable so that they can be
+/// compared against the json they were parsed from.
+#[cfg(feature = "schema-drift")]
+pub(crate) trait RawResponse: DeserializeOwned + Serialize {}
+
+#[cfg(feature = "schema-drift")]
+impl<T: DeserializeOwned + Serialize> RawResponse for T {}
+
 /// Roblox's error response used when a status code of 403 is given. Only the first error
 /// is used when converting to [`RoboatError`].
 #[allow(missing_docs)]
@@ -18,11 +37,23 @@ struct RobloxErrorRaw {
     message: String,
 }
 
+/// The header Roblox uses to say which challenge has to be completed before a request is allowed.
+const CHALLENGE_TYPE_HEADER: HeaderName = HeaderName::from_static("rblx-challenge-type");
+
 impl Client {
     /// Used to process a 403 response from an endpoint. This requires new xcsrf to be
     /// pulled and returned inside an error
     async fn process_403(request_response: Response) -> RoboatError {
         let headers = request_response.headers().clone();
+
+        // Challenges also come back as a 403 with an error code of 0, so they are
+        // checked first to keep them from being mistaken for an xcsrf error.
+        if let Some(challenge_type) = headers.get(CHALLENGE_TYPE_HEADER) {
+            return RoboatError::ChallengeRequired(
+                challenge_type.to_str().unwrap_or_default().to_string(),
+            );
+        }
+
         let xcsrf = headers
             .get(XCSRF_HEADER)
             .map(|x| x.to_str().unwrap().to_string());
@@ -123,9 +154,10 @@ impl Client {
     }
 
     /// Parses a json from a [`reqwest::Response`] into a response struct, returning an error if the response is malformed.
-    pub(crate) async fn parse_to_raw<T: DeserializeOwned>(
-        response: Response,
-    ) -> Result<T, RoboatError> {
+    ///
+    /// With the `schema-drift` feature, the json is also compared against the fields of the struct.
+    pub(crate) async fn parse_to_raw<T: RawResponse>(response: Response) -> Result<T, RoboatError> {
+        #[cfg(not(feature = "schema-drift"))]
         let response_struct = match response.json::<T>().await {
             Ok(x) => x,
             Err(_) => {
@@ -133,6 +165,21 @@ impl Client {
             }
         };
 
+        #[cfg(feature = "schema-drift")]
+        let response_struct = {
+            let Ok(json) = response.json::<serde_json::Value>().await else {
+                return Err(RoboatError::MalformedResponse);
+            };
+
+            let Ok(response_struct) = T::deserialize(&json) else {
+                return Err(RoboatError::MalformedResponse);
+            };
+
+            crate::schema_drift::check(&json, &response_struct);
+
+            response_struct
+        };
+
         Ok(response_struct)
     }
 }